@@ -0,0 +1,123 @@
+// The refresh and draw hot path on a big board: grouping fetched tickets
+// into lanes, turning ADF descriptions into text, and drawing a whole
+// frame. Run with `cargo bench`; criterion reports changes since the last
+// run, so a regression shows up as a slower percentage.
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use kanbars::board::Board;
+use kanbars::config::{Config, GroupBy};
+use kanbars::jira_api::extract_text_from_adf;
+use kanbars::model::{ParentRef, Sprint, SprintState, StatusCategory, StatusGroups, Ticket, TicketType};
+use kanbars::ui::{draw_ui, AppState};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use serde_json::{json, Value};
+use std::hint::black_box;
+
+const BOARD_SIZE: usize = 2_000;
+
+const STATUSES: &[(&str, StatusCategory)] = &[
+    ("Backlog", StatusCategory::ToDo),
+    ("To Do", StatusCategory::ToDo),
+    ("In Progress", StatusCategory::InProgress),
+    ("In Review", StatusCategory::InProgress),
+    ("QA", StatusCategory::InProgress),
+    ("Done", StatusCategory::Done),
+];
+const TYPES: &[TicketType] = &[TicketType::Story, TicketType::Task, TicketType::Bug, TicketType::Epic];
+const PEOPLE: &[&str] = &["Ada Lovelace", "Grace Hopper", "Linus Pauling", "unassigned"];
+const COMPONENTS: &[&str] = &["api", "web", "billing", "search", "infra"];
+
+// A board's worth of varied tickets; the same every run so results compare
+fn tickets(count: usize) -> Vec<Ticket> {
+    (0..count)
+        .map(|i| {
+            let (status, category) = STATUSES[i % STATUSES.len()];
+            Ticket {
+                key: format!("BENCH-{}", i + 1),
+                ticket_type: TYPES[i % TYPES.len()].clone(),
+                summary: format!("Ticket {} with a summary long enough to wrap onto a second line of its card", i),
+                status: status.to_string(),
+                assignee: PEOPLE[i % PEOPLE.len()].to_string(),
+                priority: Some(if i % 97 == 0 { "Highest" } else { "Medium" }.to_string()),
+                parent: (i % 3 == 0).then(|| ParentRef {
+                    key: format!("BENCH-E{}", i % 7),
+                    summary: format!("Epic {}", i % 7),
+                    is_epic: true,
+                    url_index: None,
+                }),
+                components: COMPONENTS.iter().skip(i % COMPONENTS.len()).take(1 + i % 2).map(|c| c.to_string()).collect(),
+                status_category: Some(category),
+                flagged: i % 41 == 0,
+                incident: i % 97 == 0,
+                sprint: Some(Sprint { name: format!("Sprint {}", 40 + i % 3), state: SprintState::Active }),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+// A long description as the API returns it: paragraphs with marks, links,
+// mentions, lists and code blocks
+fn adf_document(paragraphs: usize) -> Value {
+    let content: Vec<Value> = (0..paragraphs)
+        .flat_map(|i| {
+            [
+                json!({"type": "paragraph", "content": [
+                    {"type": "text", "text": format!("Paragraph {} explains the change, ", i)},
+                    {"type": "text", "text": "in bold", "marks": [{"type": "strong"}]},
+                    {"type": "text", "text": " with a "},
+                    {"type": "text", "text": "link", "marks": [{"type": "link", "attrs": {"href": format!("https://example.com/{}", i)}}]},
+                    {"type": "mention", "attrs": {"id": "abc123", "text": "@Ada Lovelace"}},
+                ]}),
+                json!({"type": "bulletList", "content": [
+                    {"type": "listItem", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "First point"}]}]},
+                    {"type": "listItem", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "Second point"}]}]},
+                ]}),
+                json!({"type": "codeBlock", "content": [{"type": "text", "text": "fn main() {\n    println!(\"hi\");\n}"}]}),
+            ]
+        })
+        .collect();
+    json!({"type": "doc", "version": 1, "content": content})
+}
+
+fn group_tickets(c: &mut Criterion) {
+    let tickets = tickets(BOARD_SIZE);
+    for (name, group_by) in [("status", GroupBy::Status), ("sprint", GroupBy::Sprint), ("component", GroupBy::Component)] {
+        c.bench_function(&format!("from_tickets/{}/{}", name, BOARD_SIZE), |b| {
+            b.iter_batched(
+                || tickets.clone(),
+                |tickets| black_box(StatusGroups::from_tickets(tickets, group_by)),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+}
+
+fn extract_adf(c: &mut Criterion) {
+    let document = adf_document(200);
+    c.bench_function("extract_text_from_adf/200_paragraphs", |b| {
+        b.iter(|| {
+            let mut urls = Vec::new();
+            black_box(extract_text_from_adf(black_box(&document), &mut urls))
+        })
+    });
+}
+
+fn render_board(c: &mut Criterion) {
+    let config = Config::default();
+    let mut board = Board::new(config.clone());
+    board.columns = StatusGroups::from_tickets(tickets(BOARD_SIZE), GroupBy::Status);
+    board.data_source = "bench".to_string();
+    let boards = [board];
+    let mut app_state = AppState::new(config.display.clone(), Vec::new());
+    let mut terminal = Terminal::new(TestBackend::new(200, 60)).expect("test backend");
+
+    c.bench_function(&format!("draw_ui/{}_tickets/200x60", BOARD_SIZE), |b| {
+        b.iter(|| {
+            terminal.draw(|frame| draw_ui(frame, &boards, 0, false, &mut app_state)).expect("draw");
+        })
+    });
+}
+
+criterion_group!(benches, group_tickets, extract_adf, render_board);
+criterion_main!(benches);
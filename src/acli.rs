@@ -0,0 +1,212 @@
+// Backend that shells out to Atlassian's `acli` for users who are already
+// logged in there and have no API token configured
+use crate::config::Config;
+use crate::model::{Ticket, TicketType};
+use crate::users;
+use serde::Deserialize;
+use std::error::Error;
+use std::process::Command;
+
+#[derive(Debug, Deserialize)]
+struct AcliIssue {
+    key: String,
+    fields: AcliFields,
+}
+
+#[derive(Debug, Deserialize)]
+struct AcliFields {
+    summary: String,
+    status: AcliNamed,
+    issuetype: AcliNamed,
+    assignee: Option<AcliUser>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AcliNamed {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AcliUser {
+    #[serde(rename = "displayName")]
+    display_name: Option<String>,
+    #[serde(rename = "emailAddress")]
+    email_address: Option<String>,
+}
+
+// `acli` has printed both a bare array and a search-style envelope
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum AcliOutput {
+    Issues(Vec<AcliIssue>),
+    Search { issues: Vec<AcliIssue> },
+}
+
+/// Whether `acli` can be run at all
+pub fn installed() -> bool {
+    Command::new("acli").arg("--version").output().is_ok_and(|o| o.status.success())
+}
+
+pub fn fetch_tickets_acli(config: &Config) -> Result<Vec<Ticket>, Box<dyn Error>> {
+    let output = Command::new("acli")
+        .args([
+            "jira", "workitem", "search",
+            "--jql", &config.query.jql,
+            "--fields", "key,summary,status,issuetype,assignee",
+            "--json",
+        ])
+        .output()
+        .map_err(|e| format!("Could not run acli ({}). Set JIRA_API_TOKEN to use the REST API instead", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "acli search failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ).into());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    match serde_json::from_str::<AcliOutput>(&stdout) {
+        Ok(AcliOutput::Issues(issues)) | Ok(AcliOutput::Search { issues }) => {
+            Ok(issues.into_iter().map(ticket_from_issue).collect())
+        }
+        // Older acli versions ignore --json and print a table
+        Err(_) => Ok(stdout.lines().filter_map(parse_acli_line).collect()),
+    }
+}
+
+fn ticket_from_issue(issue: AcliIssue) -> Ticket {
+    let assignee = issue.fields.assignee
+        .and_then(|u| match u.display_name {
+            Some(name) => {
+                if let Some(ref email) = u.email_address {
+                    users::remember(email, &name);
+                }
+                Some(name)
+            }
+            None => u.email_address.map(|email| display_name_for(&email)),
+        })
+        .unwrap_or_else(|| "unassigned".to_string());
+
+    Ticket {
+        key: issue.key,
+        ticket_type: TicketType::from_name(&issue.fields.issuetype.name),
+        summary: issue.fields.summary,
+        status: issue.fields.status.name,
+        assignee,
+        ..Default::default()
+    }
+}
+
+// Prefer a cached display name over a raw email
+fn display_name_for(email: &str) -> String {
+    users::display_name(email).unwrap_or_else(|| email.to_string())
+}
+
+// Fallback for table output: columns are separated by two or more spaces
+// (or box-drawing/pipe borders), in the order key, type, summary, status,
+// assignee. Splitting on runs of spaces keeps multi-word values intact.
+pub fn parse_acli_line(line: &str) -> Option<Ticket> {
+    let columns: Vec<&str> = line
+        .split(['│', '|', '\t'])
+        .flat_map(|part| part.split("  "))
+        .map(|c| c.trim())
+        .filter(|c| !c.is_empty())
+        .collect();
+
+    let key = *columns.first()?;
+    if !is_issue_key(key) || columns.len() < 4 {
+        return None;
+    }
+
+    let assignee = columns.get(4)
+        .map(|a| if a.contains('@') { display_name_for(a) } else { a.to_string() })
+        .unwrap_or_else(|| "unassigned".to_string());
+
+    Some(Ticket {
+        key: key.to_string(),
+        ticket_type: TicketType::from_name(columns[1]),
+        summary: columns[2].to_string(),
+        status: columns[3].to_string(),
+        assignee,
+        ..Default::default()
+    })
+}
+
+// PROJ-123 style keys; used to skip headers and borders in table output
+fn is_issue_key(s: &str) -> bool {
+    match s.split_once('-') {
+        Some((project, number)) => {
+            !project.is_empty()
+                && project.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+                && project.starts_with(|c: char| c.is_ascii_uppercase())
+                && !number.is_empty()
+                && number.chars().all(|c| c.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // A table cell as acli prints it: words split by single spaces, with
+    // none of the characters that separate columns
+    const CELL: &str = r"[^\s|│\-@]{1,8}( [^\s|│@]{1,8}){0,4}";
+
+    fn key() -> impl Strategy<Value = String> {
+        (r"[A-Z][A-Z0-9_]{0,9}", 1u32..1_000_000).prop_map(|(project, number)| format!("{}-{}", project, number))
+    }
+
+    proptest! {
+        #[test]
+        fn any_line_parses_or_is_skipped_without_panicking(line in any::<String>()) {
+            let _ = parse_acli_line(&line);
+        }
+
+        #[test]
+        fn issue_keys_are_recognized(key in key()) {
+            prop_assert!(is_issue_key(&key));
+        }
+
+        #[test]
+        fn lowercase_or_numberless_keys_are_not(project in r"[a-z][a-z0-9]{0,5}", number in r"[0-9]{0,4}[a-z]?") {
+            let lowercase = format!("{}-{}", project, number);
+            let numberless = format!("{}-", project.to_uppercase());
+            prop_assert!(!is_issue_key(&lowercase));
+            prop_assert!(!is_issue_key(&numberless));
+        }
+
+        #[test]
+        fn columns_survive_spaces_pipes_and_box_borders(
+            key in key(),
+            issue_type in prop::sample::select(vec!["Story", "Bug", "Task", "Epic", "Sub-task"]),
+            summary in CELL,
+            status in CELL,
+            assignee in CELL,
+            gap in prop::sample::select(vec!["  ", "   ", "\t", " | ", " │ "]),
+        ) {
+            let line = [key.as_str(), issue_type, &summary, &status, &assignee].join(gap);
+            let ticket = parse_acli_line(&line).expect("a row with a key parses");
+            prop_assert_eq!(ticket.key, key);
+            prop_assert_eq!(ticket.ticket_type.name(), TicketType::from_name(issue_type).name());
+            prop_assert_eq!(ticket.summary, summary);
+            prop_assert_eq!(ticket.status, status);
+            prop_assert_eq!(ticket.assignee, assignee);
+        }
+
+        #[test]
+        fn rows_without_an_assignee_are_unassigned(key in key(), summary in CELL, status in CELL) {
+            let ticket = parse_acli_line(&format!("│ {} │ Task │ {} │ {} │", key, summary, status)).expect("parses");
+            prop_assert_eq!(ticket.assignee, "unassigned");
+        }
+
+        #[test]
+        fn headers_and_borders_are_skipped(header in r"(KEY|Key|key)  [A-Za-z ]{0,30}", border in r"[─┼├┤┌┐└┘+\-=| ]{0,40}") {
+            prop_assert!(parse_acli_line(&header).is_none());
+            prop_assert!(parse_acli_line(&border).is_none());
+        }
+    }
+}
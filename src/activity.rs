@@ -0,0 +1,70 @@
+// Session log of what kanbars did to JIRA and how it went, for the `L`
+// panel and optionally appended to a file, so a failed update can't go
+// unnoticed
+use chrono::{DateTime, Local};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+// Oldest entries are dropped past this
+const MAX_ENTRIES: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    Refresh,
+    Change,
+    Error,
+}
+
+impl Kind {
+    pub fn label(&self) -> &str {
+        match self {
+            Kind::Refresh => "refresh",
+            Kind::Change => "change",
+            Kind::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub time: DateTime<Local>,
+    pub kind: Kind,
+    pub message: String,
+}
+
+static ENTRIES: Mutex<Vec<Entry>> = Mutex::new(Vec::new());
+static LOG_FILE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Also append every entry to this file (`--activity-log`)
+pub fn log_to(path: PathBuf) {
+    let _ = LOG_FILE.set(path);
+}
+
+pub fn record(kind: Kind, message: impl Into<String>) {
+    let entry = Entry {
+        time: Local::now(),
+        kind,
+        message: message.into(),
+    };
+    
+    // The file is best effort; the panel still has the entry
+    if let Some(path) = LOG_FILE.get()
+        && let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path)
+    {
+        let _ = writeln!(file, "{}\t{}\t{}", entry.time.to_rfc3339(), entry.kind.label(), entry.message);
+    }
+    
+    if let Ok(mut entries) = ENTRIES.lock() {
+        if entries.len() >= MAX_ENTRIES {
+            entries.remove(0);
+        }
+        entries.push(entry);
+    }
+}
+
+/// Everything logged this session, oldest first
+pub fn entries() -> Vec<Entry> {
+    ENTRIES.lock().map(|entries| entries.clone()).unwrap_or_default()
+}
@@ -0,0 +1,81 @@
+// The classic aging WIP chart: each in-progress ticket as a dot in its
+// column, as high as the days it has sat in that status, so the work that
+// is quietly going stale stands out before it's late.
+use crate::config::Config;
+use crate::jira_api;
+use crate::model::{StatusCategory, Ticket};
+use chrono::Local;
+
+// Days in status that still count as fresh, and as worth a look; anything
+// older is stale
+pub const FRESH_DAYS: f64 = 2.0;
+pub const WATCH_DAYS: f64 = 5.0;
+
+#[derive(Debug)]
+pub struct Aged {
+    pub key: String,
+    pub summary: String,
+    pub days: f64,
+}
+
+/// A status on the board, with its tickets oldest first
+#[derive(Debug)]
+pub struct Column {
+    pub status: String,
+    pub tickets: Vec<Aged>,
+}
+
+#[derive(Debug)]
+pub struct Aging {
+    pub columns: Vec<Column>,
+    pub error: Option<String>,
+}
+
+impl Aging {
+    /// Ages of the in-progress ones among `tickets`, with columns in the
+    /// order their statuses first appear
+    pub fn load(config: &Config, tickets: &[&Ticket]) -> Self {
+        let in_progress: Vec<&Ticket> = tickets.iter()
+            .copied()
+            .filter(|t| t.status_category == Some(StatusCategory::InProgress))
+            .collect();
+        let mut aging = Aging { columns: Vec::new(), error: None };
+        if in_progress.is_empty() {
+            return aging;
+        }
+        let keys: Vec<String> = in_progress.iter().map(|t| t.key.clone()).collect();
+        let since = match jira_api::fetch_status_since(config, &keys) {
+            Ok(since) => since,
+            Err(e) => {
+                aging.error = Some(e.to_string().lines().next().unwrap_or_default().to_string());
+                return aging;
+            }
+        };
+        let now = Local::now();
+        for ticket in in_progress {
+            let Some(entered) = since.get(&ticket.key) else { continue };
+            let aged = Aged {
+                key: ticket.key.clone(),
+                summary: ticket.summary.clone(),
+                days: ((now - *entered).num_minutes() as f64 / (24.0 * 60.0)).max(0.0),
+            };
+            match aging.columns.iter_mut().find(|c| c.status == ticket.status) {
+                Some(column) => column.tickets.push(aged),
+                None => aging.columns.push(Column { status: ticket.status.clone(), tickets: vec![aged] }),
+            }
+        }
+        for column in &mut aging.columns {
+            column.tickets.sort_by(|a, b| b.days.total_cmp(&a.days));
+        }
+        aging
+    }
+
+    /// Every ticket on the chart, oldest first
+    pub fn oldest(&self) -> Vec<(&str, &Aged)> {
+        let mut all: Vec<_> = self.columns.iter()
+            .flat_map(|c| c.tickets.iter().map(move |t| (c.status.as_str(), t)))
+            .collect();
+        all.sort_by(|a, b| b.1.days.total_cmp(&a.1.days));
+        all
+    }
+}
@@ -0,0 +1,2047 @@
+// What the keys do, as data: the event loop turns each key into an
+// `Action` for whatever is on top of the screen stack, and `reduce` applies
+// it to the boards and UI state. Actions that change JIRA go out from here
+// too, so everything a key can do is in one place, ready to be bound to
+// other keys or run from a command palette.
+use crate::activity::{self, Kind};
+use crate::aging::Aging;
+use crate::blockers;
+use crate::board::Board;
+use crate::browser;
+use crate::clipboard;
+use crate::config::Config;
+use crate::debug;
+use crate::history;
+use crate::i18n::{t, tf};
+use crate::inbox::Inbox;
+use crate::jira_api::{self, NewIssue};
+use crate::markdown;
+use crate::query_builder::QueryBuilder;
+use crate::metadata;
+use crate::permissions::Permission;
+use crate::model::{Reaction, StatusCategory, Ticket};
+use crate::releases::Releases;
+use crate::reminders::{self, Reminders};
+use crate::timesheet::{self, Timesheet};
+use crate::ui::{AppState, DetailSection, RawPayload, UiMode, ZOOM};
+use crate::users;
+use chrono::Local;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::time::Instant;
+
+// Lines of the comment a reply starts out quoting
+const REPLY_QUOTE_LINES: usize = 3;
+
+// Lines the detail view moves on Page Up/Down
+const DETAIL_PAGE: usize = 10;
+
+/// Everything the keys can change
+pub struct App {
+    pub boards: Vec<Board>,
+    pub focused: usize,  // Board receiving keys in a split view
+    pub paused: bool,
+    pub state: AppState,
+    pub quit: bool,  // Set once the user asks to leave
+}
+
+impl App {
+    pub fn new(boards: Vec<Board>, setup_needed: Vec<&'static str>) -> Self {
+        let mut state = AppState::new(boards[0].config.display.clone(), setup_needed);
+        state.reminders = Reminders::load();
+        App { boards, focused: 0, paused: false, state, quit: false }
+    }
+}
+
+/// Something a key does. Most mean the same wherever they apply: `Back`
+/// cancels typing, a search or a filter before it closes what's on top,
+/// `Confirm` is Enter on a field or the selected row, and the text actions
+/// go to whichever field is open.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    Quit,
+    Back,
+    Confirm,
+    Input(char),  // Typed into the open field; a tab is four spaces in a comment
+    DeleteBack,
+    Other,  // A key with nothing to do here, which still ends key sequences and type-ahead
+    Open(UiMode),  // A screen or overlay with nothing to load: help, legend, stats, activity, query builder, setup
+    SelectPrev,
+    SelectNext,
+    Reload,
+
+    // Board
+    TypeAhead(char),  // A digit of a ticket number to jump to
+    SwitchBoard,
+    TogglePause,
+    CycleDensity,
+    Zoom(i8),  // In or out a level
+    Refresh,
+    RefreshCard,
+    RefreshLane,
+    ToggleFlag,
+    EditSummary,
+    NewTicket,
+    OpenInbox,
+    OpenReleases,
+    OpenTimesheet,
+    ShowDiff,
+    ShowRawPayload,
+    ShowBlockers,
+    ShowAging,
+    CycleAssignee,  // Narrow the board to the next assignee, then everyone again
+    ReloadMetadata,
+    CopySnippet,  // The ticket as a line for Slack
+    SetReminder,
+
+    // Setup, query builder and template picker
+    WriteSampleConfig,
+    NextField,
+    PrevField,
+    CycleValue(bool),  // Forward or back
+    SaveProfile,
+    PickTemplate(usize),
+
+    // Inbox and timesheet
+    ToggleRead,
+    MarkAllRead,
+    ShiftWeek(i64),
+    LogWork,
+
+    // Detail view and raw payload overlay
+    Prefix(char),  // First key of `]]` or `[[`
+    ScrollUp(usize),
+    ScrollDown(usize),
+    PageUp,
+    PageDown,
+    HalfPage(bool),  // Down or up
+    ScrollTop,
+    ScrollBottom,
+    GoTop,  // `g`: to the top, unless `x` follows to open a link
+    NextSection,
+    PrevSection,
+    JumpToComments,
+    StartSearch,
+    NextMatch,
+    PrevMatch,
+    ToggleWatchers,
+    PickCheckItem(bool),  // Next or previous checklist item
+    ToggleCheckItem,
+    StartComment,
+    Reply,
+    ThumbsUp,
+    ToggleInternal,
+    OpenLink(usize),
+    OpenVisibleLink,
+}
+
+impl Action {
+    // Changes JIRA or fetches from it, so not possible on a cached board
+    fn needs_jira(self) -> bool {
+        matches!(
+            self,
+            Action::Refresh | Action::RefreshCard | Action::RefreshLane | Action::ToggleFlag | Action::EditSummary
+                | Action::NewTicket | Action::OpenInbox | Action::OpenTimesheet | Action::OpenReleases
+                | Action::ShowDiff | Action::ShowBlockers | Action::ShowAging | Action::ReloadMetadata | Action::Open(UiMode::QueryBuilder)
+                | Action::StartComment | Action::Reply | Action::ThumbsUp | Action::ToggleCheckItem
+        )
+    }
+}
+
+/// Apply `action` to whatever is on top of the screen stack
+pub fn reduce(app: &mut App, action: Action) {
+    if action == Action::Quit {
+        app.quit = true;
+        return;
+    }
+    if !app.state.setup_needed.is_empty() && action.needs_jira() {
+        app.state.status_message = Some(t("status.read_only").to_string());
+        return;
+    }
+    match app.state.mode() {
+        UiMode::RawPayload => raw_payload(app, action),
+        // The other overlays only close
+        UiMode::Help | UiMode::Legend | UiMode::Stats | UiMode::Hygiene | UiMode::Activity | UiMode::BoardDiff
+        | UiMode::Blockers | UiMode::CatchUp | UiMode::Aging => {
+            if action == Action::Back {
+                app.state.pop();
+            }
+        }
+        UiMode::Setup => setup(app, action),
+        UiMode::Board | UiMode::Detail if app.state.reminder_input.is_some() => reminder_input(app, action),
+        UiMode::Board if app.state.summary_edit.is_some() => summary_edit(app, action),
+        UiMode::Board => board(app, action),
+        UiMode::QueryBuilder => query_builder(app, action),
+        UiMode::NewTicket => new_ticket(app, action),
+        UiMode::Inbox => inbox(app, action),
+        UiMode::Releases => releases(app, action),
+        UiMode::Timesheet => timesheet(app, action),
+        UiMode::Detail if app.state.comment_draft.is_some() => comment_draft(app, action),
+        UiMode::Detail if app.state.search_input.is_some() => search_input(app, action),
+        UiMode::Detail => detail(app, action),
+    }
+}
+
+/// Whether a single-line field is open, taking every character typed
+pub fn typing(state: &AppState) -> bool {
+    match state.mode() {
+        UiMode::Board => state.summary_edit.is_some() || state.reminder_input.is_some(),
+        UiMode::Detail => state.search_input.is_some() || state.reminder_input.is_some(),
+        UiMode::NewTicket => state.template_summary.is_some(),
+        UiMode::QueryBuilder => state.query_builder.profile_name.is_some(),
+        UiMode::Timesheet => state.timesheet.as_ref().is_some_and(|t| t.log_input.is_some()),
+        _ => false,
+    }
+}
+
+/// Type pasted text into the open field. The comment composer keeps its
+/// lines; single-line fields get them joined with spaces, so a pasted JQL
+/// query or URL never submits halfway through. With no field open, a paste
+/// is ignored rather than run as keys.
+pub fn paste(app: &mut App, text: &str) {
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    let state = &app.state;
+    let composing = state.mode() == UiMode::Detail && state.comment_draft.is_some();
+    if !composing && !typing(state) && state.mode() != UiMode::QueryBuilder {
+        return;
+    }
+    let text = if composing {
+        text
+    } else {
+        text.replace('\t', " ").split('\n').map(str::trim).filter(|line| !line.is_empty()).collect::<Vec<_>>().join(" ")
+    };
+    for c in text.chars().filter(|&c| !c.is_control() || composing && matches!(c, '\n' | '\t')) {
+        reduce(app, Action::Input(c));
+    }
+}
+
+fn raw_payload(app: &mut App, action: Action) {
+    let Some(ref mut payload) = app.state.raw_payload else {
+        app.state.pop();
+        return;
+    };
+    let page = payload.height.max(1);
+    match action {
+        Action::Back => app.state.pop(),
+        Action::ScrollDown(lines) => payload.scroll = payload.scroll.saturating_add(lines),
+        Action::ScrollUp(lines) => payload.scroll = payload.scroll.saturating_sub(lines),
+        Action::PageDown => payload.scroll = payload.scroll.saturating_add(page),
+        Action::PageUp => payload.scroll = payload.scroll.saturating_sub(page),
+        Action::ScrollTop => payload.scroll = 0,
+        // Clamped to the last page on draw
+        Action::ScrollBottom => payload.scroll = usize::MAX,
+        _ => {}
+    }
+}
+
+fn setup(app: &mut App, action: Action) {
+    let state = &mut app.state;
+    state.status_message = None;
+    match action {
+        Action::Back | Action::Confirm => state.pop(),
+        Action::WriteSampleConfig => {
+            let path = Config::config_path();
+            state.status_message = Some(if path.exists() {
+                tf("status.config_exists", &[("path", &path.display())])
+            } else {
+                match Config::default().save() {
+                    Ok(()) => tf("status.config_written", &[("path", &path.display())]),
+                    Err(e) => tf("status.write_failed", &[("path", &path.display()), ("error", &e)]),
+                }
+            });
+        }
+        _ => {}
+    }
+}
+
+// Editing the selected card's summary in place
+fn summary_edit(app: &mut App, action: Action) {
+    let App { boards, focused, state, .. } = app;
+    let board = &mut boards[*focused];
+    match action {
+        Action::Back => state.summary_edit = None,
+        Action::DeleteBack => {
+            if let Some((_, ref mut text)) = state.summary_edit {
+                text.pop();
+            }
+        }
+        Action::Input(c) => {
+            if let Some((_, ref mut text)) = state.summary_edit {
+                text.push(c);
+            }
+        }
+        Action::Confirm => {
+            let (key, text) = state.summary_edit.take().unwrap_or_default();
+            let text = text.trim().to_string();
+            let ticket = board.columns.groups.values().flatten().find(|t| t.key == key);
+            match ticket {
+                Some(ticket) if text.is_empty() => {
+                    state.status_message = Some(tf("status.summary_needed", &[("key", &ticket.key)]));
+                }
+                Some(ticket) if text != ticket.summary => {
+                    let outcome = match jira_api::update_summary(&board.config, &key, &text) {
+                        Ok(()) => {
+                            board.columns.update(&key, |t| t.summary = text.clone());
+                            Ok(tf("status.renamed", &[("key", &key), ("summary", &text)]))
+                        }
+                        Err(e) => Err(tf("status.edit_failed", &[("key", &key), ("error", &first_line(e.as_ref()))])),
+                    };
+                    report(state, outcome);
+                }
+                _ => {}
+            }
+        }
+        _ => {}
+    }
+}
+
+// Typing the day and note of a follow-up on a ticket
+fn reminder_input(app: &mut App, action: Action) {
+    let state = &mut app.state;
+    match action {
+        Action::Back => state.reminder_input = None,
+        Action::DeleteBack => {
+            if let Some((_, ref mut text)) = state.reminder_input {
+                text.pop();
+            }
+        }
+        Action::Input(c) => {
+            if let Some((_, ref mut text)) = state.reminder_input {
+                text.push(c);
+            }
+        }
+        Action::Confirm => {
+            let (key, text) = state.reminder_input.take().unwrap_or_default();
+            let had_one = state.reminders.get(&key).is_some();
+            let reminder = match text.trim() {
+                "" => None,
+                text => match reminders::parse(text, Local::now().date_naive()) {
+                    Ok(reminder) => Some(reminder),
+                    Err(e) => {
+                        state.status_message = Some(e);
+                        return;
+                    }
+                },
+            };
+            let message = match &reminder {
+                Some(reminder) => tf("status.reminder_set", &[("key", &key), ("reminder", &reminder.describe())]),
+                None if had_one => tf("status.reminder_cleared", &[("key", &key)]),
+                None => return,
+            };
+            state.status_message = Some(match state.reminders.set(&key, reminder) {
+                Ok(()) => message,
+                Err(e) => e,
+            });
+        }
+        _ => {}
+    }
+}
+
+// The day and note of a ticket's reminder, to edit
+fn start_reminder(state: &mut AppState, key: &str) {
+    let text = state.reminders.get(key)
+        .map(|r| format!("{} {}", r.due, r.note).trim_end().to_string())
+        .unwrap_or_default();
+    state.reminder_input = Some((key.to_string(), text));
+}
+
+fn board(app: &mut App, action: Action) {
+    let App { boards, focused, paused, state, quit } = app;
+    state.status_message = None;
+    let board_count = boards.len();
+    let board = &mut boards[*focused];
+    let config = &board.config;
+    // Digits build up a key number to jump to; anything else ends it
+    let type_ahead = state.type_ahead.take();
+    match action {
+        Action::TypeAhead(c) => {
+            let mut digits = type_ahead.map(|(digits, _)| digits).unwrap_or_default();
+            digits.push(c);
+            if let Some(index) = board.columns.find_key(&digits) {
+                board.selected_index = index;
+            }
+            state.type_ahead = Some((digits, Instant::now()));
+        }
+        Action::DeleteBack if let Some((mut digits, _)) = type_ahead => {
+            digits.pop();
+            if !digits.is_empty() {
+                state.type_ahead = Some((digits, Instant::now()));
+            }
+        }
+        Action::Back if type_ahead.is_some() => {}
+        Action::Back if board.assignee.is_some() => board.set_assignee(None),
+        Action::Back if board.filter.is_some() => {
+            let _ = board.set_filter(None);
+        }
+        Action::ReloadMetadata => {
+            // Fetched again right away for the projects on the board
+            let mut projects: Vec<&str> = board.all_tickets().into_iter().map(|t| metadata::project_of(&t.key)).collect();
+            projects.sort_unstable();
+            projects.dedup();
+            state.permissions.clear();
+            let outcome = metadata::reload().and_then(|_| {
+                for project in &projects {
+                    let meta = metadata::project(config, project).map_err(|e| tf("status.metadata_failed", &[("project", project), ("error", &first_line(e.as_ref()))]))?;
+                    state.permissions.set(project, meta.permissions);
+                }
+                let projects = if projects.is_empty() { t("status.no_projects").to_string() } else { projects.join(", ") };
+                Ok(tf("status.metadata_reloaded", &[("projects", &projects)]))
+            });
+            report(state, outcome);
+        }
+        Action::CycleAssignee => {
+            let assignees = board.assignees();
+            let next = match board.assignee {
+                Some(ref current) => assignees.iter().skip_while(|a| *a != current).nth(1).cloned(),
+                None => assignees.first().cloned(),
+            };
+            board.set_assignee(next);
+        }
+        Action::Back => *quit = true,
+        Action::Open(UiMode::Setup) if state.setup_needed.is_empty() => {}
+        Action::Open(mode) => state.push(mode),
+        Action::Refresh if board.loading.is_none() => {
+            // Failures show in the board's title
+            let _ = board.refresh();
+        }
+        Action::RefreshCard => {
+            // Just the selected card, e.g. after changing it in the browser
+            if let Some(key) = board.columns.get_ticket_by_index(board.selected_index).map(|t| t.key.clone()) {
+                state.status_message = Some(match board.refresh_ticket(&key) {
+                    Ok(true) => tf("status.refreshed", &[("key", &key)]),
+                    Ok(false) => tf("status.no_longer_matches", &[("key", &key)]),
+                    Err(e) => tf("status.refresh_failed", &[("key", &key), ("error", &first_line(e.as_ref()))]),
+                });
+            }
+        }
+        Action::RefreshLane => {
+            state.status_message = Some(match board.refresh_lane() {
+                Ok((lane, count)) => tf("status.lane_refreshed", &[("lane", &lane), ("count", &count)]),
+                Err(e) => tf("status.lane_refresh_failed", &[("error", &first_line(e.as_ref()))]),
+            });
+        }
+        Action::SwitchBoard => *focused = (*focused + 1) % board_count,
+        Action::TogglePause => *paused = !*paused,
+        Action::Zoom(step) => {
+            state.zoom = (state.zoom + step).clamp(*ZOOM.start(), *ZOOM.end());
+            state.status_message = Some(tf("status.zoom", &[("zoom", &format!("{:+}", state.zoom))]));
+        }
+        Action::CycleDensity => {
+            board.cycle_density();
+            state.status_message = Some(tf("status.density", &[("density", &board.density.name())]));
+        }
+        Action::ShowRawPayload => {
+            if let Some(key) = board.columns.get_ticket_by_index(board.selected_index).map(|t| t.key.clone()) {
+                open_raw_payload(state, &key);
+            }
+        }
+        Action::OpenInbox => {
+            state.inbox = Some(Inbox::load(config));
+            state.push(UiMode::Inbox);
+        }
+        Action::OpenReleases => {
+            state.releases = Some(Releases::load(config, board.columns.unique_tickets().into_iter()));
+            state.push(UiMode::Releases);
+        }
+        Action::OpenTimesheet => {
+            // This week's worklogs, ready to log on the selected card
+            let current = board.columns.get_ticket_by_index(board.selected_index)
+                .map(|t| (t.key.clone(), t.summary.clone()));
+            let week = timesheet::week_of(Local::now().date_naive());
+            state.timesheet = Some(Timesheet::load(config, week, current));
+            state.push(UiMode::Timesheet);
+        }
+        Action::ShowDiff => {
+            // A standup view: what moved since this time yesterday
+            let tickets: Vec<_> = board.columns.unique_tickets().into_iter().cloned().collect();
+            let since = Local::now() - chrono::Duration::hours(24);
+            state.board_diff = Some(history::diff(&board.name, since, &tickets));
+            state.push(UiMode::BoardDiff);
+        }
+        Action::CopySnippet => {
+            if let Some(ticket) = board.columns.get_ticket_by_index(board.selected_index) {
+                copy_snippet(state, config, ticket);
+            }
+        }
+        Action::SetReminder => {
+            if let Some(ticket) = board.columns.get_ticket_by_index(board.selected_index) {
+                start_reminder(state, &ticket.key.clone());
+            }
+        }
+        Action::ShowBlockers => {
+            if let Some(ticket) = board.columns.get_ticket_by_index(board.selected_index) {
+                state.blockers = Some(blockers::load(config, &ticket.key, None));
+                state.push(UiMode::Blockers);
+            }
+        }
+        Action::ShowAging => {
+            // Tickets in board order, so columns chart in the order they show
+            let tickets: Vec<&Ticket> = board.columns.groups.values().flatten().collect();
+            state.aging = Some(Aging::load(config, &tickets));
+            state.push(UiMode::Aging);
+        }
+        Action::EditSummary => {
+            if let Some(ticket) = board.columns.get_ticket_by_index(board.selected_index)
+                && permitted(state, config, &ticket.key, Permission::Edit)
+            {
+                state.summary_edit = Some((ticket.key.clone(), ticket.summary.clone()));
+            }
+        }
+        Action::NewTicket => {
+            if config.templates.is_empty() {
+                state.status_message = Some(t("status.no_templates").to_string());
+            } else {
+                state.template_selected = 0;
+                state.template_summary = None;
+                state.push(UiMode::NewTicket);
+            }
+        }
+        Action::ToggleFlag => {
+            // Updating the card on success
+            if let Some((key, flagged)) = board.columns.get_ticket_by_index(board.selected_index).map(|t| (t.key.clone(), !t.flagged))
+                && permitted(state, config, &key, Permission::Edit)
+            {
+                let outcome = match jira_api::set_flagged(config, &key, flagged) {
+                    Ok(()) => {
+                        board.columns.update(&key, |t| t.flagged = flagged);
+                        Ok(tf(if flagged { "status.flagged" } else { "status.unflagged" }, &[("key", &key)]))
+                    }
+                    Err(e) => Err(tf("status.flag_failed", &[("key", &key), ("error", &first_line(e.as_ref()))])),
+                };
+                report(state, outcome);
+            }
+        }
+        // Up and down cycle through all tickets, wrapping at the ends
+        Action::SelectPrev => {
+            let total_tickets = board.columns.total_tickets();
+            if board.selected_index > 0 {
+                board.selected_index -= 1;
+            } else if total_tickets > 0 {
+                board.selected_index = total_tickets - 1;
+            }
+        }
+        Action::SelectNext => {
+            let total_tickets = board.columns.total_tickets();
+            if total_tickets > 0 {
+                board.selected_index = (board.selected_index + 1) % total_tickets;
+            }
+        }
+        Action::Confirm => {
+            // The selected ticket in full, fetching details the board left out
+            if let Some(ticket) = board.columns.get_ticket_by_index(board.selected_index) {
+                let mut detailed_ticket = ticket.clone();
+                if detailed_ticket.description.is_none() {
+                    match jira_api::fetch_ticket_details(config, &ticket.key) {
+                        Ok(full_ticket) => detailed_ticket = full_ticket,
+                        // Shown in place of the description
+                        Err(e) => detailed_ticket.description = Some(format!("{}\n\n{}", t("error.details"), e)),
+                    }
+                }
+                // Asked for before the footer offers to comment
+                state.permissions.learn(config, metadata::project_of(&ticket.key));
+                state.detail_ticket = Some(detailed_ticket);
+                state.checklist_selected = None;
+                state.detail_scroll = 0;
+                state.push(UiMode::Detail);
+            }
+        }
+        _ => {}
+    }
+}
+
+// The builder's query, with a named assignee swapped for their account id,
+// which JQL needs on sites that hide names and emails. Someone seen before
+// is found in the user directory without asking JIRA. When the lookup
+// fails, e.g. offline or for a name several people share, the name stays.
+fn builder_jql(config: &Config, builder: &QueryBuilder) -> Option<String> {
+    let name = builder.assignee.trim();
+    if matches!(name, "" | "me" | "none") {
+        return builder.to_jql();
+    }
+    let mut resolved = builder.clone();
+    if let Some(account_id) = users::account_id(name) {
+        resolved.assignee = account_id;
+    } else if let Ok(account) = jira_api::find_user(config, name) {
+        resolved.assignee = account.account_id;
+    }
+    resolved.to_jql()
+}
+
+fn query_builder(app: &mut App, action: Action) {
+    let App { boards, focused, state, .. } = app;
+    state.status_message = None;
+    let config = &boards[*focused].config;
+    let builder = &mut state.query_builder;
+    if let Some(ref mut name) = builder.profile_name {
+        // Naming the profile to save
+        match action {
+            Action::Back => builder.profile_name = None,
+            Action::DeleteBack => {
+                name.pop();
+            }
+            Action::Input(c) if !c.is_whitespace() => name.push(c),
+            Action::Confirm if !name.is_empty() => {
+                let name = builder.profile_name.take().unwrap_or_default();
+                let jql = builder_jql(config, builder).unwrap_or_default();
+                let outcome = match Config::save_profile(&name, &jql) {
+                    Ok(()) => Ok(tf("status.profile_saved", &[("name", &name)])),
+                    Err(e) => Err(tf("status.profile_failed", &[("error", &e)])),
+                };
+                report(state, outcome);
+            }
+            _ => {}
+        }
+        return;
+    }
+    match action {
+        Action::Back => state.pop(),
+        Action::PrevField => builder.prev_field(),
+        Action::NextField => builder.next_field(),
+        Action::CycleValue(forward) => builder.cycle(forward),
+        Action::DeleteBack => builder.backspace(),
+        Action::SaveProfile => {
+            if builder.to_jql().is_some() {
+                builder.profile_name = Some(String::new());
+            } else {
+                state.status_message = Some(t("status.needs_filter").to_string());
+            }
+        }
+        Action::Input(c) => builder.type_char(c),
+        Action::Confirm => match builder_jql(config, builder) {
+            // Try the query on the focused board
+            Some(jql) => {
+                let board = &mut boards[*focused];
+                let mut config = board.config.clone();
+                config.query.jql = jql;
+                config.query.extra.clear();
+                config.query.name = "builder".to_string();
+                board.reconfigure(config);
+                board.selected_index = 0;
+                let _ = board.refresh();
+                state.pop();
+            }
+            None => state.status_message = Some(t("status.needs_filter").to_string()),
+        },
+        _ => {}
+    }
+}
+
+fn new_ticket(app: &mut App, action: Action) {
+    let App { boards, focused, state, .. } = app;
+    state.status_message = None;
+    let board = &mut boards[*focused];
+    let template_count = board.config.templates.len();
+    if template_count == 0 {
+        // A config reload took the templates away
+        state.pop();
+    } else if let Some(ref mut summary) = state.template_summary {
+        match action {
+            Action::Back => state.template_summary = None,
+            Action::DeleteBack => {
+                summary.pop();
+            }
+            Action::Input(c) => summary.push(c),
+            Action::Confirm if !summary.trim().is_empty() => {
+                let summary = state.template_summary.take().unwrap_or_default();
+                if let Some(template) = board.config.templates.values().nth(state.template_selected) {
+                    let issue = NewIssue {
+                        project: template.project.clone(),
+                        issue_type: template.issue_type.clone(),
+                        summary: summary.trim().to_string(),
+                        description: Some(template.description.clone()),
+                        labels: template.labels.clone(),
+                        components: template.components.clone(),
+                    };
+                    let created = metadata::check_issue_type(&board.config, &issue.project, &issue.issue_type)
+                        .map_err(Box::<dyn Error>::from)
+                        .and_then(|()| jira_api::create_issue(&board.config, &issue));
+                    let outcome = match created {
+                        Ok(key) => {
+                            let _ = board.refresh();
+                            Ok(tf("status.created", &[("key", &key), ("summary", &issue.summary)]))
+                        }
+                        Err(e) => Err(tf("status.create_failed", &[("error", &first_line(e.as_ref()))])),
+                    };
+                    report(state, outcome);
+                }
+                state.pop();
+            }
+            _ => {}
+        }
+    } else {
+        match action {
+            Action::Back => state.pop(),
+            Action::SelectPrev => state.template_selected = (state.template_selected + template_count - 1) % template_count,
+            Action::SelectNext => state.template_selected = (state.template_selected + 1) % template_count,
+            Action::Confirm => start_summary(state, &board.config, state.template_selected),
+            Action::PickTemplate(index) if index < template_count => start_summary(state, &board.config, index),
+            _ => {}
+        }
+    }
+}
+
+// Template `index` picked: on to its summary, if its project lets you
+// create tickets at all, rather than finding out once it's typed
+fn start_summary(state: &mut AppState, config: &Config, index: usize) {
+    state.template_selected = index;
+    let project = config.templates.values().nth(index).map(|t| t.project.clone()).unwrap_or_default();
+    if permitted(state, config, &project, Permission::Create) {
+        state.template_summary = Some(String::new());
+    }
+}
+
+fn inbox(app: &mut App, action: Action) {
+    let App { boards, focused, state, .. } = app;
+    state.status_message = None;
+    let config = &boards[*focused].config;
+    let Some(ref mut inbox) = state.inbox else {
+        state.pop();
+        return;
+    };
+    match action {
+        Action::Back => state.pop(),
+        Action::SelectPrev => inbox.select_prev(),
+        Action::SelectNext => inbox.select_next(),
+        Action::ToggleRead => inbox.toggle_read(),
+        Action::MarkAllRead => inbox.mark_all_read(),
+        Action::Reload => *inbox = Inbox::load(config),
+        Action::Confirm => {
+            if let Some(key) = inbox.selected_item().map(|item| item.key.clone()) {
+                inbox.mark_read();
+                open_ticket(state, config, &key);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn releases(app: &mut App, action: Action) {
+    let App { boards, focused, state, .. } = app;
+    state.status_message = None;
+    let board = &mut boards[*focused];
+    let Some(ref mut releases) = state.releases else {
+        state.pop();
+        return;
+    };
+    match action {
+        Action::Back => state.pop(),
+        Action::SelectPrev => releases.select_prev(),
+        Action::SelectNext => releases.select_next(),
+        Action::Reload => {
+            let selected = releases.selected;
+            *releases = Releases::load(&board.config, board.columns.unique_tickets().into_iter());
+            releases.selected = selected.min(releases.versions.len().saturating_sub(1));
+        }
+        Action::Confirm => {
+            // Narrow the board to the release
+            if let Some(filter) = releases.selected_release().map(|r| r.filter()) {
+                let _ = board.set_filter(Some(filter));
+                state.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+fn timesheet(app: &mut App, action: Action) {
+    let App { boards, focused, state, .. } = app;
+    state.status_message = None;
+    let config = &boards[*focused].config;
+    let Some(ref mut timesheet) = state.timesheet else {
+        state.pop();
+        return;
+    };
+    if let Some(ref mut input) = timesheet.log_input {
+        match action {
+            Action::Back => timesheet.log_input = None,
+            Action::Confirm => {
+                let outcome = timesheet.log(config);
+                report(state, outcome);
+            }
+            Action::DeleteBack => {
+                input.pop();
+            }
+            Action::Input(c) => input.push(c),
+            _ => {}
+        }
+        return;
+    }
+    match action {
+        Action::Back => state.pop(),
+        Action::SelectPrev => timesheet.select_prev(),
+        Action::SelectNext => timesheet.select_next(),
+        Action::ShiftWeek(weeks) => timesheet.shift(config, weeks),
+        Action::Reload => timesheet.reload(config),
+        Action::LogWork => {
+            let Some(key) = timesheet.selected_row().map(|row| row.key.clone()) else { return };
+            if permitted(state, config, &key, Permission::LogWork)
+                && let Some(ref mut timesheet) = state.timesheet
+            {
+                timesheet.log_input = Some(String::new());
+            }
+        }
+        Action::Confirm => {
+            if let Some(key) = timesheet.selected_row().map(|row| row.key.clone()) {
+                open_ticket(state, config, &key);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn comment_draft(app: &mut App, action: Action) {
+    let App { boards, focused, state, .. } = app;
+    state.status_message = None;
+    let config = &boards[*focused].config;
+    let Some(ref mut draft) = state.comment_draft else { return };
+    match action {
+        Action::Back => state.comment_draft = None,
+        Action::Confirm => {
+            let blocks = markdown::parse(draft);
+            if let Some(ticket) = state.detail_ticket.as_ref()
+                && !blocks.is_empty()
+            {
+                let ticket_key = ticket.key.clone();
+                let internal = ticket.service_desk && state.comment_internal;
+                // Mentions nobody matches go out as plain text
+                let accounts: HashMap<_, _> = markdown::mentions(&blocks).into_iter()
+                    .filter_map(|name| jira_api::find_user(config, &name).ok().map(|account| (name, account)))
+                    .collect();
+                let outcome = match jira_api::add_comment(config, &ticket_key, &markdown::to_adf(&blocks, &accounts), internal) {
+                    Ok(()) => {
+                        state.comment_draft = None;
+                        // Reload so the new comment shows
+                        if let Ok(ticket) = jira_api::fetch_ticket_details(config, &ticket_key) {
+                            state.detail_ticket = Some(ticket);
+                            state.checklist_selected = None;
+                            state.jump_to_section(DetailSection::Comments);
+                        }
+                        Ok(tf("status.commented", &[("key", &ticket_key)]))
+                    }
+                    Err(e) => Err(tf("status.comment_failed", &[("error", &first_line(e.as_ref()))])),
+                };
+                report(state, outcome);
+            }
+        }
+        Action::ToggleInternal => state.comment_internal = !state.comment_internal,
+        Action::Input('\t') => draft.push_str("    "),
+        Action::Input(c) => draft.push(c),
+        Action::DeleteBack => {
+            draft.pop();
+        }
+        _ => {}
+    }
+}
+
+// Typing a search query in the detail view
+fn search_input(app: &mut App, action: Action) {
+    let state = &mut app.state;
+    match action {
+        Action::Back => state.search_input = None,
+        Action::Confirm => {
+            let query = state.search_input.take().unwrap_or_default();
+            if query.is_empty() {
+                state.clear_search();
+            } else {
+                state.search_query = Some(query);
+                state.search_jump = true;
+            }
+        }
+        Action::DeleteBack => {
+            if let Some(ref mut input) = state.search_input {
+                input.pop();
+            }
+        }
+        Action::Input(c) => {
+            if let Some(ref mut input) = state.search_input {
+                input.push(c);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn detail(app: &mut App, action: Action) {
+    let App { boards, focused, state, .. } = app;
+    let config = &boards[*focused].config;
+    // Whatever comes next ends a two-key sequence (`]]`, `[[`, `gx`)
+    let pending = state.pending_key.take();
+    state.status_message = None;
+    match action {
+        Action::Back if state.search_query.is_some() => state.clear_search(),
+        // Back to the board, or the list the ticket was opened from
+        Action::Back => state.pop(),
+        Action::ScrollUp(lines) => state.detail_scroll = state.detail_scroll.saturating_sub(lines),
+        Action::ScrollDown(lines) => state.detail_scroll = state.detail_scroll.saturating_add(lines),
+        Action::PageUp => state.detail_scroll = state.detail_scroll.saturating_sub(DETAIL_PAGE),
+        Action::PageDown => state.detail_scroll += DETAIL_PAGE,
+        Action::HalfPage(down) => state.scroll_half_page(down),
+        Action::ScrollTop => state.detail_scroll = 0,
+        Action::ScrollBottom => state.scroll_to_bottom(),
+        Action::Prefix(c) => state.pending_key = Some(c),
+        Action::NextSection => state.next_section(),
+        Action::PrevSection => state.prev_section(),
+        Action::JumpToComments => state.jump_to_section(DetailSection::Comments),
+        Action::StartSearch => state.search_input = Some(String::new()),
+        Action::NextMatch => state.next_match(),
+        Action::PrevMatch => state.prev_match(),
+        Action::GoTop if pending == Some('g') => {}
+        Action::GoTop => {
+            state.scroll_before_g = Some(state.detail_scroll);
+            state.detail_scroll = 0;
+            state.pending_key = Some('g');
+        }
+        Action::ShowRawPayload => {
+            if let Some(key) = state.detail_ticket.as_ref().map(|t| t.key.clone()) {
+                open_raw_payload(state, &key);
+            }
+        }
+        Action::PickCheckItem(forward) => {
+            let count = state.detail_ticket.as_ref().map_or(0, |t| t.checklist.len());
+            if count == 0 {
+                state.status_message = Some(t("status.no_checklist").to_string());
+                return;
+            }
+            // Wrapping, and into view the first time
+            state.checklist_selected = Some(match state.checklist_selected {
+                None if forward => 0,
+                None => count - 1,
+                Some(index) if forward => (index + 1) % count,
+                Some(index) => (index + count - 1) % count,
+            });
+            if !state.current_section().is_some_and(|(_, s)| s == DetailSection::Checklist) {
+                state.jump_to_section(DetailSection::Checklist);
+            }
+        }
+        Action::ToggleCheckItem => {
+            let picked = state.checklist_selected.and_then(|index| {
+                let ticket = state.detail_ticket.as_ref()?;
+                Some((index, ticket.key.clone(), ticket.checklist.get(index)?.clone()))
+            });
+            let Some((index, key, item)) = picked else {
+                state.status_message = Some(t("status.pick_item").to_string());
+                return;
+            };
+            if !permitted(state, config, &key, Permission::Edit) {
+                return;
+            }
+            let outcome = match jira_api::set_checklist_item(config, &key, &item, !item.done) {
+                Ok(()) => {
+                    if let Some(picked) = state.detail_ticket.as_mut().and_then(|t| t.checklist.get_mut(index)) {
+                        picked.done = !item.done;
+                    }
+                    Ok(tf(if item.done { "status.unticked" } else { "status.ticked" }, &[("key", &key), ("item", &item.text)]))
+                }
+                Err(e) => Err(tf("status.checklist_failed", &[("key", &key), ("error", &first_line(e.as_ref()))])),
+            };
+            report(state, outcome);
+        }
+        Action::CopySnippet => {
+            if let Some(ticket) = state.detail_ticket.clone() {
+                copy_snippet(state, config, &ticket);
+            }
+        }
+        Action::SetReminder => {
+            if let Some(key) = state.detail_ticket.as_ref().map(|t| t.key.clone()) {
+                start_reminder(state, &key);
+            }
+        }
+        Action::ShowBlockers => {
+            // The detail view has the ticket's own links already
+            if let Some(ticket) = state.detail_ticket.as_ref() {
+                state.blockers = Some(blockers::load(config, &ticket.key, ticket.links.as_deref()));
+                state.push(UiMode::Blockers);
+            }
+        }
+        Action::ToggleWatchers => {
+            // Fetched the first time they're shown
+            state.show_watchers = !state.show_watchers;
+            if state.show_watchers
+                && let Some(ticket) = state.detail_ticket.as_mut()
+                && ticket.watchers.is_none()
+            {
+                match jira_api::fetch_watchers(config, &ticket.key) {
+                    Ok(watchers) => ticket.watchers = Some(watchers),
+                    Err(e) => state.status_message = Some(e.to_string()),
+                }
+            }
+        }
+        Action::StartComment | Action::Reply
+            if let Some(key) = state.detail_ticket.as_ref().map(|t| t.key.clone())
+                && !permitted(state, config, &key, Permission::Comment) => {}
+        Action::StartComment => {
+            // Customer-visible has to be chosen each time
+            state.comment_draft = Some(String::new());
+            state.comment_internal = true;
+        }
+        Action::Reply => {
+            // Quoting the start of the comment is what threads the reply
+            // under it
+            let comment = state.current_comment()
+                .and_then(|index| state.detail_ticket.as_ref()?.comments.as_ref()?.get(index));
+            match comment {
+                Some(comment) => {
+                    let quote: Vec<String> = comment.own_lines()
+                        .filter(|line| !line.trim().is_empty())
+                        .take(REPLY_QUOTE_LINES)
+                        .map(|line| format!("> {}", line.trim()))
+                        .collect();
+                    state.comment_draft = Some(format!("{}\n\n", quote.join("\n")));
+                    state.comment_internal = true;
+                }
+                None => state.status_message = Some(t("status.no_comment").to_string()),
+            }
+        }
+        Action::ThumbsUp => {
+            let outcome = thumbs_up(state, config);
+            report(state, outcome);
+        }
+        Action::OpenVisibleLink if pending == Some('g') => {
+            if let Some(scroll) = state.scroll_before_g.take() {
+                state.detail_scroll = scroll;
+            }
+            match state.first_visible_link() {
+                Some(index) => open_detail_link(state, index),
+                None => state.status_message = Some(t("status.no_link").to_string()),
+            }
+        }
+        Action::OpenLink(index) => open_detail_link(state, index),
+        _ => {}
+    }
+}
+
+fn copy_snippet(state: &mut AppState, config: &Config, ticket: &Ticket) {
+    state.status_message = Some(match clipboard::copy(&ticket.snippet(config.jira.url.as_deref())) {
+        Ok(()) => tf("status.copied", &[("key", &ticket.key)]),
+        Err(e) => tf("status.copy_failed", &[("key", &ticket.key), ("error", &e)]),
+    });
+}
+
+// Show a ticket from a list, which stays underneath to come back to
+// Whether JIRA lets you do `permission` on `key` (a ticket or project),
+// asking for the project's permissions the first time without waiting on
+// the answer; when it doesn't, the status line says why rather than a 403
+// after the fact
+fn permitted(state: &mut AppState, config: &Config, key: &str, permission: Permission) -> bool {
+    state.permissions.learn(config, metadata::project_of(key));
+    match state.permissions.check(key, permission) {
+        Ok(()) => true,
+        Err(reason) => {
+            state.status_message = Some(reason);
+            false
+        }
+    }
+}
+
+fn open_ticket(state: &mut AppState, config: &Config, key: &str) {
+    match jira_api::fetch_ticket_details(config, key) {
+        Ok(ticket) => {
+            state.permissions.learn(config, metadata::project_of(key));
+            state.detail_ticket = Some(ticket);
+            state.checklist_selected = None;
+            state.detail_scroll = 0;
+            state.push(UiMode::Detail);
+        }
+        Err(e) => state.status_message = Some(tf("status.open_failed", &[("target", &key), ("error", &first_line(e.as_ref()))])),
+    }
+}
+
+/// Find out what JIRA lets you do in each project on the boards, so the
+/// footer only offers what will work. Answers come in the background.
+pub fn learn_permissions(app: &mut App) {
+    for board in &app.boards {
+        for ticket in board.all_tickets() {
+            // Once per project
+            app.state.permissions.learn(&board.config, metadata::project_of(&ticket.key));
+        }
+    }
+}
+
+/// Select the one In Progress ticket assigned to you on the main board, and
+/// with `open` show it in full. With none or several, say so and leave the
+/// board as it is.
+pub fn focus_mine(app: &mut App, open: bool) {
+    let board = &mut app.boards[0];
+    let me = match jira_api::fetch_myself(&board.config) {
+        Ok(account) => account,
+        Err(e) => {
+            app.state.status_message = Some(tf("status.mine_failed", &[("error", &first_line(e.as_ref()))]));
+            return;
+        }
+    };
+    // A ticket can sit in several lanes, e.g. by component
+    let mut seen = HashSet::new();
+    let mine: Vec<(usize, String)> = (0..board.columns.total_tickets())
+        .filter_map(|index| board.columns.get_ticket_by_index(index).map(|t| (index, t)))
+        .filter(|(_, t)| t.status_category == Some(StatusCategory::InProgress))
+        // By account id where the ticket has one, since names can be shared
+        .filter(|(_, t)| t.assignee_id.as_ref().map_or(t.assignee == me.name, |id| *id == me.account_id))
+        .filter(|(_, t)| seen.insert(t.key.clone()))
+        .map(|(index, t)| (index, t.key.clone()))
+        .collect();
+    match mine.as_slice() {
+        [] => app.state.status_message = Some(t("status.none_in_progress").to_string()),
+        [(index, key)] => {
+            board.selected_index = *index;
+            app.state.status_message = Some(tf("status.focused", &[("key", key)]));
+            if open {
+                reduce(app, Action::Confirm);
+            }
+        }
+        several => app.state.status_message = Some(tf("status.several_in_progress", &[("count", &several.len())])),
+    }
+}
+
+/// Show an action's outcome in the title and keep it in the activity log
+pub fn report(state: &mut AppState, outcome: Result<String, String>) {
+    let message = match outcome {
+        Ok(message) => {
+            activity::record(Kind::Change, &message);
+            message
+        }
+        Err(message) => {
+            activity::record(Kind::Error, &message);
+            message
+        }
+    };
+    state.status_message = Some(message);
+}
+
+// The raw JSON viewer for a ticket, once --debug has seen its payload
+fn open_raw_payload(state: &mut AppState, key: &str) {
+    if !debug::enabled() {
+        state.status_message = Some(t("status.needs_debug").to_string());
+        return;
+    }
+    match debug::raw_issue(key) {
+        Some(json) => {
+            state.raw_payload = Some(RawPayload { key: key.to_string(), json, scroll: 0, height: 0 });
+            state.push(UiMode::RawPayload);
+        }
+        None => state.status_message = Some(tf("status.no_payload", &[("key", &key)])),
+    }
+}
+
+fn first_line(error: &dyn Error) -> String {
+    error.to_string().lines().next().unwrap_or_default().to_string()
+}
+
+fn open_detail_link(state: &mut AppState, index: usize) {
+    let url = state.detail_ticket.as_ref().and_then(|t| t.urls.get(index)).cloned();
+    state.status_message = Some(match url {
+        Some(url) => match browser::open_url(&url) {
+            Ok(()) => tf("status.opened", &[("url", &url)]),
+            Err(e) => tf("status.open_failed", &[("target", &url), ("error", &e)]),
+        },
+        None => tf("status.no_such_link", &[("number", &(index + 1))]),
+    });
+}
+
+// 👍 the comment `r` would reply to, counting it in right away
+fn thumbs_up(state: &mut AppState, config: &Config) -> Result<String, String> {
+    let index = state.current_comment().ok_or(t("status.no_comment"))?;
+    let ticket = state.detail_ticket.as_mut().ok_or(t("status.no_comment"))?;
+    let container = ticket.reaction_container.clone();
+    let comment = ticket.comments.as_mut().and_then(|c| c.get_mut(index)).ok_or(t("status.no_comment"))?;
+    let (Some(container), Some(ari)) = (container, comment.ari.as_deref()) else {
+        return Err(t("status.reactions_need_cloud").to_string());
+    };
+    if comment.reactions.iter().any(|r| r.mine && r.emoji == "👍") {
+        return Ok(tf("status.already_thumbs_up", &[("author", &comment.author)]));
+    }
+
+    jira_api::add_thumbs_up(config, &container, ari)
+        .map_err(|e| tf("status.reaction_failed", &[("error", &first_line(e.as_ref()))]))?;
+    match comment.reactions.iter_mut().find(|r| r.emoji == "👍") {
+        Some(reaction) => {
+            reaction.count += 1;
+            reaction.mine = true;
+        }
+        None => comment.reactions.push(Reaction { emoji: "👍".to_string(), count: 1, mine: true }),
+    }
+    Ok(tf("status.thumbs_up", &[("author", &comment.author), ("key", &ticket.key)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checklist;
+    use crate::config::{GroupBy, TicketTemplate};
+    use crate::jira_api::WatchedComment;
+    use crate::model::{Comment, IssueLink, StatusGroups, Ticket};
+    use crate::releases::Release;
+    use crate::timesheet::Row;
+    use crate::ui::draw_ui;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+    use std::sync::Once;
+
+    // Keep the files the tests write (history, inbox, sample config) out of
+    // the user's own. Set once, before any test reads the environment.
+    fn isolate() {
+        static ONCE: Once = Once::new();
+        ONCE.call_once(|| {
+            let dir = std::env::temp_dir().join(format!("kanbars-app-tests-{}", std::process::id()));
+            unsafe {
+                std::env::set_var("KANBARS_CONFIG_DIR", dir.join("config"));
+                std::env::set_var("KANBARS_DATA_DIR", dir.join("data"));
+            }
+        });
+    }
+
+    fn ticket(key: &str, status: &str) -> Ticket {
+        Ticket {
+            key: key.to_string(),
+            status: status.to_string(),
+            summary: format!("Summary of {}", key),
+            // Already detailed, so opening it doesn't fetch
+            description: Some((1..=60).map(|i| format!("Line {} of the description\n", i)).collect()),
+            comments: Some(vec![Comment {
+                author: "Ada Lovelace".to_string(),
+                created: "2025-03-01T10:00:00.000+0000".to_string(),
+                body: "Reproduced on staging".to_string(),
+                public: None,
+                id: "1".to_string(),
+                ari: None,
+                reactions: Vec::new(),
+            }]),
+            ..Default::default()
+        }
+    }
+
+    // A demo board of three cards, so nothing reaches the network: the demo
+    // backend makes up its tickets, and JIRA calls fail for want of a URL
+    fn board() -> Board {
+        let mut board = Board::new(Config::demo());
+        let tickets = vec![ticket("DEMO-1", "To Do"), ticket("DEMO-12", "To Do"), ticket("DEMO-3", "Done")];
+        board.columns = StatusGroups::from_tickets(tickets, GroupBy::Status);
+        board.data_source = "demo".to_string();
+        board
+    }
+
+    fn app() -> App {
+        isolate();
+        App::new(vec![board()], Vec::new())
+    }
+
+    fn run(app: &mut App, actions: &[Action]) {
+        for action in actions {
+            reduce(app, *action);
+        }
+    }
+
+    // Draw a frame, which fills in what the detail view learns on draw
+    fn draw(app: &mut App) {
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).expect("test backend");
+        terminal.draw(|frame| draw_ui(frame, &app.boards, app.focused, app.paused, &mut app.state)).expect("draw");
+    }
+
+    fn selected_key(app: &App) -> String {
+        let board = &app.boards[app.focused];
+        board.columns.get_ticket_by_index(board.selected_index).map(|t| t.key.clone()).unwrap_or_default()
+    }
+
+    fn message(app: &App) -> &str {
+        app.state.status_message.as_deref().unwrap_or_default()
+    }
+
+    // The detail view of the selected card, drawn once
+    fn detail() -> App {
+        let mut app = app();
+        reduce(&mut app, Action::Confirm);
+        draw(&mut app);
+        app
+    }
+
+    #[test]
+    fn quit_leaves_from_anywhere() {
+        let mut app = detail();
+        reduce(&mut app, Action::Quit);
+        assert!(app.quit);
+    }
+
+    #[test]
+    fn back_on_the_bare_board_quits() {
+        let mut app = app();
+        reduce(&mut app, Action::Back);
+        assert!(app.quit);
+    }
+
+    #[test]
+    fn back_closes_what_is_on_top() {
+        let mut app = app();
+        run(&mut app, &[Action::Open(UiMode::Help), Action::Back]);
+        assert_eq!(app.state.mode(), UiMode::Board);
+        run(&mut app, &[Action::Confirm, Action::Back]);
+        assert_eq!(app.state.mode(), UiMode::Board);
+        assert!(app.state.detail_ticket.is_none());
+        assert!(!app.quit);
+    }
+
+    #[test]
+    fn overlays_ignore_other_keys() {
+        let mut app = app();
+        run(&mut app, &[Action::Open(UiMode::Legend), Action::SelectNext, Action::Refresh]);
+        assert_eq!(app.state.mode(), UiMode::Legend);
+        assert_eq!(app.boards[0].selected_index, 0);
+    }
+
+    #[test]
+    fn open_pushes_overlays_but_setup_only_when_needed() {
+        let mut app = app();
+        for mode in [UiMode::Help, UiMode::Stats, UiMode::Activity] {
+            run(&mut app, &[Action::Open(mode)]);
+            assert_eq!(app.state.mode(), mode);
+            run(&mut app, &[Action::Back]);
+        }
+        run(&mut app, &[Action::Open(UiMode::Setup)]);
+        assert_eq!(app.state.mode(), UiMode::Board);
+    }
+
+    #[test]
+    fn select_wraps_around_the_board() {
+        let mut app = app();
+        reduce(&mut app, Action::SelectPrev);
+        assert_eq!(app.boards[0].selected_index, 2);
+        reduce(&mut app, Action::SelectNext);
+        assert_eq!(app.boards[0].selected_index, 0);
+    }
+
+    #[test]
+    fn type_ahead_jumps_and_backspace_undoes_a_digit() {
+        let mut app = app();
+        run(&mut app, &[Action::TypeAhead('1'), Action::TypeAhead('2')]);
+        assert_eq!(selected_key(&app), "DEMO-12");
+        reduce(&mut app, Action::DeleteBack);
+        assert_eq!(app.state.type_ahead.as_ref().map(|(digits, _)| digits.as_str()), Some("1"));
+        // Anything else ends it, and Esc only cancels it
+        reduce(&mut app, Action::Other);
+        assert!(app.state.type_ahead.is_none());
+        run(&mut app, &[Action::TypeAhead('3'), Action::Back]);
+        assert!(app.state.type_ahead.is_none());
+        assert!(!app.quit);
+    }
+
+    #[test]
+    fn switch_board_cycles_focus() {
+        isolate();
+        let mut app = App::new(vec![board(), board()], Vec::new());
+        reduce(&mut app, Action::SwitchBoard);
+        assert_eq!(app.focused, 1);
+        reduce(&mut app, Action::SwitchBoard);
+        assert_eq!(app.focused, 0);
+    }
+
+    #[test]
+    fn toggle_pause() {
+        let mut app = app();
+        reduce(&mut app, Action::TogglePause);
+        assert!(app.paused);
+        reduce(&mut app, Action::TogglePause);
+        assert!(!app.paused);
+    }
+
+    #[test]
+    fn cycle_density_is_remembered_for_the_profile() {
+        let mut app = app();
+        let start = app.boards[0].density;
+        reduce(&mut app, Action::CycleDensity);
+        assert_eq!(app.boards[0].density, start.next());
+        assert_eq!(message(&app), format!("Cards: {}", start.next().name()));
+        assert_eq!(board().density, start.next());
+        run(&mut app, &[Action::CycleDensity, Action::CycleDensity]);
+        assert_eq!(app.boards[0].density, start);
+    }
+
+    #[test]
+    fn zoom_stops_at_either_end() {
+        let mut app = app();
+        run(&mut app, &[Action::Zoom(1), Action::Zoom(1), Action::Zoom(1)]);
+        assert_eq!(app.state.zoom, *ZOOM.end());
+        assert_eq!(message(&app), "Zoom +2");
+        run(&mut app, &[Action::Zoom(-1), Action::Zoom(-1), Action::Zoom(-1), Action::Zoom(-1)]);
+        assert_eq!(app.state.zoom, *ZOOM.start());
+        draw(&mut app);
+    }
+
+    #[test]
+    fn refresh_refetches_the_board() {
+        let mut app = app();
+        reduce(&mut app, Action::Refresh);
+        assert!(app.boards[0].columns.total_tickets() > 3);
+        assert!(app.boards[0].last_error.is_none());
+    }
+
+    #[test]
+    fn refresh_card_and_lane_report_back() {
+        let mut app = app();
+        reduce(&mut app, Action::RefreshCard);
+        assert!(message(&app).contains("DEMO-"), "{}", message(&app));
+        reduce(&mut app, Action::RefreshLane);
+        assert!(message(&app).starts_with("Refreshed"), "{}", message(&app));
+    }
+
+    #[test]
+    fn changes_are_refused_until_jira_is_set_up() {
+        isolate();
+        let mut app = App::new(vec![board()], vec!["JIRA_URL"]);
+        assert_eq!(app.state.mode(), UiMode::Setup);
+        reduce(&mut app, Action::Back);
+        for action in [Action::Refresh, Action::ToggleFlag, Action::OpenInbox, Action::Open(UiMode::QueryBuilder)] {
+            app.state.status_message = None;
+            reduce(&mut app, action);
+            assert!(message(&app).starts_with("Read-only"), "{:?}", action);
+            assert_eq!(app.state.mode(), UiMode::Board);
+        }
+        reduce(&mut app, Action::Open(UiMode::Setup));
+        assert_eq!(app.state.mode(), UiMode::Setup);
+    }
+
+    #[test]
+    fn toggle_flag_keeps_the_card_when_jira_refuses() {
+        let mut app = app();
+        reduce(&mut app, Action::ToggleFlag);
+        assert!(message(&app).starts_with("Flag update of"), "{}", message(&app));
+        assert!(!app.boards[0].columns.get_ticket_by_index(0).is_some_and(|t| t.flagged));
+    }
+
+    #[test]
+    fn edit_summary_types_into_the_card() {
+        let mut app = app();
+        let key = selected_key(&app);
+        run(&mut app, &[Action::EditSummary, Action::DeleteBack, Action::Input('!')]);
+        let edited = app.state.summary_edit.clone().map(|(_, text)| text);
+        assert_eq!(edited, Some(format!("Summary of {}!", &key[..key.len() - 1])));
+        reduce(&mut app, Action::Back);
+        assert!(app.state.summary_edit.is_none());
+    }
+
+    #[test]
+    fn edit_summary_refuses_an_empty_summary() {
+        let mut app = app();
+        reduce(&mut app, Action::EditSummary);
+        app.state.summary_edit.as_mut().expect("editing").1 = "  ".to_string();
+        reduce(&mut app, Action::Confirm);
+        assert!(message(&app).ends_with("needs a summary; left unchanged"));
+    }
+
+    #[test]
+    fn new_ticket_needs_templates() {
+        let mut app = app();
+        reduce(&mut app, Action::NewTicket);
+        assert!(message(&app).starts_with("No templates"));
+        assert_eq!(app.state.mode(), UiMode::Board);
+    }
+
+    #[test]
+    fn new_ticket_picks_a_template_and_types_a_summary() {
+        let mut app = app();
+        let template = TicketTemplate {
+            project: "DEMO".to_string(),
+            issue_type: "Task".to_string(),
+            labels: Vec::new(),
+            components: Vec::new(),
+            description: String::new(),
+        };
+        app.boards[0].config.templates.insert("bug".to_string(), template.clone());
+        app.boards[0].config.templates.insert("chore".to_string(), template);
+        run(&mut app, &[Action::NewTicket, Action::SelectNext]);
+        assert_eq!(app.state.mode(), UiMode::NewTicket);
+        assert_eq!(app.state.template_selected, 1);
+        run(&mut app, &[Action::SelectPrev, Action::PickTemplate(5)]);
+        assert!(app.state.template_summary.is_none());
+        run(&mut app, &[Action::PickTemplate(1), Action::Input('x'), Action::Input('y'), Action::DeleteBack]);
+        assert_eq!(app.state.template_summary.as_deref(), Some("x"));
+        reduce(&mut app, Action::Confirm);
+        assert!(message(&app).starts_with("Create failed"), "{}", message(&app));
+        assert_eq!(app.state.mode(), UiMode::Board);
+    }
+
+    #[test]
+    fn inbox_opens_and_marks_comments_read() {
+        let mut app = app();
+        reduce(&mut app, Action::OpenInbox);
+        assert_eq!(app.state.mode(), UiMode::Inbox);
+        let inbox = app.state.inbox.as_mut().expect("inbox");
+        assert!(inbox.error.is_some());
+        inbox.items = (1..=2).map(|i| WatchedComment {
+            id: i.to_string(),
+            key: format!("DEMO-{}", i),
+            summary: String::new(),
+            author: "Grace Hopper".to_string(),
+            created: String::new(),
+            body: String::new(),
+            mentions_me: false,
+        }).collect();
+        run(&mut app, &[Action::SelectNext, Action::ToggleRead]);
+        let inbox = app.state.inbox.as_ref().expect("inbox");
+        assert_eq!(inbox.selected, 1);
+        assert_eq!(inbox.unread_count(), 1);
+        run(&mut app, &[Action::SelectPrev, Action::MarkAllRead]);
+        assert_eq!(app.state.inbox.as_ref().map(Inbox::unread_count), Some(0));
+        reduce(&mut app, Action::Reload);
+        assert!(app.state.inbox.as_ref().is_some_and(|inbox| inbox.items.is_empty()));
+        reduce(&mut app, Action::Back);
+        assert!(app.state.inbox.is_none());
+    }
+
+    #[test]
+    fn releases_narrow_the_board() {
+        let mut app = app();
+        reduce(&mut app, Action::OpenReleases);
+        assert_eq!(app.state.mode(), UiMode::Releases);
+        let releases = app.state.releases.as_mut().expect("releases");
+        releases.versions = ["2.4", "2.5"].into_iter().map(|name| Release {
+            name: Some(name.to_string()),
+            release_date: None,
+            released: false,
+            overdue: false,
+            done: 0,
+            total: 0,
+        }).collect();
+        run(&mut app, &[Action::SelectNext, Action::SelectNext, Action::SelectPrev]);
+        assert_eq!(app.state.releases.as_ref().map(|r| r.selected), Some(1));
+        reduce(&mut app, Action::Confirm);
+        assert_eq!(app.state.mode(), UiMode::Board);
+        assert_eq!(app.boards[0].filter.as_ref().map(|(label, _)| label.as_str()), Some("2.5"));
+        // Esc shows the whole board again rather than quitting
+        reduce(&mut app, Action::Back);
+        assert!(app.boards[0].filter.is_none());
+        assert!(!app.quit);
+    }
+
+    #[test]
+    fn releases_reload_keeps_the_selection_in_range() {
+        let mut app = app();
+        reduce(&mut app, Action::OpenReleases);
+        app.state.releases.as_mut().expect("releases").selected = 40;
+        reduce(&mut app, Action::Reload);
+        let releases = app.state.releases.as_ref().expect("releases");
+        assert!(releases.selected < releases.versions.len().max(1));
+    }
+
+    #[test]
+    fn timesheet_moves_between_weeks_and_logs_work() {
+        let mut app = app();
+        reduce(&mut app, Action::OpenTimesheet);
+        assert_eq!(app.state.mode(), UiMode::Timesheet);
+        let week = app.state.timesheet.as_ref().map(|t| t.week_start).expect("timesheet");
+        reduce(&mut app, Action::ShiftWeek(-1));
+        assert_eq!(app.state.timesheet.as_ref().map(|t| t.week_start), Some(week - chrono::Days::new(7)));
+        reduce(&mut app, Action::Reload);
+
+        let timesheet = app.state.timesheet.as_mut().expect("timesheet");
+        timesheet.rows = ["DEMO-1", "DEMO-3"].into_iter().map(|key| Row {
+            key: key.to_string(),
+            summary: String::new(),
+            days: [0; 7],
+        }).collect();
+        run(&mut app, &[Action::SelectNext, Action::SelectPrev, Action::SelectPrev]);
+        assert_eq!(app.state.timesheet.as_ref().map(|t| t.selected), Some(1));
+        run(&mut app, &[Action::LogWork, Action::Input('2'), Action::Input('h'), Action::Input('x'), Action::DeleteBack]);
+        assert_eq!(app.state.timesheet.as_ref().and_then(|t| t.log_input.clone()).as_deref(), Some("2h"));
+        reduce(&mut app, Action::Confirm);
+        assert!(app.state.timesheet.as_ref().is_some_and(|t| t.log_input.is_none()));
+        assert!(!message(&app).is_empty());
+        reduce(&mut app, Action::Back);
+        assert!(app.state.timesheet.is_none());
+    }
+
+    #[test]
+    fn copy_snippet_copies_the_selected_or_open_ticket() {
+        let mut app = app();
+        let key = selected_key(&app);
+        reduce(&mut app, Action::CopySnippet);
+        assert_eq!(message(&app), format!("Copied {} for Slack", key));
+        let mut app = detail();
+        reduce(&mut app, Action::CopySnippet);
+        assert_eq!(message(&app), format!("Copied {} for Slack", key));
+        assert_eq!(app.state.mode(), UiMode::Detail);
+    }
+
+    #[test]
+    fn show_diff_opens_an_overlay() {
+        let mut app = app();
+        reduce(&mut app, Action::ShowDiff);
+        assert_eq!(app.state.mode(), UiMode::BoardDiff);
+        assert!(app.state.board_diff.is_some());
+        reduce(&mut app, Action::Back);
+        assert!(app.state.board_diff.is_none());
+    }
+
+    #[test]
+    fn diff_ages_beyond_any_date_are_refused() {
+        let day_ago = history::parse_since("24h").expect("an age");
+        assert!((Local::now() - day_ago - chrono::Duration::hours(24)).num_seconds().abs() < 5);
+        for age in ["999999999999d", "9223372036854775807m", "99999999999999w"] {
+            assert!(history::parse_since(age).unwrap_err().starts_with("Can't read"), "{}", age);
+        }
+    }
+
+    #[test]
+    fn checklist_items_are_picked_and_ticked() {
+        let mut app = detail();
+        reduce(&mut app, Action::ToggleCheckItem);
+        assert!(message(&app).contains("Tab to pick"));
+        app.state.detail_ticket.as_mut().expect("ticket").checklist =
+            checklist::field_items("Definition of done\n* [x] Tests pass\n- [ ] Docs updated");
+        draw(&mut app);
+        run(&mut app, &[Action::PickCheckItem(false), Action::PickCheckItem(false)]);
+        assert_eq!(app.state.checklist_selected, Some(0));
+        assert_eq!(app.state.current_section().map(|(_, s)| s), Some(DetailSection::Checklist));
+        // No JIRA to write to, so it stays as it was
+        reduce(&mut app, Action::ToggleCheckItem);
+        assert!(message(&app).contains("Checklist update"));
+        assert!(app.state.detail_ticket.as_ref().expect("ticket").checklist[0].done);
+        assert_eq!(
+            checklist::set_field_item("* [x] Tests pass\n- [ ] Docs updated", 1, "Docs updated", true).as_deref(),
+            Some("* [x] Tests pass\n- [x] Docs updated"),
+        );
+        // Line endings and the last newline are kept
+        assert_eq!(
+            checklist::set_field_item("* [X] Tests pass\r\n- [done] Docs updated\r\n", 1, "Docs updated", false).as_deref(),
+            Some("* [X] Tests pass\r\n- [open] Docs updated\r\n"),
+        );
+        // A line added above it since it was loaded moves the item
+        assert_eq!(checklist::set_field_item("* [ ] Lint\n* [x] Tests pass\n- [ ] Docs updated", 1, "Docs updated", true), None);
+        assert_eq!(checklist::set_field_item("* [x] Tests pass", 3, "Docs updated", true), None);
+
+        // A reload with a shorter checklist doesn't leave a stale pick
+        app.state.checklist_selected = Some(1);
+        app.state.detail_ticket.as_mut().expect("ticket").checklist.truncate(1);
+        reduce(&mut app, Action::ToggleCheckItem);
+        assert!(message(&app).contains("Tab to pick"));
+        reduce(&mut app, Action::Back);
+        assert_eq!(app.state.checklist_selected, None);
+    }
+
+    #[test]
+    fn reminders_are_set_edited_and_cleared() {
+        let mut app = app();
+        let key = selected_key(&app);
+        reduce(&mut app, Action::SetReminder);
+        assert!(typing(&app.state));
+        paste(&mut app, "2020-01-02 check the deploy");
+        reduce(&mut app, Action::Confirm);
+        let reminder = app.state.reminders.get(&key).expect("reminder").clone();
+        assert_eq!((reminder.due.as_str(), reminder.note.as_str()), ("2020-01-02", "check the deploy"));
+        assert!(Reminders::load().due(Local::now().date_naive()).contains(&key.as_str()));
+
+        // Opens on what's set, and an empty day clears it
+        reduce(&mut app, Action::SetReminder);
+        assert_eq!(app.state.reminder_input.as_ref().map(|(_, text)| text.as_str()), Some("2020-01-02 check the deploy"));
+        for _ in 0..40 {
+            reduce(&mut app, Action::DeleteBack);
+        }
+        reduce(&mut app, Action::Confirm);
+        assert!(app.state.reminders.get(&key).is_none());
+        assert!(message(&app).contains("cleared"));
+
+        let thursday = chrono::NaiveDate::from_ymd_opt(2026, 10, 15).expect("date");
+        assert_eq!(reminders::parse("thu", thursday).map(|r| r.due), Ok("2026-10-22".to_string()));
+        assert_eq!(reminders::parse("Friday ping Bob", thursday).map(|r| (r.due, r.note)), Ok(("2026-10-16".to_string(), "ping Bob".to_string())));
+        assert!(reminders::parse("someday", thursday).is_err());
+        // Too far ahead to be a date is unreadable, not a crash
+        assert!(reminders::parse("100000000d", thursday).unwrap_err().starts_with("Can't read '100000000d'"));
+        assert!(reminders::parse("9999999999999w", thursday).is_err());
+        assert_eq!(reminders::parse("2w", thursday).map(|r| r.due), Ok("2026-10-29".to_string()));
+    }
+
+    #[test]
+    fn hygiene_flags_tickets_breaking_rules() {
+        use crate::config::HygieneRule;
+        use crate::model::{Sprint, SprintState, TicketType};
+        let mut bug = ticket("DEMO-20", "In Progress");
+        bug.ticket_type = TicketType::Bug;
+        bug.status_category = Some(StatusCategory::InProgress);
+        bug.assignee = "unassigned".to_string();
+        bug.sprint = Some(Sprint { name: "Sprint 4".to_string(), state: SprintState::Active });
+        let rules = Config::default().hygiene.rules;
+        assert_eq!(crate::hygiene::problems(&bug, &rules), rules);
+        bug.status_category = Some(StatusCategory::Done);
+        assert!(crate::hygiene::problems(&bug, &rules).is_empty());
+        assert!(!HygieneRule::BugWithoutPriority.broken_by(&ticket("DEMO-21", "To Do")));
+
+        let mut app = app();
+        reduce(&mut app, Action::Open(UiMode::Hygiene));
+        draw(&mut app);
+        reduce(&mut app, Action::Back);
+        assert_eq!(app.state.mode(), UiMode::Board);
+    }
+
+    #[test]
+    fn blockers_follow_only_blocking_links() {
+        let mut app = detail();
+        let link = |relation: &str, key: &str| IssueLink {
+            relation: relation.to_string(),
+            key: key.to_string(),
+            summary: String::new(),
+            status: "To Do".to_string(),
+        };
+        app.state.detail_ticket.as_mut().expect("ticket").links = Some(vec![
+            link("is blocked by", "DEMO-7"),
+            link("relates to", "DEMO-8"),
+            link("blocks", "DEMO-9"),
+        ]);
+        reduce(&mut app, Action::ShowBlockers);
+        assert_eq!(app.state.mode(), UiMode::Blockers);
+        let chain = app.state.blockers.as_ref().expect("blockers").as_ref().expect("chain");
+        assert_eq!(chain.blocked_by.iter().map(|n| n.key.as_str()).collect::<Vec<_>>(), ["DEMO-7"]);
+        assert_eq!(chain.blocks.iter().map(|n| n.key.as_str()).collect::<Vec<_>>(), ["DEMO-9"]);
+        draw(&mut app);
+        reduce(&mut app, Action::Back);
+        assert_eq!(app.state.mode(), UiMode::Detail);
+        assert!(app.state.blockers.is_none());
+    }
+
+    #[test]
+    fn show_aging_charts_the_in_progress_tickets() {
+        let mut app = app();
+        let key = selected_key(&app);
+        app.boards[0].columns.update(&key, |t| t.status_category = Some(StatusCategory::InProgress));
+        reduce(&mut app, Action::ShowAging);
+        assert_eq!(app.state.mode(), UiMode::Aging);
+        // Asked JIRA when it entered its status, which the demo can't answer
+        let aging = app.state.aging.as_ref().expect("aging");
+        assert!(aging.columns.is_empty());
+        assert!(aging.error.is_some());
+        draw(&mut app);
+        reduce(&mut app, Action::Back);
+        assert_eq!(app.state.mode(), UiMode::Board);
+        assert!(app.state.aging.is_none());
+    }
+
+    #[test]
+    fn show_aging_without_work_in_progress_is_empty() {
+        let mut app = app();
+        reduce(&mut app, Action::ShowAging);
+        assert_eq!(app.state.mode(), UiMode::Aging);
+        let aging = app.state.aging.as_ref().expect("aging");
+        assert!(aging.columns.is_empty() && aging.error.is_none());
+        draw(&mut app);
+    }
+
+    #[test]
+    fn raw_payload_needs_debug() {
+        let mut app = app();
+        reduce(&mut app, Action::ShowRawPayload);
+        assert!(message(&app).contains("--debug"));
+        assert_eq!(app.state.mode(), UiMode::Board);
+    }
+
+    #[test]
+    fn raw_payload_scrolls() {
+        let mut app = app();
+        app.state.raw_payload = Some(RawPayload { key: "DEMO-1".to_string(), json: "{}".to_string(), scroll: 0, height: 5 });
+        app.state.push(UiMode::RawPayload);
+        let scroll = |app: &App| app.state.raw_payload.as_ref().map(|p| p.scroll);
+        run(&mut app, &[Action::ScrollDown(1), Action::PageDown]);
+        assert_eq!(scroll(&app), Some(6));
+        run(&mut app, &[Action::ScrollUp(2), Action::PageUp]);
+        assert_eq!(scroll(&app), Some(0));
+        run(&mut app, &[Action::ScrollBottom, Action::ScrollDown(1)]);
+        assert_eq!(scroll(&app), Some(usize::MAX));
+        reduce(&mut app, Action::ScrollTop);
+        assert_eq!(scroll(&app), Some(0));
+        reduce(&mut app, Action::Back);
+        assert!(app.state.raw_payload.is_none());
+    }
+
+    #[test]
+    fn setup_writes_a_sample_config_once() {
+        isolate();
+        let mut app = App::new(vec![board()], vec!["JIRA_URL"]);
+        reduce(&mut app, Action::WriteSampleConfig);
+        assert!(message(&app).starts_with("Wrote") || message(&app).ends_with("already exists; edit it and save"), "{}", message(&app));
+        reduce(&mut app, Action::WriteSampleConfig);
+        assert!(message(&app).ends_with("already exists; edit it and save"));
+        reduce(&mut app, Action::Confirm);
+        assert_eq!(app.state.mode(), UiMode::Board);
+    }
+
+    #[test]
+    fn query_builder_edits_fields_and_tries_the_query() {
+        let mut app = app();
+        reduce(&mut app, Action::Open(UiMode::QueryBuilder));
+        // Clear the "me" and "Not done" it starts with
+        app.state.query_builder.assignee.clear();
+        run(&mut app, &[Action::NextField, Action::NextField, Action::CycleValue(false), Action::SaveProfile]);
+        assert_eq!(message(&app), "Set at least one filter first");
+        run(&mut app, &[Action::NextField, Action::PrevField, Action::PrevField, Action::PrevField]);
+        assert_eq!(app.state.query_builder.selected, 0);
+        run(&mut app, &[Action::Input('D'), Action::Input('X'), Action::DeleteBack, Action::Input('E')]);
+        assert_eq!(app.state.query_builder.project, "DE");
+        run(&mut app, &[Action::NextField, Action::NextField, Action::CycleValue(true)]);
+        assert_eq!(app.state.query_builder.status, 1);
+        run(&mut app, &[Action::CycleValue(false)]);
+        assert_eq!(app.state.query_builder.status, 0);
+        reduce(&mut app, Action::Confirm);
+        assert_eq!(app.state.mode(), UiMode::Board);
+        assert_eq!(app.boards[0].config.query.name, "builder");
+    }
+
+    #[test]
+    fn known_users_are_saved_and_stand_in_for_searches() {
+        isolate();
+        users::remember("712020:zed", "Zed Quinn");
+        users::remember("zed@example.com", "Zed Quinn");
+        users::save();
+        let saved = std::fs::read_to_string(users::cache_path().expect("data dir")).expect("users.json");
+        assert!(saved.contains("712020:zed") && saved.contains("zed@example.com"));
+
+        // An email isn't an account id, and a shared name is no answer
+        assert_eq!(users::account_id("zed quinn").as_deref(), Some("712020:zed"));
+        users::remember("712020:other-zed", "Zed Quinn");
+        assert_eq!(users::account_id("Zed Quinn"), None);
+        users::remember("712020:yan", "Yan Ortiz");
+        let builder = QueryBuilder { assignee: "Yan Ortiz".to_string(), ..QueryBuilder::default() };
+        assert_eq!(builder_jql(&Config::demo(), &builder).as_deref(), Some("assignee = \"712020:yan\" ORDER BY updated DESC"));
+    }
+
+    #[test]
+    fn query_builder_names_a_profile() {
+        let mut app = app();
+        run(&mut app, &[Action::Open(UiMode::QueryBuilder), Action::Input('D'), Action::SaveProfile]);
+        run(&mut app, &[Action::Input('m'), Action::Input(' '), Action::Input('e')]);
+        assert_eq!(app.state.query_builder.profile_name.as_deref(), Some("me"));
+        reduce(&mut app, Action::Back);
+        assert!(app.state.query_builder.profile_name.is_none());
+        assert_eq!(app.state.mode(), UiMode::QueryBuilder);
+    }
+
+    #[test]
+    fn confirm_opens_the_selected_ticket() {
+        let mut app = app();
+        let key = selected_key(&app);
+        reduce(&mut app, Action::Confirm);
+        assert_eq!(app.state.mode(), UiMode::Detail);
+        assert_eq!(app.state.detail_ticket.as_ref().map(|t| t.key.clone()), Some(key));
+    }
+
+    #[test]
+    fn detail_scrolls() {
+        let mut app = detail();
+        run(&mut app, &[Action::ScrollDown(3), Action::ScrollUp(1)]);
+        assert_eq!(app.state.detail_scroll, 2);
+        run(&mut app, &[Action::PageDown, Action::PageUp, Action::PageUp]);
+        assert_eq!(app.state.detail_scroll, 0);
+        reduce(&mut app, Action::HalfPage(true));
+        let half = app.state.detail_scroll;
+        assert!(half > 0);
+        reduce(&mut app, Action::HalfPage(false));
+        assert_eq!(app.state.detail_scroll, 0);
+        reduce(&mut app, Action::ScrollBottom);
+        assert_eq!(app.state.detail_scroll, app.state.detail_length - app.state.detail_height);
+        reduce(&mut app, Action::ScrollTop);
+        assert_eq!(app.state.detail_scroll, 0);
+    }
+
+    #[test]
+    fn detail_moves_between_sections() {
+        let mut app = detail();
+        reduce(&mut app, Action::JumpToComments);
+        let comments = app.state.detail_scroll;
+        assert!(comments > 0);
+        reduce(&mut app, Action::PrevSection);
+        assert!(app.state.detail_scroll < comments);
+        reduce(&mut app, Action::NextSection);
+        assert_eq!(app.state.detail_scroll, comments);
+    }
+
+    #[test]
+    fn prefix_waits_for_the_next_key() {
+        let mut app = detail();
+        reduce(&mut app, Action::Prefix(']'));
+        assert_eq!(app.state.pending_key, Some(']'));
+        reduce(&mut app, Action::Other);
+        assert_eq!(app.state.pending_key, None);
+    }
+
+    #[test]
+    fn go_top_then_x_opens_the_first_visible_link() {
+        let mut app = detail();
+        run(&mut app, &[Action::ScrollDown(4), Action::GoTop]);
+        assert_eq!(app.state.detail_scroll, 0);
+        reduce(&mut app, Action::OpenVisibleLink);
+        // `gx` doesn't scroll; this ticket has no links
+        assert_eq!(app.state.detail_scroll, 4);
+        assert_eq!(message(&app), "No link on screen");
+        // Without `g` first, `x` does nothing
+        run(&mut app, &[Action::OpenVisibleLink]);
+        assert!(app.state.status_message.is_none());
+    }
+
+    #[test]
+    fn open_link_names_a_missing_link() {
+        let mut app = detail();
+        reduce(&mut app, Action::OpenLink(2));
+        assert_eq!(message(&app), "No link [3]");
+    }
+
+    #[test]
+    fn open_link_refuses_anything_but_web_pages() {
+        let mut app = detail();
+        app.state.detail_ticket.as_mut().unwrap().urls = vec!["file:///etc/passwd".to_string()];
+        reduce(&mut app, Action::OpenLink(0));
+        assert_eq!(message(&app), "Could not open file:///etc/passwd: only http and https links are opened");
+    }
+
+    #[test]
+    fn search_finds_and_steps_through_matches() {
+        let mut app = detail();
+        run(&mut app, &[Action::StartSearch, Action::Input('L'), Action::Input('i'), Action::Input('x'), Action::DeleteBack]);
+        assert_eq!(app.state.search_input.as_deref(), Some("Li"));
+        reduce(&mut app, Action::Confirm);
+        assert_eq!(app.state.search_query.as_deref(), Some("Li"));
+        draw(&mut app);
+        assert!(app.state.search_matches.len() > 1);
+        let first = app.state.detail_scroll;
+        reduce(&mut app, Action::NextMatch);
+        assert!(app.state.detail_scroll > first);
+        reduce(&mut app, Action::PrevMatch);
+        assert_eq!(app.state.detail_scroll, first);
+        // Esc clears the search before it closes the ticket
+        reduce(&mut app, Action::Back);
+        assert!(app.state.search_query.is_none());
+        assert_eq!(app.state.mode(), UiMode::Detail);
+    }
+
+    #[test]
+    fn toggle_watchers() {
+        let mut app = detail();
+        reduce(&mut app, Action::ToggleWatchers);
+        assert!(app.state.show_watchers);
+        // Nothing to fetch them from
+        assert!(!message(&app).is_empty());
+        reduce(&mut app, Action::ToggleWatchers);
+        assert!(!app.state.show_watchers);
+    }
+
+    #[test]
+    fn comments_are_drafted_and_sent() {
+        let mut app = detail();
+        run(&mut app, &[Action::StartComment, Action::ToggleInternal]);
+        assert!(!app.state.comment_internal);
+        run(&mut app, &[Action::Input('\t'), Action::Input('o'), Action::Input('k'), Action::DeleteBack, Action::Input('\n')]);
+        assert_eq!(app.state.comment_draft.as_deref(), Some("    o\n"));
+        reduce(&mut app, Action::Confirm);
+        assert!(message(&app).starts_with("Comment failed"), "{}", message(&app));
+        assert!(app.state.comment_draft.is_some());
+        reduce(&mut app, Action::Back);
+        assert!(app.state.comment_draft.is_none());
+        assert_eq!(app.state.mode(), UiMode::Detail);
+    }
+
+    #[test]
+    fn paste_keeps_lines_in_a_comment() {
+        let mut app = detail();
+        reduce(&mut app, Action::StartComment);
+        paste(&mut app, "Steps:\r\n1. Open\r\n\tthe board\u{7}");
+        assert_eq!(app.state.comment_draft.as_deref(), Some("Steps:\n1. Open\n    the board"));
+    }
+
+    #[test]
+    fn paste_joins_lines_in_a_single_line_field() {
+        let mut app = app();
+        reduce(&mut app, Action::Open(UiMode::QueryBuilder));
+        app.state.query_builder.project.clear();
+        paste(&mut app, "DEMO,\n  OPS\n");
+        assert_eq!(app.state.query_builder.project, "DEMO, OPS");
+        // Still open: the newline didn't submit it
+        assert_eq!(app.state.mode(), UiMode::QueryBuilder);
+    }
+
+    #[test]
+    fn paste_with_no_field_open_does_nothing() {
+        let mut app = app();
+        paste(&mut app, "q");
+        assert!(!app.quit);
+        assert_eq!(app.state.mode(), UiMode::Board);
+    }
+
+    #[test]
+    fn reply_quotes_the_comment_on_screen() {
+        let mut app = detail();
+        reduce(&mut app, Action::Reply);
+        assert_eq!(message(&app), "No comment on screen");
+        run(&mut app, &[Action::JumpToComments, Action::Reply]);
+        assert_eq!(app.state.comment_draft.as_deref(), Some("> Reproduced on staging\n\n"));
+    }
+
+    #[test]
+    fn thumbs_up_needs_jira_cloud() {
+        let mut app = detail();
+        run(&mut app, &[Action::JumpToComments, Action::ThumbsUp]);
+        assert_eq!(message(&app), "Reactions need JIRA Cloud");
+    }
+
+    #[test]
+    fn assignee_filter_cycles_through_everyone_on_the_board() {
+        let mut app = app();
+        let names = [("DEMO-1", "Bob"), ("DEMO-12", "Alice"), ("DEMO-3", "Bob")];
+        for (key, name) in names {
+            app.boards[0].columns.update(key, |t| t.assignee = name.to_string());
+        }
+        reduce(&mut app, Action::CycleAssignee);
+        assert_eq!(app.boards[0].assignee.as_deref(), Some("Alice"));
+        assert_eq!(app.boards[0].columns.total_tickets(), 1);
+        reduce(&mut app, Action::CycleAssignee);
+        assert_eq!(app.boards[0].assignee.as_deref(), Some("Bob"));
+        assert_eq!(app.boards[0].columns.total_tickets(), 2);
+        // After the last one, everyone again
+        reduce(&mut app, Action::CycleAssignee);
+        assert!(app.boards[0].assignee.is_none());
+        assert_eq!(app.boards[0].columns.total_tickets(), 3);
+        // Esc clears it rather than quitting
+        run(&mut app, &[Action::CycleAssignee, Action::Back]);
+        assert!(app.boards[0].assignee.is_none());
+        assert!(!app.quit);
+    }
+
+    #[test]
+    fn actions_jira_would_refuse_say_why_instead() {
+        let mut app = detail();
+        app.state.permissions.set("DEMO", Some(vec!["EDIT_ISSUES".to_string()]));
+        reduce(&mut app, Action::StartComment);
+        assert!(app.state.comment_draft.is_none());
+        assert_eq!(message(&app), "You can't comment on DEMO tickets: the project doesn't give you Add Comments");
+        run(&mut app, &[Action::JumpToComments, Action::Reply]);
+        assert!(app.state.comment_draft.is_none());
+        // Editing is allowed, so it goes out (and fails offline)
+        run(&mut app, &[Action::Back, Action::ToggleFlag]);
+        assert!(message(&app).starts_with("Flag update of"), "{}", message(&app));
+    }
+
+
+    #[test]
+    fn metadata_is_fetched_again_after_the_ttl_and_reload_clears_it() {
+        isolate();
+        let config = Config::demo();
+        let fell_back = |project: &str| {
+            let note = format!("Using cached metadata of {}:", project);
+            activity::entries().iter().any(|e| e.message.starts_with(&note))
+        };
+        let meta = metadata::ProjectMeta {
+            fetched: Local::now().to_rfc3339(),
+            issue_types: vec!["Task".to_string()],
+            priorities: Vec::new(),
+            users: Vec::new(),
+            transitions: Default::default(),
+            permissions: None,
+        };
+        metadata::save("TTLFRESH", &meta);
+        let expired = Local::now() - chrono::Duration::hours(config.metadata.ttl_hours as i64 + 1);
+        metadata::save("TTLSTALE", &metadata::ProjectMeta { fetched: expired.to_rfc3339(), ..meta.clone() });
+
+        // Within the TTL the copy answers without asking JIRA
+        assert_eq!(metadata::project(&config, "TTLFRESH").expect("cached").issue_types, ["Task"]);
+        assert!(!fell_back("TTLFRESH"));
+        // Past it JIRA is asked, and as it can't be reached the old copy answers
+        assert_eq!(metadata::project(&config, "TTLSTALE").expect("stale copy").issue_types, ["Task"]);
+        assert!(fell_back("TTLSTALE"));
+
+        assert_eq!(metadata::reload(), Ok(2));
+        assert!(metadata::project(&config, "TTLFRESH").is_err());
+    }
+
+}
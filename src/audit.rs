@@ -0,0 +1,114 @@
+// A record of every request that changes something in JIRA: when it was
+// sent, what it called, the shape of what it sent and how it went. It's
+// appended to audit.log in the data dir and never trimmed, so there is an
+// answer to "who changed this from kanbars?"; `kanbars audit` prints it.
+use crate::activity::{self, Kind};
+use crate::config::Config;
+use chrono::{Local, SecondsFormat};
+use reqwest::blocking::Request;
+use reqwest::Method;
+use std::error::Error;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+// Endpoints that take a POST but only read
+const READ_POSTS: [&str; 2] = ["/search/approximate-count", "/search/jql"];
+
+fn audit_path() -> Option<PathBuf> {
+    Some(Config::data_dir()?.join("audit.log"))
+}
+
+/// A write on its way to JIRA, recorded once its outcome is known
+#[derive(Debug)]
+pub struct Call {
+    method: Method,
+    endpoint: String,
+    payload: String,
+}
+
+impl Call {
+    /// The call `request` makes, or None when it only reads
+    pub fn of(request: &Request) -> Option<Self> {
+        let url = request.url();
+        let reads = *request.method() == Method::GET
+            || *request.method() == Method::HEAD
+            || READ_POSTS.iter().any(|path| url.path().ends_with(path));
+        if reads {
+            return None;
+        }
+        Some(Call {
+            method: request.method().clone(),
+            endpoint: url.as_str().split('?').next().unwrap_or_default().to_string(),
+            payload: summarize(request.body().and_then(|body| body.as_bytes())),
+        })
+    }
+
+    /// Append the call with its `result`, e.g. "204 No Content". A log that
+    /// can't be written is noted in the activity log rather than failing
+    /// the change itself.
+    pub fn record(self, result: &str) {
+        let line = format!(
+            "{}\t{}\t{}\t{}\t{}\n",
+            Local::now().to_rfc3339_opts(SecondsFormat::Secs, false),
+            self.method,
+            self.endpoint,
+            self.payload,
+            result.lines().next().unwrap_or_default(),
+        );
+        let written = audit_path().ok_or("no data directory".to_string()).and_then(|path| {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+            }
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .and_then(|mut file| file.write_all(line.as_bytes()))
+                .map_err(|e| format!("{}: {}", path.display(), e))
+        });
+        if let Err(e) = written {
+            activity::record(Kind::Error, format!("Could not write the audit log: {}", e));
+        }
+    }
+}
+
+// The fields a body sets, one level deep, e.g. "fields{summary,labels}
+// (58 bytes)"; values stay out of the log, since they can be whole comments
+fn summarize(body: Option<&[u8]>) -> String {
+    let Some(body) = body.filter(|b| !b.is_empty()) else {
+        return "-".to_string();
+    };
+    let Ok(serde_json::Value::Object(object)) = serde_json::from_slice(body) else {
+        return format!("{} bytes", body.len());
+    };
+    let keys: Vec<String> = object.iter()
+        .map(|(key, value)| match value {
+            serde_json::Value::Object(inner) if !inner.is_empty() => {
+                format!("{}{{{}}}", key, inner.keys().cloned().collect::<Vec<_>>().join(","))
+            }
+            _ => key.clone(),
+        })
+        .collect();
+    format!("{} ({} bytes)", keys.join(" "), body.len())
+}
+
+/// `kanbars audit`: every write so far, oldest first
+pub fn print() -> Result<(), Box<dyn Error>> {
+    let path = audit_path().ok_or("No data directory to keep the audit log in")?;
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            println!("No changes sent to JIRA yet (the log goes in {})", path.display());
+            return Ok(());
+        }
+        Err(e) => return Err(format!("Could not read {}: {}", path.display(), e).into()),
+    };
+    for line in contents.lines() {
+        let mut parts = line.splitn(5, '\t');
+        let mut next = || parts.next().unwrap_or_default();
+        let (time, method, endpoint, payload, result) = (next(), next(), next(), next(), next());
+        println!("{}  {:<6} {}  {}  → {}", time, method, endpoint, payload, result);
+    }
+    Ok(())
+}
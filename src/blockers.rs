@@ -0,0 +1,102 @@
+// What holds a ticket up, and what it holds up: its "is blocked by" and
+// "blocks" links, followed from ticket to ticket a few levels deep, so the
+// real critical path shows without opening each ticket along it.
+use crate::config::Config;
+use crate::jira_api;
+use crate::model::IssueLink;
+use std::collections::HashSet;
+
+// Links followed away from the ticket
+const MAX_DEPTH: usize = 4;
+
+// Tickets fetched for one chain at most, so a tangled project can't stall
+// the board
+const MAX_FETCHES: usize = 40;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    BlockedBy,
+    Blocks,
+}
+
+impl Direction {
+    // Link names as JIRA's built-in "Blocks" link type has them
+    fn matches(self, link: &IssueLink) -> bool {
+        let relation = link.relation.to_lowercase();
+        match self {
+            Direction::BlockedBy => relation.contains("blocked by"),
+            Direction::Blocks => relation == "blocks",
+        }
+    }
+}
+
+/// A ticket in the chain, with the tickets it leads to in turn
+#[derive(Debug)]
+pub struct Node {
+    pub key: String,
+    pub summary: String,
+    pub status: String,
+    pub children: Vec<Node>,
+    pub cut: Option<&'static str>,  // Why the chain stops here although it may go on
+}
+
+#[derive(Debug)]
+pub struct Chain {
+    pub key: String,
+    pub blocked_by: Vec<Node>,
+    pub blocks: Vec<Node>,
+}
+
+struct Walk<'a> {
+    config: &'a Config,
+    fetches: usize,
+    seen: HashSet<String>,
+}
+
+impl Walk<'_> {
+    fn follow(&mut self, links: &[IssueLink], direction: Direction, depth: usize) -> Vec<Node> {
+        links.iter()
+            .filter(|link| direction.matches(link))
+            .map(|link| {
+                let mut node = Node {
+                    key: link.key.clone(),
+                    summary: link.summary.clone(),
+                    status: link.status.clone(),
+                    children: Vec::new(),
+                    cut: None,
+                };
+                if !self.seen.insert(link.key.clone()) {
+                    node.cut = Some("shown above");
+                } else if depth >= MAX_DEPTH || self.fetches >= MAX_FETCHES {
+                    node.cut = Some("not followed further");
+                } else {
+                    self.fetches += 1;
+                    match jira_api::fetch_issue_links(self.config, &link.key) {
+                        Ok(links) => node.children = self.follow(&links, direction, depth + 1),
+                        Err(_) => node.cut = Some("couldn't load its links"),
+                    }
+                }
+                node
+            })
+            .collect()
+    }
+}
+
+/// The chains either side of `key`, starting from its links as already
+/// fetched, or fetching them when there are none at hand
+pub fn load(config: &Config, key: &str, links: Option<&[IssueLink]>) -> Result<Chain, String> {
+    let fetched;
+    let links = match links {
+        Some(links) => links,
+        None => {
+            fetched = jira_api::fetch_issue_links(config, key).map_err(|e| e.to_string())?;
+            &fetched
+        }
+    };
+    let mut walk = Walk { config, fetches: 0, seen: HashSet::from([key.to_string()]) };
+    let blocked_by = walk.follow(links, Direction::BlockedBy, 1);
+    // Each side is a tree of its own
+    walk.seen = HashSet::from([key.to_string()]);
+    let blocks = walk.follow(links, Direction::Blocks, 1);
+    Ok(Chain { key: key.to_string(), blocked_by, blocks })
+}
@@ -0,0 +1,591 @@
+// A board on screen: one query's tickets with their own selection and
+// refresh timer, so split views can update independently
+use crate::activity::{self, Kind};
+use crate::config::{Config, Density, GroupBy};
+use crate::desktop;
+use crate::history;
+use crate::jira::{failure, fetch_tickets, fetch_tickets_paged, Failure, Fetched};
+use crate::jira_api::{ApiError, PageProgress};
+use crate::jql;
+use crate::model::{StatusGroups, Ticket, NO_COMPONENT, NO_SPRINT};
+use crate::rate_limit;
+use crate::telemetry;
+use chrono::{DateTime, Local};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const DEFAULT_REFRESH_SECONDS: u64 = 60;
+/// How soon queries that failed are retried on their own
+const RETRY_DELAY: Duration = Duration::from_secs(15);
+/// How soon to look again when off hours have no end in sight, e.g. a
+/// schedule with no working days
+const OFF_HOURS_RECHECK: Duration = Duration::from_secs(3600);
+/// How long a card that changed in a refresh stays highlighted
+pub const HIGHLIGHT: Duration = Duration::from_secs(10);
+
+fn refresh_interval(config: &Config) -> Duration {
+    Duration::from_secs(config.query.refresh.unwrap_or(DEFAULT_REFRESH_SECONDS))
+}
+
+// Card density picked with `v`, per profile, remembered between runs
+fn densities_path() -> Option<PathBuf> {
+    Some(Config::data_dir()?.join("density.toml"))
+}
+
+fn saved_densities() -> BTreeMap<String, Density> {
+    densities_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| toml::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+// The profile's remembered density, else the configured one
+fn density(config: &Config) -> Density {
+    saved_densities().get(&config.query.name).copied().unwrap_or(config.display.density)
+}
+
+#[derive(Debug)]
+pub struct Board {
+    pub name: String,  // Profile name, shown in the title of split views
+    pub config: Config,
+    pub columns: StatusGroups,
+    pub selected_index: usize,  // Global index across all tickets
+    pub data_source: String,  // Which backend produced the board, e.g. "API"
+    pub total_matches: Option<usize>,  // Set when the board shows only part of the results
+    pub last_update: DateTime<Local>,  // Last successful fetch
+    pub last_refresh: Instant,  // Last fetch attempt
+    pub next_refresh: Instant,
+    pub refresh_interval: Duration,
+    pub last_error: Option<String>,  // Why the latest refresh failed, shown in the title
+    pub api_error: Option<ApiError>,  // Set when the failure needs fixing, e.g. bad credentials or JQL
+    pub loading: Option<Loading>,  // Background fetch in progress
+    pub filter: Option<(String, String)>,  // (label, JQL clause) narrowing the query, e.g. to one release
+    pub assignee: Option<String>,  // Only this assignee's tickets show, cycled with `a`
+    hidden: Vec<Ticket>,  // Tickets the assignee filter keeps off the board
+    pub stale: HashSet<String>,  // Tickets kept from before a refresh that came back only in part
+    pub changed: HashMap<String, Instant>,  // Tickets new or changed in a refresh, and when, for a fading highlight
+    pub density: Density,  // How much each card shows
+    pub unattended: bool,  // On a wallboard: retry failed refreshes soon instead of a whole interval later
+    failed_queries: Vec<String>,  // Queries that came back only in part, retried on their own
+    full_refresh_at: Instant,  // When the whole board is due again while retrying failed queries
+    known_incidents: Option<HashSet<String>>,  // None until the first load, which announces nothing
+}
+
+/// A background fetch and how far it has got
+#[derive(Debug)]
+pub struct Loading {
+    events: mpsc::Receiver<LoadEvent>,
+    pub pages_done: usize,
+    pub pages_total: Option<usize>,
+    pub started: Instant,
+}
+
+enum LoadEvent {
+    Page(PageProgress),
+    Done(Result<Fetched, Failure>),
+}
+
+impl Board {
+    /// An empty board, due for its first refresh
+    pub fn new(config: Config) -> Self {
+        let density = density(&config);
+        Board {
+            name: config.query.name.clone(),
+            columns: StatusGroups::new(config.display.group_by),
+            refresh_interval: refresh_interval(&config),
+            config,
+            selected_index: 0,
+            data_source: String::new(),
+            total_matches: None,
+            last_update: Local::now(),
+            last_refresh: Instant::now(),
+            next_refresh: Instant::now(),
+            last_error: None,
+            api_error: None,
+            loading: None,
+            filter: None,
+            assignee: None,
+            hidden: Vec::new(),
+            stale: HashSet::new(),
+            changed: HashMap::new(),
+            density,
+            unattended: false,
+            failed_queries: Vec::new(),
+            full_refresh_at: Instant::now(),
+            known_incidents: None,
+        }
+    }
+
+    /// The board as last seen, from its snapshot history, while it can't be
+    /// fetched (e.g. before JIRA is set up)
+    pub fn from_cache(config: Config) -> Self {
+        let mut board = Board::new(config);
+        if let Some((time, tickets)) = history::latest(&board.name) {
+            board.set_tickets(tickets);
+            board.data_source = "cache".to_string();
+            board.last_update = time;
+        }
+        board
+    }
+
+    /// Fetch in the background, filling the board page by page as results
+    /// arrive; for boards with nothing on screen yet, where a blocking
+    /// fetch would leave the UI frozen
+    pub fn start_loading(&mut self) {
+        self.restart_timer();
+        let (events, receiver) = mpsc::channel();
+        let config = self.query_config();
+        thread::spawn(move || {
+            let page_events = events.clone();
+            let mut on_page = |progress: PageProgress| {
+                let _ = page_events.send(LoadEvent::Page(progress));
+            };
+            let result = fetch_tickets_paged(&config, Some(&mut on_page)).map_err(failure);
+            let _ = events.send(LoadEvent::Done(result));
+        });
+        self.loading = Some(Loading {
+            events: receiver,
+            pages_done: 0,
+            pages_total: None,
+            started: Instant::now(),
+        });
+    }
+
+    /// Still waiting on the first results, with nothing to show yet
+    pub fn is_starting(&self) -> bool {
+        self.loading.is_some() && self.data_source.is_empty() && self.columns.groups.is_empty()
+    }
+
+    /// Take in whatever a background load has sent since the last call
+    pub fn poll_loading(&mut self) {
+        let Some(ref mut loading) = self.loading else { return };
+        let mut page = None;
+        let mut finished = None;
+        loop {
+            match loading.events.try_recv() {
+                Ok(LoadEvent::Page(progress)) => {
+                    loading.pages_done = progress.pages_done;
+                    loading.pages_total = progress.pages_total;
+                    page = Some(progress.tickets);
+                }
+                Ok(LoadEvent::Done(result)) => {
+                    finished = Some(result);
+                    break;
+                }
+                Err(mpsc::TryRecvError::Empty) => break,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    finished = Some(Err(("Loading stopped unexpectedly".to_string(), None)));
+                    break;
+                }
+            }
+        }
+        if let Some(tickets) = page {
+            self.set_tickets(tickets);
+        }
+        if let Some(result) = finished {
+            self.loading = None;
+            let _ = self.finish_refresh(result);
+        }
+    }
+
+    /// Switch to a reloaded config, refreshing as soon as the stagger allows
+    pub fn reconfigure(&mut self, config: Config) {
+        self.name = config.query.name.clone();
+        self.refresh_interval = refresh_interval(&config);
+        self.density = density(&config);
+        self.config = config;
+        self.next_refresh = Instant::now();
+    }
+    
+    /// Show more or less on each card, remembering the choice for this
+    /// profile. Failing to save it only costs remembering it.
+    pub fn cycle_density(&mut self) {
+        self.density = self.density.next();
+        let Some(path) = densities_path() else { return };
+        let mut densities = saved_densities();
+        densities.insert(self.name.clone(), self.density);
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        if let Ok(contents) = toml::to_string(&densities) {
+            let _ = fs::write(path, contents);
+        }
+    }
+
+    /// Re-run the query, keeping the selection in range. The timer restarts
+    /// even on failure so a broken query doesn't retry in a tight loop, and
+    /// stretches while the API budget runs low.
+    pub fn refresh(&mut self) -> Result<(), Box<dyn Error>> {
+        self.restart_timer();
+        let result = fetch_tickets_paged(&self.query_config(), None).map_err(failure);
+        Ok(self.finish_refresh(result)?)
+    }
+
+    /// The timer's refresh: just the queries that failed while their retry
+    /// is due, the whole board otherwise
+    pub fn refresh_due(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.failed_queries.is_empty() || Instant::now() >= self.full_refresh_at {
+            self.refresh()
+        } else {
+            self.retry_failed()
+        }
+    }
+
+    /// Narrow the board to `filter` (label, JQL clause), or widen it back
+    /// with None, and refresh
+    pub fn set_filter(&mut self, filter: Option<(String, String)>) -> Result<(), Box<dyn Error>> {
+        self.filter = filter;
+        self.selected_index = 0;
+        self.refresh()
+    }
+
+    /// Show only `assignee`'s tickets, or everyone's with None, keeping the
+    /// selection on the same ticket when it still shows
+    pub fn set_assignee(&mut self, assignee: Option<String>) {
+        let selected_key = self.columns.get_ticket_by_index(self.selected_index).map(|t| t.key.clone());
+        let group_by = self.columns.group_by;
+        let mut seen = HashSet::new();
+        let tickets: Vec<Ticket> = std::mem::replace(&mut self.columns, StatusGroups::new(group_by))
+            .groups
+            .into_values()
+            .flatten()
+            .chain(std::mem::take(&mut self.hidden))
+            .filter(|t| seen.insert(t.key.clone()))
+            .collect();
+        self.assignee = assignee;
+        self.set_tickets(tickets);
+        self.selected_index = selected_key
+            .and_then(|key| self.columns.groups.values().flatten().position(|t| t.key == key))
+            .unwrap_or(0);
+    }
+
+    /// The assignees on the board, in order, to cycle the filter through
+    pub fn assignees(&self) -> Vec<String> {
+        let names: BTreeSet<&str> = self.all_tickets().into_iter().map(|t| t.assignee.as_str()).collect();
+        names.into_iter().map(str::to_string).collect()
+    }
+
+    /// Every ticket once, including those the assignee filter hides
+    pub fn all_tickets(&self) -> Vec<&Ticket> {
+        let mut tickets = self.columns.unique_tickets();
+        tickets.extend(&self.hidden);
+        tickets
+    }
+
+    // Put `tickets` in their lanes, setting aside those the assignee filter
+    // hides
+    fn set_tickets(&mut self, tickets: Vec<Ticket>) {
+        let (shown, hidden) = match self.assignee {
+            Some(ref assignee) => tickets.into_iter().partition(|t| &t.assignee == assignee),
+            None => (tickets, Vec::new()),
+        };
+        self.hidden = hidden;
+        self.columns = StatusGroups::from_tickets(shown, self.columns.group_by);
+    }
+
+    // The config to query with, narrowed by any filter
+    fn query_config(&self) -> Config {
+        match self.filter {
+            Some((_, ref clause)) => narrow(self.config.clone(), clause),
+            None => self.config.clone(),
+        }
+    }
+
+    fn restart_timer(&mut self) {
+        self.last_refresh = Instant::now();
+        self.next_refresh = self.last_refresh + self.scheduled_interval() * rate_limit::backoff_factor();
+    }
+    
+    // The usual interval in working hours; outside them the off-hours one,
+    // cut short when working hours start sooner
+    fn scheduled_interval(&self) -> Duration {
+        let schedule = &self.config.refresh;
+        let now = Local::now().naive_local();
+        if schedule.working(now) {
+            return self.refresh_interval;
+        }
+        let until_start = schedule.next_start(now).and_then(|start| (start - now).to_std().ok());
+        let off_hours = Some(Duration::from_secs(schedule.off_hours_seconds)).filter(|d| !d.is_zero());
+        match (off_hours, until_start) {
+            (Some(off_hours), Some(until_start)) => off_hours.min(until_start),
+            (Some(interval), None) | (None, Some(interval)) => interval,
+            (None, None) => OFF_HOURS_RECHECK,
+        }
+    }
+    
+    /// Whether the board is outside working hours, refreshing slowly or not
+    /// at all
+    pub fn off_hours(&self) -> bool {
+        !self.config.refresh.working(Local::now().naive_local())
+    }
+
+    // Show a finished fetch, or keep the old tickets and note why it failed
+    fn finish_refresh(&mut self, result: Result<Fetched, Failure>) -> Result<(), String> {
+        telemetry::record_refresh(self.last_refresh.elapsed(), result.is_ok());
+        let fetched = match result {
+            Ok(fetched) => fetched,
+            Err((e, api_error)) => {
+                // After a 429, try again as soon as JIRA allows rather than
+                // waiting out a whole interval
+                if let Some(wait) = rate_limit::retry_after() {
+                    self.next_refresh = self.last_refresh + wait;
+                } else if self.unattended && api_error.is_none() {
+                    // Most likely the network; nobody is there to press `r`.
+                    // Errors that need fixing, like bad credentials, keep to
+                    // the interval so they don't lock the account.
+                    self.next_refresh = self.next_refresh.min(self.last_refresh + RETRY_DELAY);
+                }
+                self.last_error = Some(e.lines().next().unwrap_or_default().to_string());
+                self.api_error = api_error;
+                activity::record(Kind::Error, format!("{}: refresh failed: {}", self.name, self.last_error.as_deref().unwrap_or_default()));
+                return Err(e);
+            }
+        };
+        self.api_error = None;
+        self.data_source = fetched.source;
+        self.total_matches = fetched.total;
+        self.last_update = Local::now();
+        if fetched.failed.is_empty() {
+            self.last_error = None;
+            self.stale.clear();
+            self.failed_queries.clear();
+            history::record(&self.name, &fetched.tickets);
+            self.note_changes(&fetched.tickets);
+            self.set_tickets(fetched.tickets);
+            activity::record(Kind::Refresh, format!("{}: {} tickets via {}", self.name, self.columns.total_tickets(), self.data_source));
+            self.announce_incidents();
+        } else {
+            // Keep the failed queries' tickets as they were, marked stale, and
+            // retry those queries well before the next full refresh. A partial
+            // board stays out of the history so it doesn't look like churn.
+            self.failed_queries = fetched.failed.iter().map(|(name, _)| name.clone()).collect();
+            let fresh: HashSet<String> = fetched.tickets.iter().map(|t| t.key.clone()).collect();
+            let multi_query = !self.config.query.extra.is_empty();
+            let failed_queries = &self.failed_queries;
+            let mut kept: Vec<Ticket> = self.all_tickets().into_iter()
+                .filter(|t| !fresh.contains(&t.key))
+                .filter(|t| !multi_query || t.queries.iter().any(|q| failed_queries.contains(q)))
+                .cloned()
+                .collect();
+            self.stale = kept.iter().map(|t| t.key.clone()).collect();
+            self.note_changes(&fetched.tickets);
+            kept.extend(fetched.tickets);
+            self.set_tickets(kept);
+            self.note_partial(&fetched.failed);
+            self.full_refresh_at = self.next_refresh;
+            self.next_refresh = self.next_refresh.min(self.last_refresh + RETRY_DELAY);
+            self.announce_incidents();
+        }
+
+        let total = self.columns.total_tickets();
+        if self.selected_index >= total {
+            self.selected_index = total.saturating_sub(1);
+        }
+        Ok(())
+    }
+
+    // Fetch only the queries that failed last time, swapping their stale
+    // tickets for fresh ones
+    fn retry_failed(&mut self) -> Result<(), Box<dyn Error>> {
+        self.last_refresh = Instant::now();
+        self.next_refresh = self.full_refresh_at;
+        let retried = std::mem::take(&mut self.failed_queries);
+        let mut config = self.query_config();
+        let multi_query = !config.query.extra.is_empty();
+        config.query.extra.retain(|q| retried.contains(&q.name));
+        if !retried.contains(&config.query.name) {
+            if config.query.extra.is_empty() {
+                return self.refresh();
+            }
+            let first = config.query.extra.remove(0);
+            config.query.name = first.name;
+            config.query.jql = first.jql;
+        }
+
+        let fetched = match fetch_tickets_paged(&config, None) {
+            Ok(fetched) => fetched,
+            Err(e) => {
+                self.failed_queries = retried;
+                self.next_refresh = self.next_refresh.min(self.last_refresh + RETRY_DELAY);
+                self.last_error = Some(e.to_string().lines().next().unwrap_or_default().to_string());
+                return Err(e);
+            }
+        };
+        self.failed_queries = fetched.failed.iter().map(|(name, _)| name.clone()).collect();
+        let recovered: Vec<String> = retried.into_iter().filter(|q| !self.failed_queries.contains(q)).collect();
+
+        // A single query's tickets come back without its name
+        let mut tickets = fetched.tickets;
+        if multi_query && config.query.extra.is_empty() {
+            for ticket in &mut tickets {
+                ticket.queries = vec![config.query.name.clone()];
+            }
+        }
+        // Fresh copies keep the other queries that matched them
+        for ticket in &mut tickets {
+            if let Some(old) = self.all_tickets().into_iter().find(|t| t.key == ticket.key) {
+                for query in old.queries.iter().filter(|q| !recovered.contains(q)) {
+                    if !ticket.queries.contains(query) {
+                        ticket.queries.push(query.clone());
+                    }
+                }
+            }
+        }
+
+        let fresh: HashSet<String> = tickets.iter().map(|t| t.key.clone()).collect();
+        let still_failed = &self.failed_queries;
+        let stale = &self.stale;
+        let covered = |t: &Ticket| stale.contains(&t.key) && !t.queries.iter().any(|q| still_failed.contains(q))
+            && (multi_query || still_failed.is_empty());
+        let dropped: HashSet<String> = self.all_tickets().into_iter()
+            .filter(|t| fresh.contains(&t.key) || covered(t))
+            .map(|t| t.key.clone())
+            .collect();
+        self.merge(|t| dropped.contains(&t.key), tickets);
+        self.stale.retain(|key| !dropped.contains(key));
+
+        if self.failed_queries.is_empty() {
+            self.last_error = None;
+            activity::record(Kind::Refresh, format!("{}: recovered {}", self.name, recovered.join(", ")));
+        } else {
+            self.note_partial(&fetched.failed);
+            self.next_refresh = self.next_refresh.min(self.last_refresh + RETRY_DELAY);
+        }
+        Ok(())
+    }
+
+    // Say which queries came back only in part, in the title and the log
+    fn note_partial(&mut self, failed: &[(String, String)]) {
+        let names: Vec<&str> = failed.iter().map(|(name, _)| name.as_str()).collect();
+        let error = failed.first().map_or("", |(_, error)| error.lines().next().unwrap_or_default());
+        self.last_error = Some(format!("{} only partly loaded, retrying: {}", names.join(", "), error));
+        activity::record(Kind::Error, format!("{}: {}", self.name, self.last_error.as_deref().unwrap_or_default()));
+    }
+
+    /// Re-fetch one ticket and merge it into the board, which costs far
+    /// less than a full refresh. Returns whether the ticket still matches
+    /// the board's query; if not, it leaves the board.
+    pub fn refresh_ticket(&mut self, key: &str) -> Result<bool, Box<dyn Error>> {
+        let fetched = fetch_tickets(&self.narrowed(&format!("key = {}", jql::quote(key))))?;
+        let still_matches = !fetched.tickets.is_empty();
+        self.merge(|t| t.key == key, fetched.tickets);
+        activity::record(Kind::Refresh, format!("{}: refreshed {}", self.name, key));
+        Ok(still_matches)
+    }
+
+    /// Re-query only the selected ticket's lane and merge the results, for
+    /// quicker updates on big boards. Returns the lane's name and how many
+    /// tickets it now holds.
+    pub fn refresh_lane(&mut self) -> Result<(String, usize), Box<dyn Error>> {
+        let lane = self.columns.lane_of(self.selected_index)
+            .ok_or("No lane selected")?
+            .to_string();
+        let clause = match self.columns.group_by {
+            GroupBy::Status => jql::in_clause("status", &[&lane]),
+            GroupBy::Sprint if lane == NO_SPRINT => "sprint is EMPTY".to_string(),
+            GroupBy::Sprint => jql::in_clause("sprint", &[&lane]),
+            GroupBy::Component if lane == NO_COMPONENT => "component is EMPTY".to_string(),
+            GroupBy::Component => jql::in_clause("component", &[&lane]),
+        };
+        let fetched = fetch_tickets(&self.narrowed(&clause))?;
+
+        // Tickets that moved into the lane leave their old one
+        let fresh_keys: HashSet<String> = fetched.tickets.iter().map(|t| t.key.clone()).collect();
+        let count = fetched.tickets.len();
+        let group_by = self.columns.group_by;
+        self.merge(|t| fresh_keys.contains(&t.key) || in_lane(t, group_by, &lane), fetched.tickets);
+        activity::record(Kind::Refresh, format!("{}: refreshed lane {}, {} tickets", self.name, lane, count));
+        Ok((lane, count))
+    }
+
+    // The board's config with `clause` ANDed onto every query
+    fn narrowed(&self, clause: &str) -> Config {
+        narrow(self.query_config(), clause)
+    }
+
+    // Swap the tickets `replaced` picks for `fresh`, keeping the selection
+    // on the same ticket when it's still there
+    fn merge(&mut self, replaced: impl Fn(&Ticket) -> bool, fresh: Vec<Ticket>) {
+        self.note_changes(&fresh);
+        let selected_key = self.columns.get_ticket_by_index(self.selected_index).map(|t| t.key.clone());
+        let group_by = self.columns.group_by;
+        let old = std::mem::replace(&mut self.columns, StatusGroups::new(group_by));
+        // Component lanes hold copies of some tickets; keep one
+        let mut seen = HashSet::new();
+        let mut tickets: Vec<Ticket> = old.groups.into_values()
+            .flatten()
+            .chain(std::mem::take(&mut self.hidden))
+            .filter(|t| !replaced(t) && seen.insert(t.key.clone()))
+            .collect();
+        tickets.extend(fresh);
+        self.set_tickets(tickets);
+
+        let position = selected_key.and_then(|key| {
+            self.columns.groups.values().flatten().position(|t| t.key == key)
+        });
+        let total = self.columns.total_tickets();
+        self.selected_index = position.unwrap_or(self.selected_index.min(total.saturating_sub(1)));
+        self.announce_incidents();
+    }
+
+    // Remember tickets that are new or were updated since the board was last
+    // fetched, so their cards can light up for a moment. The first fetch
+    // has nothing to compare with and marks nothing.
+    fn note_changes(&mut self, tickets: &[Ticket]) {
+        self.changed.retain(|_, at| at.elapsed() < HIGHLIGHT);
+        if self.known_incidents.is_none() {
+            return;
+        }
+        let before: HashMap<&str, (&str, Option<&str>)> = self.columns.groups.values()
+            .flatten()
+            .chain(&self.hidden)
+            .map(|t| (t.key.as_str(), (t.status.as_str(), t.updated.as_deref())))
+            .collect();
+        let now = Instant::now();
+        for ticket in tickets {
+            if before.get(ticket.key.as_str()) != Some(&(ticket.status.as_str(), ticket.updated.as_deref())) {
+                self.changed.insert(ticket.key.clone(), now);
+            }
+        }
+    }
+
+    // Note incidents that weren't on the board before, with a desktop
+    // notification when configured
+    fn announce_incidents(&mut self) {
+        let incidents: Vec<&Ticket> = self.all_tickets().into_iter().filter(|t| t.incident).collect();
+        if let Some(known) = &self.known_incidents {
+            for ticket in incidents.iter().filter(|t| !known.contains(&t.key)) {
+                activity::record(Kind::Change, format!("{}: incident {} {}", self.name, ticket.key, ticket.summary));
+                if self.config.incidents.notify
+                    && let Err(e) = desktop::notify(&format!("Incident {}", ticket.key), &ticket.summary)
+                {
+                    activity::record(Kind::Error, format!("Desktop notification failed: {}", e));
+                }
+            }
+        }
+        self.known_incidents = Some(incidents.iter().map(|t| t.key.clone()).collect());
+    }
+}
+
+// `config` with `clause` ANDed onto every query
+fn narrow(mut config: Config, clause: &str) -> Config {
+    config.query.jql = jql::and(clause, &config.query.jql);
+    for extra in &mut config.query.extra {
+        extra.jql = jql::and(clause, &extra.jql);
+    }
+    config
+}
+
+fn in_lane(ticket: &Ticket, group_by: GroupBy, lane: &str) -> bool {
+    match group_by {
+        GroupBy::Status => ticket.status == lane,
+        GroupBy::Sprint => ticket.sprint.as_ref().map_or(NO_SPRINT, |s| s.name.as_str()) == lane,
+        GroupBy::Component if lane == NO_COMPONENT => ticket.components.is_empty(),
+        GroupBy::Component => ticket.components.iter().any(|c| c == lane),
+    }
+}
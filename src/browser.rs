@@ -0,0 +1,36 @@
+use std::io;
+use std::process::{Command, Stdio};
+use std::thread;
+
+// Open a URL with the platform's default handler, without blocking the UI.
+// Links come from ticket text anyone can write, so only web pages are opened,
+// and never through a shell.
+pub fn open_url(url: &str) -> io::Result<()> {
+    let scheme = url.split_once(':').map(|(scheme, _)| scheme.to_ascii_lowercase());
+    if !matches!(scheme.as_deref(), Some("http" | "https")) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "only http and https links are opened"));
+    }
+    
+    let mut command = if cfg!(target_os = "macos") {
+        let mut c = Command::new("open");
+        c.arg(url);
+        c
+    } else if cfg!(target_os = "windows") {
+        let mut c = Command::new("rundll32");
+        c.args(["url.dll,FileProtocolHandler", url]);
+        c
+    } else {
+        let mut c = Command::new("xdg-open");
+        c.arg(url);
+        c
+    };
+    
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    // The opener hands off and exits; wait on it so it isn't left a zombie
+    thread::spawn(move || child.wait());
+    Ok(())
+}
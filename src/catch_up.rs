@@ -0,0 +1,63 @@
+// The first time the board opens on a new day: what changed since the last
+// session, from the board's snapshots and the comments on tickets you
+// watch, so the day starts caught up.
+use crate::config::Config;
+use crate::history::{self, BoardDiff};
+use crate::jira_api::{self, WatchedComment};
+use crate::model::Ticket;
+use chrono::{DateTime, Local};
+use std::fs;
+use std::path::PathBuf;
+
+// Longest look back for comments, however long you were away
+const MAX_DAYS: i64 = 14;
+
+#[derive(Debug)]
+pub struct CatchUp {
+    pub since: DateTime<Local>,
+    pub board: Result<BoardDiff, String>,
+    pub comments: Result<Vec<WatchedComment>, String>,  // Newest first
+}
+
+fn session_path() -> Option<PathBuf> {
+    Some(Config::data_dir()?.join("last_session"))
+}
+
+/// When the last session ended, if that was before today
+pub fn due() -> Option<DateTime<Local>> {
+    let text = fs::read_to_string(session_path()?).ok()?;
+    let last = DateTime::parse_from_rfc3339(text.trim()).ok()?.with_timezone(&Local);
+    (last.date_naive() < Local::now().date_naive()).then_some(last)
+}
+
+/// Remember now as the end of the latest session. Failing to only costs
+/// tomorrow's summary.
+pub fn end_session() {
+    let Some(path) = session_path() else { return };
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    let _ = fs::write(path, Local::now().to_rfc3339());
+}
+
+impl CatchUp {
+    /// Changes to the board `name` now showing `tickets`, and new comments
+    /// on watched tickets, since `since`
+    pub fn load(config: &Config, name: &str, tickets: &[Ticket], since: DateTime<Local>) -> Self {
+        let days = ((Local::now() - since).num_days() + 1).clamp(1, MAX_DAYS) as u32;
+        let comments = jira_api::fetch_myself(config)
+            .and_then(|me| jira_api::fetch_watched_comments(config, &me, days))
+            .map(|comments| comments.into_iter().filter(|c| posted(c).is_some_and(|t| t > since)).collect())
+            .map_err(|e| e.to_string().lines().next().unwrap_or_default().to_string());
+        CatchUp {
+            since,
+            board: history::diff(name, since, tickets),
+            comments,
+        }
+    }
+}
+
+// JIRA's timestamps, e.g. "2024-05-01T09:30:00.000+0000"
+fn posted(comment: &WatchedComment) -> Option<DateTime<Local>> {
+    DateTime::parse_from_str(&comment.created, "%Y-%m-%dT%H:%M:%S%.f%z").ok().map(|t| t.with_timezone(&Local))
+}
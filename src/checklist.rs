@@ -0,0 +1,129 @@
+// Checklists, such as a definition of done, come in two shapes: action items
+// (ADF task lists) in the description, and checklist apps that keep one item
+// per line of a text custom field, like `* [x] Docs updated`. Both read
+// into the same items, and a toggle writes back to where the item came from.
+use crate::model::{ChecklistItem, ChecklistSource};
+
+/// The action items of an ADF document, in order
+pub fn tasks(adf: &serde_json::Value) -> Vec<ChecklistItem> {
+    let mut items = Vec::new();
+    collect_tasks(adf, &mut items);
+    items
+}
+
+fn collect_tasks(node: &serde_json::Value, items: &mut Vec<ChecklistItem>) {
+    let children = node.get("content").and_then(|c| c.as_array()).into_iter().flatten();
+    if node.get("type").and_then(|t| t.as_str()) != Some("taskItem") {
+        for child in children {
+            collect_tasks(child, items);
+        }
+        return;
+    }
+    let attrs = node.get("attrs");
+    let Some(id) = attrs.and_then(|a| a.get("localId")).and_then(|i| i.as_str()) else {
+        return;
+    };
+    let mut text = String::new();
+    for child in children {
+        inline_text(child, &mut text);
+    }
+    items.push(ChecklistItem {
+        text: text.trim().to_string(),
+        done: attrs.and_then(|a| a.get("state")).and_then(|s| s.as_str()) == Some("DONE"),
+        source: ChecklistSource::Task(id.to_string()),
+    });
+}
+
+// Text and mentions of a task, without the line breaks of block nodes
+fn inline_text(node: &serde_json::Value, text: &mut String) {
+    if let Some(t) = node.get("text").and_then(|t| t.as_str()) {
+        text.push_str(t);
+    } else if let Some(t) = node.get("attrs").and_then(|a| a.get("text")).and_then(|t| t.as_str()) {
+        text.push_str(t);
+    }
+    for child in node.get("content").and_then(|c| c.as_array()).into_iter().flatten() {
+        inline_text(child, text);
+    }
+}
+
+/// The document without its task lists, which show as the checklist instead
+pub fn without_tasks(adf: &serde_json::Value) -> serde_json::Value {
+    let mut adf = adf.clone();
+    strip_tasks(&mut adf);
+    adf
+}
+
+fn strip_tasks(node: &mut serde_json::Value) {
+    if let Some(content) = node.get_mut("content").and_then(|c| c.as_array_mut()) {
+        content.retain(|child| child.get("type").and_then(|t| t.as_str()) != Some("taskList"));
+        for child in content {
+            strip_tasks(child);
+        }
+    }
+}
+
+/// Tick or untick the action item `id`; false when the document has no such item
+pub fn set_task(node: &mut serde_json::Value, id: &str, done: bool) -> bool {
+    if node.get("type").and_then(|t| t.as_str()) == Some("taskItem")
+        && node.get("attrs").and_then(|a| a.get("localId")).and_then(|i| i.as_str()) == Some(id)
+    {
+        node["attrs"]["state"] = serde_json::json!(if done { "DONE" } else { "TODO" });
+        return true;
+    }
+    node.get_mut("content")
+        .and_then(|c| c.as_array_mut())
+        .is_some_and(|content| content.iter_mut().any(|child| set_task(child, id, done)))
+}
+
+// Box markers as checklist apps write them, ticked and not
+const MARKERS: [(&str, &str); 2] = [("[x]", "[ ]"), ("[done]", "[open]")];
+
+// The marker of a checklist line, with where it starts and whether it's ticked
+fn marker(line: &str) -> Option<(usize, &'static str, bool)> {
+    let rest = line.trim_start();
+    let rest = rest.strip_prefix(['*', '-']).map(str::trim_start).unwrap_or(rest);
+    let start = line.len() - rest.len();
+    let lower = rest.to_lowercase();
+    MARKERS.iter().find_map(|&(ticked, open)| {
+        if lower.starts_with(ticked) {
+            Some((start, ticked, true))
+        } else if lower.starts_with(open) {
+            Some((start, open, false))
+        } else {
+            None
+        }
+    })
+}
+
+/// The items of a text checklist field, one per line with a box marker;
+/// headings and other lines are left out
+pub fn field_items(text: &str) -> Vec<ChecklistItem> {
+    text.lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let (start, marker, done) = marker(line)?;
+            Some(ChecklistItem {
+                text: line[start + marker.len()..].trim().to_string(),
+                done,
+                source: ChecklistSource::Field(index),
+            })
+        })
+        .collect()
+}
+
+/// The field text with line `index` ticked or unticked in the style it was
+/// written in, and otherwise untouched; None when that line is no longer
+/// the item reading `item`, e.g. after an edit in the browser
+pub fn set_field_item(text: &str, index: usize, item: &str, done: bool) -> Option<String> {
+    let offset: usize = text.split_inclusive('\n').take(index).map(str::len).sum();
+    let line = text[offset..].lines().next()?;
+    let (start, old, _) = marker(line)?;
+    if line[start + old.len()..].trim() != item {
+        return None;
+    }
+    let (ticked, open) = MARKERS.iter().find(|(t, o)| *t == old || *o == old)?;
+    let start = offset + start;
+    let mut text = text.to_string();
+    text.replace_range(start..start + old.len(), if done { ticked } else { open });
+    Some(text)
+}
@@ -1,60 +1,319 @@
-use clap::Parser;
+use crate::config::{uses_var, Backend, Config, GroupBy, REFRESH_BOUNDS};
+use crate::jql;
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
-#[command(name = "kanbars")]
+#[command(name = "kanbars", version)]
 #[command(about = "🦀 Lightweight Terminal Kanban for JIRA", long_about = None)]
+#[command(after_help = "Settings are layered: config file < environment < command line. Any config setting can be set from the environment as KANBARS_<SECTION>_<KEY>, e.g. KANBARS_DISPLAY_DAILY_SUMMARY=false.")]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    
     /// Custom JQL query
-    #[arg(long)]
+    #[arg(long, env = "KANBARS_JQL")]
     pub jql: Option<String>,
     
+    /// Use a named query from the config's [profiles] instead of [query]
+    #[arg(long, env = "KANBARS_PROFILE")]
+    pub profile: Option<String>,
+    
+    /// Show a second profile's board below the main one (overrides config)
+    #[arg(long, env = "KANBARS_SPLIT")]
+    pub split: Option<String>,
+    
     /// Filter by epic
     #[arg(long)]
     pub epic: Option<String>,
     
+    /// Filter by component
+    #[arg(long)]
+    pub component: Option<String>,
+    
+    /// Filter by fix version
+    #[arg(long = "fix-version")]
+    pub fix_version: Option<String>,
+    
+    /// Only show flagged (impediment) tickets
+    #[arg(long)]
+    pub flagged: bool,
+    
     /// Show tickets for a specific assignee
     #[arg(long)]
     pub assignee: Option<String>,
     
-    /// JIRA instance URL (overrides config)
+    /// What each swim lane represents (overrides config)
+    #[arg(long = "group-by", value_enum, env = "KANBARS_GROUP_BY")]
+    pub group_by: Option<GroupBy>,
+    
+    /// Where to fetch tickets from (overrides config)
+    #[arg(long, value_enum, env = "KANBARS_BACKEND")]
+    pub backend: Option<Backend>,
+    
+    /// Most tickets to load (overrides config, default: 100)
+    #[arg(long = "max-results", env = "KANBARS_MAX_RESULTS")]
+    pub max_results: Option<usize>,
+    
+    /// Value for a {{name}} placeholder in JQL, e.g. --var team=payments (repeatable)
+    #[arg(long = "var", value_name = "NAME=VALUE", value_parser = parse_var)]
+    pub vars: Vec<(String, String)>,
+    
+    /// JIRA instance URL (overrides config and JIRA_URL)
     #[arg(long)]
     pub url: Option<String>,
     
+    /// JIRA account email (overrides config and JIRA_USER)
+    #[arg(long)]
+    pub email: Option<String>,
+    
+    /// Config file to use instead of the default location
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+    
     /// Generate a sample config file
     #[arg(long)]
     pub init: bool,
     
-    /// Auto-refresh interval in seconds (default: 60)
-    #[arg(short = 'r', long = "refresh", default_value = "60")]
-    pub refresh: u64,
+    /// Print a man page (roff) to stdout
+    #[arg(long)]
+    pub man: bool,
+    
+    /// Auto-refresh interval in seconds (overrides config, default: 60)
+    #[arg(short = 'r', long = "refresh", env = "KANBARS_REFRESH", value_parser = clap::value_parser!(u64).range(REFRESH_BOUNDS))]
+    pub refresh: Option<u64>,
+    
+    /// Also append the activity log (`L`) to this file
+    #[arg(long, env = "KANBARS_ACTIVITY_LOG", value_name = "FILE")]
+    pub activity_log: Option<PathBuf>,
+    
+    /// Screen-reader friendly output: linear lists, no box drawing or emoji
+    #[arg(long, env = "KANBARS_A11Y")]
+    pub a11y: bool,
+    
+    /// Show a made-up sample board instead of JIRA, to try kanbars without an account
+    #[arg(long)]
+    pub demo: bool,
+    
+    /// Save every JIRA response into this directory, for replaying later
+    #[arg(long, value_name = "DIR", conflicts_with = "replay")]
+    pub record: Option<PathBuf>,
+    
+    /// Answer JIRA requests from responses saved with --record, without the network
+    #[arg(long, value_name = "DIR")]
+    pub replay: Option<PathBuf>,
+    
+    /// Keep the last raw JSON responses in the data dir, and show a ticket's raw payload with `J`
+    #[arg(long, env = "KANBARS_DEBUG")]
+    pub debug: bool,
+    
+    /// Unattended display for a TV: no cursor or key hints, wide lane titles,
+    /// each profile shown in turn for SECONDS (default 60), and failed
+    /// refreshes retried every 15 seconds until they work
+    #[arg(long, value_name = "SECONDS", num_args = 0..=1, default_missing_value = "60", value_parser = clap::value_parser!(u64).range(5..))]
+    pub wallboard: Option<u64>,
+    
+    /// On startup, select your one In Progress ticket, or with `open` show
+    /// it in full; with none or several the board opens as usual
+    #[arg(long = "focus-mine", value_name = "HOW", value_enum, num_args = 0..=1, default_missing_value = "select")]
+    pub focus_mine: Option<Focus>,
     
     /// Display once and exit (useful with watch command)
     #[arg(long = "once")]
     pub once: bool,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum Focus {
+    Select,
+    Open,
+}
+
+fn parse_var(arg: &str) -> Result<(String, String), String> {
+    let (name, value) = arg.split_once('=')
+        .ok_or_else(|| format!("expected NAME=VALUE, got '{}'", arg))?;
+    Ok((name.trim().to_lowercase(), value.to_string()))
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Check the config file, credentials and connection to JIRA
+    Doctor,
+    /// Assign a ticket to someone, by email, name or account id
+    Assign {
+        /// Ticket key, e.g. PROJ-123
+        key: String,
+        /// Email, display name or account id (emails can't be searched on sites that hide them)
+        user: String,
+    },
+    /// Assign a ticket to yourself
+    Take {
+        /// Ticket key, e.g. PROJ-123
+        key: String,
+    },
+    /// Move every ticket matching a query to a status. Lists them unless --yes
+    Transition {
+        /// Tickets to move
+        #[arg(long)]
+        jql: String,
+        /// Target status or transition name, e.g. "Shipped"
+        status: String,
+        /// Perform the transitions instead of listing them
+        #[arg(long)]
+        yes: bool,
+        /// Most tickets to touch
+        #[arg(long, default_value_t = 200)]
+        limit: usize,
+    },
+    /// Show which tickets entered, left or changed columns since a time
+    Diff {
+        /// e.g. "yesterday 09:00", "24h", "2024-05-01"
+        #[arg(long, default_value = "24h")]
+        since: String,
+    },
+    /// Create a ticket and print its key
+    Create {
+        /// Project key, e.g. PROJ
+        #[arg(long)]
+        project: String,
+        /// Issue type name
+        #[arg(long = "type", default_value = "Task")]
+        issue_type: String,
+        #[arg(long)]
+        summary: String,
+        /// File holding the description, or - for stdin
+        #[arg(long)]
+        description_file: Option<PathBuf>,
+    },
+    /// Print every change kanbars has sent to JIRA, from the audit log
+    Audit,
+    /// Print a shell completion script, e.g. `kanbars completions zsh > _kanbars`
+    Completions {
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
+}
+
 impl Args {
-    pub fn build_jql(&self, default_jql: &str) -> String {
-        if let Some(ref jql) = self.jql {
-            return jql.clone();
+    /// One config per board on screen: the main query (or `--profile`)
+    /// with command-line overrides applied, plus the split profile if any
+    pub fn board_configs(&self, mut config: Config) -> Result<Vec<Config>, String> {
+        // Swap in a named profile's query before applying CLI overrides
+        if let Some(ref profile) = self.profile {
+            config = config.with_profile(profile)
+                .ok_or_else(|| format!("Unknown profile '{}'", profile))?;
+        }
+        
+        // Override config with CLI args
+        if let Some(ref url) = self.url {
+            config.jira.url = Some(url.clone());
+        }
+        if let Some(ref email) = self.email {
+            config.jira.email = Some(email.clone());
+        }
+        if let Some(group_by) = self.group_by {
+            config.display.group_by = group_by;
+        }
+        if let Some(backend) = self.backend {
+            config.jira.backend = backend;
+        }
+        if let Some(max_results) = self.max_results {
+            config.query.max_results = max_results.max(1);
         }
+        if let Some(ref split) = self.split {
+            config.display.split = Some(split.clone());
+        }
+        if let Some(refresh) = self.refresh {
+            config.query.refresh = Some(refresh);
+        }
+        config.vars.extend(self.vars.iter().cloned());
         
-        let mut jql = default_jql.to_string();
+        config.validate().map_err(|problems| format!("Invalid options:\n{}", problems.join("\n")))?;
+        
+        // The split board shares connection and display settings; query filters
+        // from the command line only narrow the main board
+        let split_config = match config.display.split {
+            Some(ref name) => {
+                let mut split_config = config.with_profile(name)
+                    .ok_or_else(|| format!("Unknown profile '{}'", name))?;
+                split_config.query.refresh = split_config.query.refresh.or(self.refresh);
+                split_config.expand_vars()?;
+                Some(split_config)
+            }
+            None => None,
+        };
+        if let Some(ref assignee) = self.assignee {
+            config.vars.insert("me".to_string(), jql::quote(jql::value("--assignee", assignee)?));
+        }
+        let jql = self.build_jql(&config)?;
+        config.expand_vars()?;
+        config.query.jql = jql;
+        Ok(std::iter::once(config).chain(split_config).collect())
+    }
+    
+    /// The configured query (or `--jql`) with its placeholders filled,
+    /// narrowed by the filter flags. Values are quoted as JQL strings, so
+    /// quotes and keywords in names are safe, and they're added after the
+    /// placeholders are filled, so a `{{` in one stays as typed.
+    pub fn build_jql(&self, config: &Config) -> Result<String, String> {
+        let template = self.jql.as_deref().unwrap_or(&config.query.jql);
+        let mut jql = config.expand(template)?;
+        if self.jql.is_some() {
+            return Ok(jql);
+        }
         
         if let Some(ref epic) = self.epic {
-            jql = format!("\"Epic Link\" = {} AND {}", epic, jql);
+            jql = jql::and(&format!("\"Epic Link\" = {}", jql::quote(jql::value("--epic", epic)?)), &jql);
         }
         
-        if let Some(ref assignee) = self.assignee {
-            // Replace currentUser() or any assignee clause
-            if jql.contains("assignee") {
-                jql = jql.replace("assignee = currentUser()", &format!("assignee = '{}'", assignee));
-                jql = jql.replace("developer = 'Jake Swanson'", &format!("assignee = '{}'", assignee));
-            } else {
-                jql = format!("assignee = '{}' AND {}", assignee, jql);
-            }
+        if let Some(ref component) = self.component {
+            jql = jql::and(&format!("component = {}", jql::quote(jql::value("--component", component)?)), &jql);
+        }
+        
+        if let Some(ref fix_version) = self.fix_version {
+            jql = jql::and(&format!("fixVersion = {}", jql::quote(jql::value("--fix-version", fix_version)?)), &jql);
+        }
+        
+        if self.flagged {
+            jql = jql::and("flagged = Impediment", &jql);
         }
         
-        jql
+        // Queries written around {{me}} get the assignee through that
+        // placeholder (see `board_configs`); others are narrowed by an
+        // explicit clause on the person field
+        if let Some(ref assignee) = self.assignee
+            && !uses_var(template, "me")
+        {
+            jql = jql::and(&format!("{} = {}", config.fields.person, jql::quote(jql::value("--assignee", assignee)?)), &jql);
+        }
+        
+        Ok(jql)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board_jql(args: &[&str]) -> Result<String, String> {
+        let args = Args::parse_from(std::iter::once("kanbars").chain(args.iter().copied()));
+        let mut config = Config::default();
+        config.query.jql = "assignee = {{me}} ORDER BY rank".to_string();
+        let configs = args.board_configs(config)?;
+        Ok(configs[0].query.jql.clone())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn flag_values_are_quoted_and_never_expanded() {
+        assert_eq!(
+            board_jql(&["--epic", "{{me}}"]),
+            Ok(r#""Epic Link" = "{{me}}" AND (assignee = currentUser()) ORDER BY rank"#.to_string()),
+        );
+        assert_eq!(
+            board_jql(&["--component", "a{{x}}\"b"]),
+            Ok(r#"component = "a{{x}}\"b" AND (assignee = currentUser()) ORDER BY rank"#.to_string()),
+        );
+        // {{me}} takes the assignee, quoted like any other value
+        assert_eq!(board_jql(&["--assignee", "{{sprint}}"]), Ok(r#"assignee = "{{sprint}}" ORDER BY rank"#.to_string()));
+        assert_eq!(board_jql(&["--jql", "project = {{x}}"]).map_err(|e| e.starts_with("No value for {{x}}")), Err(true));
+    }
+}
@@ -0,0 +1,45 @@
+use base64::{engine::general_purpose, Engine as _};
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+// Copy text with the platform's clipboard tool. Where there is none, as over
+// SSH, an OSC 52 escape asks the terminal itself to take it, which most do.
+pub fn copy(text: &str) -> io::Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("pbcopy")
+    } else if cfg!(target_os = "windows") {
+        Command::new("clip")
+    } else if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+        Command::new("wl-copy")
+    } else {
+        let mut c = Command::new("xclip");
+        c.args(["-selection", "clipboard"]);
+        c
+    };
+    
+    // clip reads the console's code page, which mangles anything past ASCII,
+    // unless the text is UTF-16 marked by its byte order mark
+    let bytes: Vec<u8> = if cfg!(target_os = "windows") {
+        std::iter::once(0xFEFF).chain(text.encode_utf16()).flat_map(u16::to_le_bytes).collect()
+    } else {
+        text.as_bytes().to_vec()
+    };
+    
+    let copied = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .and_then(|mut child| {
+            child.stdin.take().ok_or(io::ErrorKind::BrokenPipe)?.write_all(&bytes)?;
+            child.wait()
+        });
+    match copied {
+        Ok(status) if status.success() => Ok(()),
+        _ => {
+            let mut stdout = io::stdout();
+            write!(stdout, "\x1b]52;c;{}\x07", general_purpose::STANDARD.encode(text))?;
+            stdout.flush()
+        }
+    }
+}
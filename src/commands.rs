@@ -0,0 +1,156 @@
+// Non-interactive subcommands for scripts, shell aliases and git hooks
+use crate::activity::{self, Kind};
+use crate::config::Config;
+use crate::history::{self, Change};
+use crate::jira::fetch_tickets;
+use crate::jira_api::{self, NewIssue};
+use crate::metadata;
+use crate::model::symbol;
+use crate::permissions::{self, Permission};
+use std::error::Error;
+use std::io::Read;
+use std::path::Path;
+
+/// `kanbars assign PROJ-123 alice@example.com`, or a display name or
+/// account id
+pub fn assign(config: &Config, key: &str, user: &str) -> Result<(), Box<dyn Error>> {
+    permissions::check(config, key, Permission::Assign)?;
+    let account = metadata::find_user(config, metadata::project_of(key), user)?;
+    jira_api::assign(config, key, &account)?;
+    activity::record(Kind::Change, format!("{} assigned to {}", key, account.name));
+    println!("{} assigned to {}", key, account.name);
+    Ok(())
+}
+
+/// `kanbars take PROJ-123`: assign to whoever the credentials belong to
+pub fn take(config: &Config, key: &str) -> Result<(), Box<dyn Error>> {
+    permissions::check(config, key, Permission::Assign)?;
+    let account = jira_api::fetch_myself(config)?;
+    jira_api::assign(config, key, &account)?;
+    activity::record(Kind::Change, format!("{} assigned to {}", key, account.name));
+    println!("{} assigned to {}", key, account.name);
+    Ok(())
+}
+
+/// `kanbars create --project PROJ --summary "..."`, printing the new key
+pub fn create(
+    config: &Config,
+    project: &str,
+    issue_type: &str,
+    summary: &str,
+    description_file: Option<&Path>,
+) -> Result<(), Box<dyn Error>> {
+    let description = match description_file {
+        Some(path) if path == Path::new("-") => {
+            let mut text = String::new();
+            std::io::stdin().read_to_string(&mut text)?;
+            Some(text)
+        }
+        Some(path) => Some(std::fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?),
+        None => None,
+    };
+    permissions::check(config, project, Permission::Create)?;
+    metadata::check_issue_type(config, project, issue_type)?;
+    let issue = NewIssue {
+        project: project.to_string(),
+        issue_type: issue_type.to_string(),
+        summary: summary.to_string(),
+        description,
+        ..Default::default()
+    };
+    let key = jira_api::create_issue(config, &issue)?;
+    activity::record(Kind::Change, format!("Created {}: {}", key, summary));
+    println!("{}", key);
+    Ok(())
+}
+
+/// `kanbars transition --jql '...' "Shipped"`: lists the tickets a bulk
+/// move would touch, and moves them one by one with `--yes`
+pub fn transition(config: &Config, jql: &str, status: &str, yes: bool, limit: usize) -> Result<(), Box<dyn Error>> {
+    let mut query = config.clone();
+    query.query.jql = jql.to_string();
+    query.query.max_results = limit;
+    query.query.extra.clear();
+    query.expand_vars()?;
+    let (tickets, total) = jira_api::fetch_tickets_api(&query)?;
+    
+    let (done, pending): (Vec<_>, Vec<_>) = tickets.iter()
+        .partition(|t| t.status.eq_ignore_ascii_case(status));
+    if pending.is_empty() {
+        println!("Nothing to move: {} matching ticket(s) already in {}", done.len(), status);
+        return Ok(());
+    }
+    if let Some(total) = total {
+        println!("Only the first {} of {} matches are included; raise --limit to touch more", tickets.len(), total);
+    }
+    
+    if !yes {
+        println!("Would move {} ticket(s) to {}:", pending.len(), status);
+        for ticket in &pending {
+            println!("  {:<12} {:<16} {}", ticket.key, ticket.status, ticket.summary);
+        }
+        println!("Run again with --yes to apply.");
+        return Ok(());
+    }
+    
+    let mut failures = 0;
+    for ticket in &pending {
+        let result = permissions::check(config, &ticket.key, Permission::Transition).map_err(Into::into).and_then(|()| metadata::transitions(config, &ticket.key, ticket.ticket_type.name(), &ticket.status).and_then(|transitions| {
+            let transition = transitions.iter()
+                .find(|t| t.to_status.eq_ignore_ascii_case(status) || t.name.eq_ignore_ascii_case(status))
+                .ok_or_else(|| format!("no transition to {} from {}", status, ticket.status))?;
+            jira_api::transition_issue(config, &ticket.key, transition)
+        }));
+        match result {
+            Ok(()) => {
+                activity::record(Kind::Change, format!("Moved {} to {}", ticket.key, status));
+                println!("{} {} → {}", symbol("✅", "ok"), ticket.key, status);
+            }
+            Err(e) => {
+                failures += 1;
+                let error = e.to_string().lines().next().unwrap_or_default().to_string();
+                activity::record(Kind::Error, format!("Move of {} to {} failed: {}", ticket.key, status, error));
+                println!("{} {}: {}", symbol("❌", "FAIL"), ticket.key, error);
+            }
+        }
+    }
+    
+    println!("Moved {} of {} ticket(s)", pending.len() - failures, pending.len());
+    if failures > 0 {
+        return Err(format!("{} ticket(s) could not be moved", failures).into());
+    }
+    Ok(())
+}
+
+/// `kanbars diff --since "yesterday 09:00"`: each board's changes since then
+pub fn diff(configs: &[Config], since: &str) -> Result<(), Box<dyn Error>> {
+    let since = history::parse_since(since)?;
+    for config in configs {
+        let fetched = fetch_tickets(config)?;
+        history::record(&config.query.name, &fetched.tickets);
+        let diff = history::diff(&config.query.name, since, &fetched.tickets)?;
+        
+        if configs.len() > 1 {
+            println!("━━ {} ━━", config.query.name);
+        }
+        println!("Since {}", since.format("%a %b %-d %H:%M"));
+        if diff.baseline > since {
+            println!("(history starts at {})", diff.baseline.format("%a %b %-d %H:%M"));
+        }
+        if diff.changes.is_empty() {
+            println!("  No changes");
+        }
+        for change in &diff.changes {
+            match change {
+                Change::Entered(t) => println!("  + {:<12} {:<24} {}", t.key, t.status, t.summary),
+                Change::Left(t) => println!("  - {:<12} {:<24} {}", t.key, t.status, t.summary),
+                Change::Moved { ticket: t, from } => {
+                    println!("  ~ {:<12} {:<24} {}", t.key, format!("{} → {}", from, t.status), t.summary);
+                }
+            }
+        }
+        println!();
+    }
+    Ok(())
+}
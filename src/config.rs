@@ -1,24 +1,406 @@
-use dirs;
+use crate::i18n;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
-#[derive(Debug, Serialize, Deserialize)]
+static CONFIG_PATH: OnceLock<PathBuf> = OnceLock::new();
+
+// Sections whose settings `KANBARS_<SECTION>_<KEY>` can override
+const SECTIONS: [&str; 8] = ["jira", "query", "display", "fields", "incidents", "refresh", "hygiene", "metadata"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Config {
     pub jira: JiraConfig,
     pub query: QueryConfig,
+    #[serde(default)]
+    pub display: DisplayConfig,
+    #[serde(default)]
+    pub fields: FieldsConfig,
+    #[serde(default)]
+    pub incidents: IncidentConfig,
+    #[serde(default)]
+    pub refresh: RefreshConfig,
+    #[serde(default)]
+    pub hygiene: HygieneConfig,
+    #[serde(default)]
+    pub metadata: MetadataConfig,
+    /// Named queries that can replace `query` (`--profile`) or fill a split view
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, QueryConfig>,
+    /// Values for `{{name}}` placeholders in JQL
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub vars: BTreeMap<String, String>,
+    /// Tickets that can be created from the board with `N`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub templates: BTreeMap<String, TicketTemplate>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct JiraConfig {
     pub url: Option<String>,
     pub email: Option<String>,
     pub api_token: Option<String>,
+    /// Where tickets come from
+    #[serde(default)]
+    pub backend: Backend,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    /// JIRA REST API (needs an API token)
+    Api,
+    /// Atlassian's `acli` command-line tool
+    Acli,
+    /// REST API, falling back to acli when it fails or isn't configured
+    #[default]
+    Auto,
+    /// Made-up sample tickets, without contacting JIRA
+    Demo,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct QueryConfig {
     pub jql: String,
+    /// Most tickets to load; results beyond this are reported as truncated
+    #[serde(default = "default_max_results")]
+    pub max_results: usize,
+    /// Name of `jql` on card badges when `extra` queries are configured
+    #[serde(default = "default_query_name")]
+    pub name: String,
+    /// More queries fetched alongside `jql` and merged into one board
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra: Vec<NamedQuery>,
+    /// Seconds between auto-refreshes of this query's board
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NamedQuery {
+    pub name: String,
+    pub jql: String,
+}
+
+/// Fields prefilled on a ticket created from the board; only the summary
+/// is asked for
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TicketTemplate {
+    pub project: String,
+    #[serde(rename = "type", default = "default_issue_type")]
+    pub issue_type: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<String>,
+    /// Description skeleton, e.g. headings to fill in later
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+}
+
+fn default_issue_type() -> String {
+    "Task".to_string()
+}
+
+/// Allowed auto-refresh intervals, in seconds
+pub const REFRESH_BOUNDS: std::ops::RangeInclusive<u64> = 5..=86400;
+
+fn default_max_results() -> usize {
+    100
+}
+
+fn default_query_name() -> String {
+    "mine".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DisplayConfig {
+    /// Show a short colored parent/epic tag on each card
+    pub show_epic: bool,
+    /// What each swim lane represents
+    pub group_by: GroupBy,
+    /// How assignees appear on cards
+    pub assignee_style: AssigneeStyle,
+    /// Profile shown in a second board below the main one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split: Option<String>,
+    /// Shown in place of the lanes when the query matches nothing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub empty_message: Option<String>,
+    /// Language of the interface, e.g. "es"; defaults to LANG
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Title bar text in place of the built-in one, e.g. "{board}: {count} tickets"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Bar along the bottom listing the keys that apply
+    pub footer: bool,
+    /// Widest the detail view's text gets, centered in wider terminals; 0 for no limit
+    pub reading_width: u16,
+    /// Symbols for ticket types and lanes
+    pub icons: IconSet,
+    /// How much each card shows, until changed on the board with `v`
+    pub density: Density,
+    /// On the first start of a day, sum up what changed since the last session
+    pub daily_summary: bool,
+    /// Icons for particular statuses, overriding the guess from the name
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub status_icons: BTreeMap<String, String>,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        DisplayConfig {
+            show_epic: true,
+            group_by: GroupBy::Status,
+            assignee_style: AssigneeStyle::Both,
+            split: None,
+            empty_message: None,
+            language: None,
+            title: None,
+            footer: true,
+            reading_width: 100,
+            icons: IconSet::Emoji,
+            density: Density::Normal,
+            daily_summary: true,
+            status_icons: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Density {
+    /// One line per card, the summary cut to fit
+    Compact,
+    /// Long summaries wrap onto a second line
+    Normal,
+    /// Normal, plus a line of labels, priority and due date
+    Detailed,
+}
+
+impl Density {
+    pub fn next(self) -> Self {
+        match self {
+            Density::Compact => Density::Normal,
+            Density::Normal => Density::Detailed,
+            Density::Detailed => Density::Compact,
+        }
+    }
+    
+    pub fn name(self) -> &'static str {
+        match self {
+            Density::Compact => "compact",
+            Density::Normal => "normal",
+            Density::Detailed => "detailed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IconSet {
+    /// Emoji, or ASCII on consoles that can't draw them (see KANBARS_ASCII)
+    Emoji,
+    /// Glyphs from a patched Nerd Font
+    Nerd,
+    /// Plain ASCII
+    Ascii,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AssigneeStyle {
+    /// Truncated name, e.g. "@jake"
+    Name,
+    /// Colored initials badge, e.g. "JS"
+    Initials,
+    Both,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupBy {
+    Status,
+    Sprint,
+    Component,  // A ticket with several components shows in each of their lanes
+}
+
+/// When auto-refresh runs, across all boards
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RefreshConfig {
+    /// Minutes without a keypress before auto-refresh pauses until the
+    /// next one; 0 never pauses
+    pub idle_minutes: u64,
+    /// Working hours, e.g. "08:00-19:00". Once set, boards refresh at their
+    /// usual pace only within them, on `days` that aren't `holidays`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hours: Option<String>,
+    /// Working days, e.g. ["mon", "tue", "wed", "thu", "fri"]
+    pub days: Vec<String>,
+    /// Dates off, e.g. ["2025-12-25"]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub holidays: Vec<String>,
+    /// Seconds between refreshes outside working hours; 0 waits for them
+    pub off_hours_seconds: u64,
+}
+
+impl Default for RefreshConfig {
+    fn default() -> Self {
+        RefreshConfig {
+            idle_minutes: 60,
+            hours: None,
+            days: ["mon", "tue", "wed", "thu", "fri"].map(str::to_string).to_vec(),
+            holidays: Vec::new(),
+            off_hours_seconds: 900,
+        }
+    }
+}
+
+impl RefreshConfig {
+    // Start and end of working hours, when set and valid
+    fn hours(&self) -> Option<(NaiveTime, NaiveTime)> {
+        let (start, end) = self.hours.as_deref()?.split_once('-')?;
+        let start = NaiveTime::parse_from_str(start.trim(), "%H:%M").ok()?;
+        let end = NaiveTime::parse_from_str(end.trim(), "%H:%M").ok()?;
+        (start < end).then_some((start, end))
+    }
+    
+    fn working_day(&self, date: NaiveDate) -> bool {
+        self.days.iter().any(|day| day.parse::<Weekday>() == Ok(date.weekday()))
+            && !self.holidays.iter().any(|holiday| NaiveDate::parse_from_str(holiday, "%Y-%m-%d") == Ok(date))
+    }
+    
+    /// Whether boards refresh at their usual pace at `time`: within working
+    /// hours, or always when there are none
+    pub fn working(&self, time: NaiveDateTime) -> bool {
+        match self.hours() {
+            Some((start, end)) => self.working_day(time.date()) && (start..end).contains(&time.time()),
+            None => true,
+        }
+    }
+    
+    /// When working hours next start after `time`, looking a few weeks
+    /// ahead at most
+    #[cfg(feature = "tui")]
+    pub fn next_start(&self, time: NaiveDateTime) -> Option<NaiveDateTime> {
+        let (start, _) = self.hours()?;
+        time.date().iter_days()
+            .take(30)
+            .filter(|date| self.working_day(*date))
+            .map(|date| date.and_time(start))
+            .find(|next| *next > time)
+    }
+}
+
+/// Tickets that get pinned to the top of their lane, marked and announced
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct IncidentConfig {
+    /// Issue types that are always incidents
+    pub types: Vec<String>,
+    /// Priorities that make any ticket one
+    pub priorities: Vec<String>,
+    /// Desktop notification when one shows up on the board
+    pub notify: bool,
+}
+
+impl Default for IncidentConfig {
+    fn default() -> Self {
+        IncidentConfig {
+            types: vec!["Incident".to_string()],
+            priorities: vec!["Highest".to_string(), "Blocker".to_string()],
+            notify: true,
+        }
+    }
+}
+
+impl IncidentConfig {
+    pub fn matches(&self, issue_type: &str, priority: Option<&str>) -> bool {
+        self.types.iter().any(|t| t.eq_ignore_ascii_case(issue_type))
+            || priority.is_some_and(|p| self.priorities.iter().any(|q| q.eq_ignore_ascii_case(p)))
+    }
+}
+
+/// Team rules the board is checked against; tickets breaking one get a ⚠
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HygieneConfig {
+    /// Rules in force; an empty list turns the checks off
+    pub rules: Vec<HygieneRule>,
+}
+
+impl Default for HygieneConfig {
+    fn default() -> Self {
+        HygieneConfig {
+            rules: vec![HygieneRule::UnestimatedInSprint, HygieneRule::UnassignedInProgress, HygieneRule::BugWithoutPriority],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HygieneRule {
+    /// In the active sprint without story points
+    UnestimatedInSprint,
+    /// In progress with nobody on it
+    UnassignedInProgress,
+    /// A bug nobody has prioritized
+    BugWithoutPriority,
+}
+
+/// How long project metadata (issue types, priorities, assignable people,
+/// transitions) is kept before it's fetched again
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MetadataConfig {
+    pub ttl_hours: u64,
+}
+
+impl Default for MetadataConfig {
+    fn default() -> Self {
+        MetadataConfig { ttl_hours: 24 }
+    }
+}
+
+/// Custom field ids, which vary between JIRA instances
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FieldsConfig {
+    /// The "Flagged" (impediment) field
+    pub flagged: String,
+    /// The "Sprint" field
+    pub sprint: String,
+    /// The "Story Points" (or "Story point estimate") field
+    pub story_points: String,
+    /// People field meaning "my tickets" (`{{person}}` in JQL, and what
+    /// `--assignee` filters on), e.g. "assignee" or a custom "developer"
+    pub person: String,
+    /// Text field a checklist app keeps its items in, one per line like
+    /// `* [x] Docs updated`, e.g. "customfield_10050"
+    pub checklist: Option<String>,
+}
+
+impl Default for FieldsConfig {
+    fn default() -> Self {
+        FieldsConfig {
+            flagged: "customfield_10021".to_string(),
+            sprint: "customfield_10020".to_string(),
+            story_points: "customfield_10016".to_string(),
+            person: "developer".to_string(),
+            checklist: None,
+        }
+    }
 }
 
 impl Default for Config {
@@ -28,68 +410,401 @@ impl Default for Config {
                 url: None,
                 email: None,
                 api_token: None,
+                backend: Backend::Auto,
             },
             query: QueryConfig {
-                jql: "developer = currentUser() AND status NOT IN ('Done', 'Shipped', 'Discontinued', 'Closed', 'Hibernate')".to_string(),
+                jql: "{{person}} = {{me}} AND status NOT IN ('Done', 'Shipped', 'Discontinued', 'Closed', 'Hibernate')".to_string(),
+                max_results: default_max_results(),
+                name: default_query_name(),
+                extra: Vec::new(),
+                refresh: None,
             },
+            display: DisplayConfig::default(),
+            fields: FieldsConfig::default(),
+            incidents: IncidentConfig::default(),
+            refresh: RefreshConfig::default(),
+            hygiene: HygieneConfig::default(),
+            metadata: MetadataConfig::default(),
+            profiles: BTreeMap::new(),
+            vars: BTreeMap::new(),
+            templates: BTreeMap::new(),
         }
     }
 }
 
 impl Config {
-    pub fn load() -> Self {
+    /// Settings for `--demo`: the sample board, whatever the config file says
+    pub fn demo() -> Self {
+        let mut config = Config::default();
+        config.jira.backend = Backend::Demo;
+        config.query.name = "demo".to_string();
+        config.query.jql = "project = DEMO".to_string();
+        config
+    }
+    
+    /// Copy of this config querying the named profile; the main query
+    /// answers to its own `name` too
+    pub fn with_profile(&self, name: &str) -> Option<Config> {
+        let query = match self.profiles.get(name) {
+            Some(query) => query.clone(),
+            None if self.query.name == name => self.query.clone(),
+            None => return None,
+        };
+        let mut config = self.clone();
+        config.query = query;
+        config.query.name = name.to_string();
+        Some(config)
+    }
+    
+    /// Read the config file (if any) with environment variables layered on
+    /// top. Syntax errors and unknown keys are reported with their line, so
+    /// a typo can't silently fall back to defaults.
+    pub fn load() -> Result<Self, String> {
         let config_path = Self::config_path();
+        let source = if config_path.exists() { format!("config {}", config_path.display()) } else { "configuration".to_string() };
+        let invalid = |detail: String| format!("Invalid {}:\n{}", source, detail.trim_end());
         
-        if config_path.exists() {
+        let mut config = if config_path.exists() {
             let contents = fs::read_to_string(&config_path)
-                .unwrap_or_else(|_| String::new());
-            toml::from_str(&contents).unwrap_or_else(|_| Self::default())
+                .map_err(|e| invalid(e.to_string()))?;
+            toml::from_str(&contents)
+                .map_err(|e| invalid(e.to_string()))?
         } else {
-            // Check environment variables as fallback
-            let mut config = Self::default();
-            
-            // Support both JIRA_URL and JIRA_SITE (ACLI style)
-            if let Ok(url) = std::env::var("JIRA_URL") {
-                config.jira.url = Some(url);
-            } else if let Ok(site) = std::env::var("JIRA_SITE") {
-                // Convert site to full URL if it's just the domain
-                let url = if site.starts_with("http://") || site.starts_with("https://") {
-                    site
-                } else {
-                    format!("https://{}", site)
-                };
-                config.jira.url = Some(url);
+            Self::default()
+        };
+        
+        // Problems the file has by itself are blamed on it, the rest on the
+        // environment
+        let file_problems = config.validate().err().unwrap_or_default();
+        config = config.apply_env_overrides(std::env::vars())?;
+        config.apply_env();
+        config.validate().map_err(|problems| {
+            let (file, environment): (Vec<_>, Vec<_>) = problems.into_iter().partition(|p| file_problems.contains(p));
+            let mut message = Vec::new();
+            if !file.is_empty() {
+                message.push(invalid(file.join("\n")));
             }
-            
-            if let Ok(user) = std::env::var("JIRA_USER") {
-                config.jira.email = Some(user);
-            } else if let Ok(email) = std::env::var("JIRA_EMAIL") {
-                config.jira.email = Some(email);
+            if !environment.is_empty() {
+                message.push(format!("Invalid settings from the environment:\n{}", environment.join("\n")));
+            }
+            message.join("\n")
+        })?;
+        Ok(config)
+    }
+    
+    // Any `[section] key` from `KANBARS_<SECTION>_<KEY>` among `vars`, e.g.
+    // KANBARS_DISPLAY_DAILY_SUMMARY=false. Values read as TOML where they
+    // can (numbers, booleans, arrays) unless the setting is text; an unset
+    // one, like a token of digits, is tried as text when that doesn't fit.
+    // Errors name the variable, not the config file.
+    fn apply_env_overrides(self, vars: impl IntoIterator<Item = (String, String)>) -> Result<Self, String> {
+        let overrides: Vec<(String, &str, String, String)> = vars.into_iter()
+            .filter_map(|(variable, value)| {
+                let name = variable.strip_prefix("KANBARS_")?.to_lowercase();
+                let section = SECTIONS.iter().find(|s| name.starts_with(&format!("{}_", s)))?;
+                let key = name[section.len() + 1..].to_string();
+                Some((variable, *section, key, value))
+            })
+            .collect();
+        if overrides.is_empty() {
+            return Ok(self);
+        }
+        
+        let mut table = toml::Table::try_from(&self).map_err(|e| e.to_string())?;
+        let mut config = self;
+        for (variable, section, key, value) in overrides {
+            let typed = toml::from_str::<toml::Table>(&format!("value = {}", value))
+                .ok()
+                .and_then(|mut parsed| parsed.remove("value"))
+                .filter(|_| !matches!(table.get(section).and_then(|s| s.get(&key)), Some(toml::Value::String(_))));
+            let mut error = None;
+            for value in typed.into_iter().chain([toml::Value::String(value)]) {
+                let mut attempt = table.clone();
+                if let Some(settings) = attempt.entry(section)
+                    .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+                    .as_table_mut()
+                {
+                    settings.insert(key.clone(), value);
+                }
+                match attempt.clone().try_into() {
+                    Ok(overridden) => {
+                        (table, config, error) = (attempt, overridden, None);
+                        break;
+                    }
+                    Err(e) => {
+                        error.get_or_insert(e);
+                    }
+                }
             }
-            
-            if let Ok(token) = std::env::var("JIRA_API_TOKEN") {
-                config.jira.api_token = Some(token);
+            if let Some(e) = error {
+                return Err(format!("Invalid {}: {}", variable, e.to_string().trim_end()));
             }
-            
-            config
         }
+        Ok(config)
     }
     
-    pub fn config_path() -> PathBuf {
-        let config_dir = dirs::config_dir()
-            .expect("Could not find config directory")
-            .join("kanbars");
+    // JIRA credentials from the environment (same variables as ACLI)
+    // override the file, so a token can stay out of it
+    fn apply_env(&mut self) {
+        // Support both JIRA_URL and JIRA_SITE (ACLI style)
+        if let Ok(url) = std::env::var("JIRA_URL") {
+            self.jira.url = Some(url);
+        } else if let Ok(site) = std::env::var("JIRA_SITE") {
+            // Convert site to full URL if it's just the domain
+            let url = if site.starts_with("http://") || site.starts_with("https://") {
+                site
+            } else {
+                format!("https://{}", site)
+            };
+            self.jira.url = Some(url);
+        }
+        
+        if let Ok(user) = std::env::var("JIRA_USER") {
+            self.jira.email = Some(user);
+        } else if let Ok(email) = std::env::var("JIRA_EMAIL") {
+            self.jira.email = Some(email);
+        }
         
-        // Create directory if it doesn't exist
-        let _ = fs::create_dir_all(&config_dir);
+        if let Ok(token) = std::env::var("JIRA_API_TOKEN") {
+            self.jira.api_token = Some(token);
+        }
         
+        // KANBARS_VAR_TEAM=payments fills {{team}}
+        for (key, value) in std::env::vars() {
+            if let Some(name) = key.strip_prefix("KANBARS_VAR_") {
+                self.vars.insert(name.to_lowercase(), value);
+            }
+        }
+    }
+    
+    /// Fill `{{name}}` placeholders in every query this config runs.
+    /// `me` and `sprint` default to `currentUser()` and `openSprints()`,
+    /// and `person` is the configured people field.
+    pub fn expand_vars(&mut self) -> Result<(), String> {
+        let vars = self.var_values();
+        self.query.jql = expand_template(&self.query.jql, &vars)?;
+        for extra in &mut self.query.extra {
+            extra.jql = expand_template(&extra.jql, &vars)?;
+        }
+        Ok(())
+    }
+    
+    /// `jql` with its placeholders filled as `expand_vars` fills them
+    pub fn expand(&self, jql: &str) -> Result<String, String> {
+        expand_template(jql, &self.var_values())
+    }
+    
+    fn var_values(&self) -> BTreeMap<String, String> {
+        let mut vars = BTreeMap::from([
+            ("me".to_string(), "currentUser()".to_string()),
+            ("sprint".to_string(), "openSprints()".to_string()),
+            ("person".to_string(), self.fields.person.clone()),
+        ]);
+        vars.extend(self.vars.clone());
+        vars
+    }
+    
+    /// Values that parse but can't work, one message per problem
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+        
+        if let Some(ref url) = self.jira.url
+            && !(url.starts_with("https://") || url.starts_with("http://"))
+        {
+            problems.push(format!("jira.url must start with https:// (got \"{}\")", url));
+        }
+        
+        let queries = std::iter::once(("query".to_string(), &self.query))
+            .chain(self.profiles.iter().map(|(name, q)| (format!("profiles.{}", name), q)));
+        for (section, query) in queries {
+            if query.jql.trim().is_empty() {
+                problems.push(format!("{}.jql is empty", section));
+            }
+            if query.max_results == 0 {
+                problems.push(format!("{}.max_results must be at least 1", section));
+            }
+            if let Some(refresh) = query.refresh
+                && !REFRESH_BOUNDS.contains(&refresh)
+            {
+                problems.push(format!(
+                    "{}.refresh must be between {} and {} seconds (got {})",
+                    section, REFRESH_BOUNDS.start(), REFRESH_BOUNDS.end(), refresh
+                ));
+            }
+        }
+        
+        if let Some(ref language) = self.display.language
+            && !i18n::languages().any(|code| code.eq_ignore_ascii_case(language))
+        {
+            problems.push(format!(
+                "display.language \"{}\" isn't available (choose from {})",
+                language, i18n::languages().collect::<Vec<_>>().join(", ")
+            ));
+        }
+        
+        if let Some(ref split) = self.display.split
+            && self.with_profile(split).is_none()
+        {
+            problems.push(format!("display.split names unknown profile \"{}\"", split));
+        }
+        
+        if let Some(ref hours) = self.refresh.hours
+            && self.refresh.hours().is_none()
+        {
+            problems.push(format!("refresh.hours must look like \"08:00-19:00\" (got \"{}\")", hours));
+        }
+        for day in self.refresh.days.iter().filter(|day| day.parse::<Weekday>().is_err()) {
+            problems.push(format!("refresh.days has \"{}\", which isn't a weekday like \"mon\"", day));
+        }
+        for holiday in self.refresh.holidays.iter().filter(|h| NaiveDate::parse_from_str(h, "%Y-%m-%d").is_err()) {
+            problems.push(format!("refresh.holidays has \"{}\", which isn't a date like \"2025-12-25\"", holiday));
+        }
+        
+        for (name, template) in &self.templates {
+            if template.project.trim().is_empty() {
+                problems.push(format!("templates.{}.project is empty", name));
+            }
+        }
+        
+        if problems.is_empty() { Ok(()) } else { Err(problems) }
+    }
+    
+    /// Read and write this file instead of the usual one (`--config`)
+    pub fn use_path(path: PathBuf) {
+        let _ = CONFIG_PATH.set(path);
+    }
+    
+    /// `--config`, else `config.toml` in `KANBARS_CONFIG_DIR`, else in the
+    /// platform config dir
+    pub fn config_path() -> PathBuf {
+        if let Some(path) = CONFIG_PATH.get() {
+            return path.clone();
+        }
+        
+        let config_dir = match std::env::var_os("KANBARS_CONFIG_DIR") {
+            Some(dir) => PathBuf::from(dir),
+            None => dirs::config_dir()
+                .expect("Could not find config directory")
+                .join("kanbars"),
+        };
         config_dir.join("config.toml")
     }
     
+    /// Where kanbars keeps state between runs: `KANBARS_DATA_DIR`, else
+    /// the platform's local data dir
+    pub fn data_dir() -> Option<PathBuf> {
+        match std::env::var_os("KANBARS_DATA_DIR") {
+            Some(dir) => Some(PathBuf::from(dir)),
+            None => dirs::data_local_dir().map(|dir| dir.join("kanbars")),
+        }
+    }
+    
+    /// Add or replace `[profiles.<name>]` in the config file, leaving the
+    /// rest of the file's settings as they are
+    #[cfg(feature = "tui")]
+    pub fn save_profile(name: &str, jql: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let config_path = Self::config_path();
+        let mut table: toml::Table = match fs::read_to_string(&config_path) {
+            Ok(contents) => toml::from_str(&contents)?,
+            Err(_) => toml::Table::try_from(Self::default())?,
+        };
+        
+        let profiles = table.entry("profiles")
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+            .as_table_mut()
+            .ok_or("`profiles` in the config file isn't a table")?;
+        let mut profile = toml::Table::new();
+        profile.insert("jql".to_string(), toml::Value::String(jql.to_string()));
+        profiles.insert(name.to_string(), toml::Value::Table(profile));
+        
+        if let Some(dir) = config_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(config_path, toml::to_string_pretty(&table)?)?;
+        Ok(())
+    }
+    
     pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
         let config_path = Self::config_path();
+        if let Some(dir) = config_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
         let toml_string = toml::to_string_pretty(self)?;
         fs::write(config_path, toml_string)?;
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// Whether `jql` has a `{{name}}` placeholder
+pub fn uses_var(jql: &str, name: &str) -> bool {
+    placeholders(jql).any(|(_, found)| found == name)
+}
+
+// Each `{{name}}` as (byte range, trimmed name)
+fn placeholders(jql: &str) -> impl Iterator<Item = (std::ops::Range<usize>, &str)> {
+    let mut from = 0;
+    std::iter::from_fn(move || {
+        let start = from + jql[from..].find("{{")?;
+        let len = jql[start + 2..].find("}}")?;
+        let end = start + 2 + len + 2;
+        from = end;
+        Some((start..end, jql[start + 2..start + 2 + len].trim()))
+    })
+}
+
+// Replace each `{{name}}` with its value, failing on names with no value
+fn expand_template(jql: &str, vars: &BTreeMap<String, String>) -> Result<String, String> {
+    let mut expanded = String::new();
+    let mut copied = 0;
+    for (range, name) in placeholders(jql) {
+        let value = vars.get(name).ok_or_else(|| format!(
+            "No value for {{{{{}}}}} in JQL; set it under [vars], with KANBARS_VAR_{} or --var {}=...",
+            name, name.to_uppercase(), name
+        ))?;
+        expanded.push_str(&jql[copied..range.start]);
+        expanded.push_str(value);
+        copied = range.end;
+    }
+    expanded.push_str(&jql[copied..]);
+    Ok(expanded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overridden(vars: &[(&str, &str)]) -> Result<Config, String> {
+        Config::default().apply_env_overrides(vars.iter().map(|(name, value)| (name.to_string(), value.to_string())))
+    }
+
+    #[test]
+    fn env_overrides_read_typed_values() {
+        let config = overridden(&[("KANBARS_DISPLAY_DAILY_SUMMARY", "false"), ("KANBARS_QUERY_MAX_RESULTS", "25")]).expect("valid");
+        assert!(!config.display.daily_summary);
+        assert_eq!(config.query.max_results, 25);
+        assert!(Config::default().display.daily_summary);
+    }
+
+    #[test]
+    fn env_overrides_keep_text_that_looks_like_a_number() {
+        let config = overridden(&[("KANBARS_JIRA_API_TOKEN", "0123456789"), ("KANBARS_QUERY_NAME", "2024")]).expect("valid");
+        assert_eq!(config.jira.api_token.as_deref(), Some("0123456789"));
+        assert_eq!(config.query.name, "2024");
+    }
+
+    #[test]
+    fn env_overrides_name_the_variable_they_came_from() {
+        let unknown = overridden(&[("KANBARS_DISPLAY_NO_SUCH_SETTING", "1")]).unwrap_err();
+        assert!(unknown.starts_with("Invalid KANBARS_DISPLAY_NO_SUCH_SETTING: "), "{}", unknown);
+        assert!(unknown.contains("no_such_setting"));
+        let bad = overridden(&[("KANBARS_QUERY_MAX_RESULTS", "lots")]).unwrap_err();
+        assert!(bad.starts_with("Invalid KANBARS_QUERY_MAX_RESULTS: "), "{}", bad);
+        assert!(!bad.contains("config"));
+    }
+
+    #[test]
+    fn other_kanbars_variables_are_not_settings() {
+        let config = overridden(&[("KANBARS_DATA_DIR", "/tmp"), ("KANBARS_REFRESH", "lots"), ("KANBARS_VAR_TEAM", "x")]).expect("ignored");
+        assert_eq!(config.query.max_results, Config::default().query.max_results);
+    }
+}
@@ -0,0 +1,98 @@
+// `--debug`: keeps the last raw JSON responses in the data dir, and each
+// ticket's raw payload in memory for the in-app viewer, for working out
+// why a field shows up wrong without reaching for curl
+use crate::config::Config;
+use chrono::Local;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+/// Response files kept on disk; older ones are deleted
+const KEEP: usize = 50;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+fn issues() -> &'static Mutex<HashMap<String, String>> {
+    static ISSUES: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
+    ISSUES.get_or_init(Mutex::default)
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn dir() -> Option<PathBuf> {
+    Some(Config::data_dir()?.join("debug"))
+}
+
+/// Save a response and remember the issues in it. Does nothing unless
+/// `--debug` is on.
+pub fn record(url: &str, status: u16, body: &[u8]) {
+    if !enabled() {
+        return;
+    }
+
+    let json: Option<Value> = serde_json::from_slice(body).ok();
+    if let Some(ref json) = json {
+        remember_issues(json);
+    }
+
+    let Some(dir) = dir() else { return };
+    let entry = serde_json::json!({
+        "url": url,
+        "status": status,
+        "body": json.unwrap_or_else(|| Value::String(String::from_utf8_lossy(body).into_owned())),
+    });
+    let name = format!(
+        "{}-{:04}.json",
+        Local::now().format("%Y%m%dT%H%M%S%.3f"),
+        SEQUENCE.fetch_add(1, Ordering::Relaxed) % 10_000,
+    );
+    let written = fs::create_dir_all(&dir)
+        .and_then(|()| fs::write(dir.join(name), serde_json::to_string_pretty(&entry).unwrap_or_default()));
+    if written.is_ok() {
+        prune(&dir);
+    }
+}
+
+/// The last raw payload seen for a ticket, pretty-printed
+#[cfg(feature = "tui")]
+pub fn raw_issue(key: &str) -> Option<String> {
+    issues().lock().ok()?.get(key).cloned()
+}
+
+// Search results carry an `issues` array; a single issue has its own key
+// and fields. A detail fetch replaces the leaner board payload.
+fn remember_issues(json: &Value) {
+    let Ok(mut issues) = issues().lock() else { return };
+    let mut remember = |issue: &Value| {
+        if let Some(key) = issue.get("key").and_then(Value::as_str) && issue.get("fields").is_some() {
+            issues.insert(key.to_string(), serde_json::to_string_pretty(issue).unwrap_or_default());
+        }
+    };
+    match json.get("issues").and_then(Value::as_array) {
+        Some(found) => found.iter().for_each(&mut remember),
+        None => remember(json),
+    }
+}
+
+// Names start with the time, so sorting them puts the oldest first
+fn prune(dir: &Path) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+    for path in &files[..files.len().saturating_sub(KEEP)] {
+        let _ = fs::remove_file(path);
+    }
+}
@@ -0,0 +1,180 @@
+// A made-up board for `--demo`: realistic enough to try every view, record
+// a screencast or test the UI against, without JIRA. The same seed gives
+// the same board on every run, and nothing touches the network.
+use crate::config::Config;
+use crate::model::{Comment, IssueLink, ParentRef, Sprint, SprintState, StatusCategory, Ticket, TicketType, TimeTracking};
+
+const SEED: u64 = 0x006b_616e_6261_7273;  // "kanbars"
+const TICKETS: usize = 28;
+
+const STATUSES: &[(&str, StatusCategory)] = &[
+    ("To Do", StatusCategory::ToDo),
+    ("In Progress", StatusCategory::InProgress),
+    ("In Review", StatusCategory::InProgress),
+    ("Done", StatusCategory::Done),
+];
+const TYPES: &[&str] = &["Story", "Story", "Task", "Task", "Bug"];
+const PEOPLE: &[&str] = &["Ada Lovelace", "Grace Hopper", "Linus Pauling", "Margaret Hamilton", "Ken Iverson"];
+const COMPONENTS: &[&str] = &["api", "web", "billing", "search"];
+const VERSIONS: &[&str] = &["2.4", "2.5"];
+const LABELS: &[&str] = &["tech-debt", "customer", "perf", "a11y"];
+const EPICS: &[(&str, &str)] = &[
+    ("DEMO-1", "Self-serve billing"),
+    ("DEMO-2", "Search that finds things"),
+    ("DEMO-3", "Faster page loads"),
+];
+const SPRINTS: &[(&str, SprintState)] = &[
+    ("Sprint 41", SprintState::Closed),
+    ("Sprint 42", SprintState::Active),
+    ("Sprint 43", SprintState::Future),
+];
+const VERBS: &[&str] = &["Fix", "Add", "Speed up", "Clean up", "Document", "Retry", "Cache", "Validate"];
+const THINGS: &[&str] = &[
+    "invoice PDF export",
+    "search result ranking",
+    "login rate limiting",
+    "webhook delivery",
+    "dark mode toggle",
+    "CSV import",
+    "password reset emails",
+    "dashboard charts",
+    "API pagination",
+    "tax calculation",
+];
+const COMMENTS: &[&str] = &[
+    "Reproduced on staging, looking into it now.",
+    "Can we split this? The **API** part could ship first.",
+    "Pushed a fix behind the `new-flow` flag.",
+    "Looks good to me 👍",
+];
+
+// xorshift64*: tiny, and stable across platforms and releases
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    fn chance(&mut self, percent: u64) -> bool {
+        self.next() % 100 < percent
+    }
+}
+
+/// The demo board's tickets, the same on every call
+pub fn tickets(config: &Config) -> Vec<Ticket> {
+    let mut rng = Rng(SEED);
+    let mut tickets: Vec<Ticket> = (0..TICKETS).map(|i| ticket(&mut rng, config, i)).collect();
+
+    // A couple of fixed tickets so the incident and blocker styles show
+    let mut incident = ticket(&mut rng, config, TICKETS);
+    incident.ticket_type = TicketType::Bug;
+    incident.summary = "Checkout returns 500 for EU customers".to_string();
+    incident.status = "In Progress".to_string();
+    incident.status_category = Some(StatusCategory::InProgress);
+    incident.priority = Some("Highest".to_string());
+    incident.incident = config.incidents.matches("Bug", incident.priority.as_deref());
+    incident.flagged = true;
+    tickets.push(incident);
+
+    let blocked = tickets.iter().position(|t| t.status == "To Do").unwrap_or_default();
+    let blocker = tickets.iter().find(|t| t.status == "In Progress").map(|t| (t.key.clone(), t.summary.clone()));
+    if let Some((key, summary)) = blocker {
+        tickets[blocked].links = Some(vec![IssueLink {
+            relation: "is blocked by".to_string(),
+            key,
+            summary,
+            status: "In Progress".to_string(),
+        }]);
+    }
+    tickets
+}
+
+fn ticket(rng: &mut Rng, config: &Config, index: usize) -> Ticket {
+    let (status, category) = *rng.pick(STATUSES);
+    let issue_type = *rng.pick(TYPES);
+    let priority = *rng.pick(&["Low", "Medium", "Medium", "High"]);
+    let summary = format!("{} {}", rng.pick(VERBS), rng.pick(THINGS));
+    let assignee = if rng.chance(15) { "unassigned".to_string() } else { rng.pick(PEOPLE).to_string() };
+    let (epic_key, epic_summary) = *rng.pick(EPICS);
+    let (sprint, sprint_state) = *rng.pick(SPRINTS);
+    let day = 1 + rng.below(27);
+
+    let mut components = vec![rng.pick(COMPONENTS).to_string()];
+    if rng.chance(20) {
+        let other = rng.pick(COMPONENTS).to_string();
+        if !components.contains(&other) {
+            components.push(other);
+        }
+    }
+    let comments = (0..rng.below(3))
+        .map(|i| Comment {
+            author: rng.pick(PEOPLE).to_string(),
+            created: format!("2025-03-{:02}T1{}:15:00.000+0000", day.max(i + 1), i),
+            body: rng.pick(COMMENTS).to_string(),
+            public: None,
+            id: format!("{}", 10_000 + index * 10 + i),
+            ari: None,
+            reactions: Vec::new(),
+        })
+        .collect();
+    let time_tracking = rng.chance(40).then(|| {
+        let estimate = (1 + rng.below(5)) as u64 * 8 * 3600;
+        let spent = if category == StatusCategory::ToDo { 0 } else { estimate / (1 + rng.below(3)) as u64 };
+        TimeTracking {
+            original_estimate: Some(format!("{}d", estimate / (8 * 3600))),
+            remaining_estimate: Some(format!("{}h", (estimate - spent) / 3600)),
+            time_spent: (spent > 0).then(|| format!("{}h", spent / 3600)),
+            original_estimate_seconds: Some(estimate),
+            remaining_estimate_seconds: Some(estimate - spent),
+            time_spent_seconds: (spent > 0).then_some(spent),
+        }
+    });
+
+    Ticket {
+        key: format!("DEMO-{}", 10 + index),
+        ticket_type: TicketType::from_name(issue_type),
+        summary,
+        status: status.to_string(),
+        assignee,
+        description: Some(format!(
+            "Part of {}.\n\nAcceptance criteria:\n- Works for existing accounts\n- Covered by tests\n- Docs updated",
+            epic_summary,
+        )),
+        priority: Some(priority.to_string()),
+        reporter: Some(rng.pick(PEOPLE).to_string()),
+        created: Some(format!("2025-02-{:02}T09:30:00.000+0000", day)),
+        updated: Some(format!("2025-03-{:02}T16:45:00.000+0000", day)),
+        labels: Some(if rng.chance(30) { vec![rng.pick(LABELS).to_string()] } else { Vec::new() }),
+        due_date: index.is_multiple_of(4).then(|| format!("2025-03-{:02}", 10 + index % 18)),
+        comments: Some(comments),
+        links: Some(Vec::new()),
+        remote_links: Some(Vec::new()),
+        parent: Some(ParentRef { key: epic_key.to_string(), summary: epic_summary.to_string(), is_epic: true, url_index: None }),
+        components,
+        fix_versions: if rng.chance(70) { vec![rng.pick(VERSIONS).to_string()] } else { Vec::new() },
+        status_category: Some(category),
+        resolution: (category == StatusCategory::Done).then(|| "Done".to_string()),
+        time_tracking,
+        // Not drawn from `rng`, so the rest of the board stays as it was
+        story_points: (issue_type != "Bug").then(|| [1.0, 2.0, 3.0, 5.0, 8.0][index % 5]),
+        flagged: rng.chance(8),
+        incident: config.incidents.matches(issue_type, Some(priority)),
+        watch_count: Some(1 + rng.below(4) as u64),
+        vote_count: Some(0),
+        watchers: Some(vec![rng.pick(PEOPLE).to_string()]),
+        sprint: Some(Sprint { name: sprint.to_string(), state: sprint_state }),
+        ..Default::default()
+    }
+}
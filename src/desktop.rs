@@ -0,0 +1,45 @@
+use std::io;
+use std::process::{Command, Stdio};
+
+// Pop up a desktop notification with the platform's own tool, without
+// blocking the UI
+pub fn notify(title: &str, body: &str) -> io::Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        let mut c = Command::new("osascript");
+        c.arg("-e").arg(format!(
+            "display notification {} with title {}",
+            applescript_string(body),
+            applescript_string(title),
+        ));
+        c
+    } else if cfg!(target_os = "windows") {
+        // A balloon tip from the tray, which needs no extra modules. The text
+        // comes from tickets, so it reaches the script through the
+        // environment and is never part of it.
+        let script = "Add-Type -AssemblyName System.Windows.Forms; \
+             $n = New-Object System.Windows.Forms.NotifyIcon; \
+             $n.Icon = [System.Drawing.SystemIcons]::Warning; $n.Visible = $true; \
+             $n.ShowBalloonTip(10000, $env:KANBARS_NOTIFY_TITLE, $env:KANBARS_NOTIFY_BODY, 'Warning'); \
+             Start-Sleep -Seconds 10; $n.Dispose()";
+        let mut c = Command::new("powershell");
+        c.args(["-NoProfile", "-NonInteractive", "-Command", script])
+            .env("KANBARS_NOTIFY_TITLE", title)
+            .env("KANBARS_NOTIFY_BODY", body);
+        c
+    } else {
+        let mut c = Command::new("notify-send");
+        c.args(["--urgency=critical", "--app-name=kanbars", title, body]);
+        c
+    };
+    
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
+
+fn applescript_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -0,0 +1,85 @@
+// `kanbars doctor`: walk through everything a board needs and say which
+// piece is missing, instead of leaving users to decode a failed refresh
+use crate::acli;
+use crate::config::{Backend, Config};
+use crate::jira_api;
+
+pub fn run() -> bool {
+    let mut healthy = true;
+    let mut check = |ok: bool, message: String| {
+        println!("{} {}", if ok { "✅" } else { "❌" }, message);
+        healthy &= ok;
+    };
+
+    let path = Config::config_path();
+    if !path.exists() {
+        println!("➖ No config file at {} (using defaults and environment variables)", path.display());
+    }
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            check(false, e);
+            return false;
+        }
+    };
+    if path.exists() {
+        check(true, format!("Config file {}", path.display()));
+    }
+
+    let jira = &config.jira;
+    check(jira.url.is_some(), format!("JIRA URL: {}", jira.url.as_deref().unwrap_or("not set (JIRA_URL or JIRA_SITE)")));
+    check(jira.email.is_some(), format!("Email: {}", jira.email.as_deref().unwrap_or("not set (JIRA_USER or JIRA_EMAIL)")));
+
+    let has_token = jira.api_token.is_some();
+    let acli_found = acli::installed();
+    match jira.backend {
+        Backend::Api => check(has_token, token_message(has_token)),
+        Backend::Acli => check(acli_found, acli_message(acli_found)),
+        Backend::Auto => {
+            check(has_token || acli_found, token_message(has_token));
+            println!("{} {}", if acli_found { "✅" } else { "➖" }, acli_message(acli_found));
+        }
+        Backend::Demo => println!("➖ Demo backend: sample tickets, JIRA is never contacted"),
+    }
+
+    if has_token && jira.url.is_some() && jira.email.is_some() {
+        match jira_api::fetch_myself(&config) {
+            Ok(account) => check(true, format!("Signed in to JIRA as {}", account.name)),
+            Err(e) => check(false, format!("Could not reach JIRA: {}", e.to_string().lines().next().unwrap_or_default())),
+        }
+    }
+
+    healthy
+}
+
+/// Settings no backend can do without, as "config key or ENV_VAR" hints;
+/// empty once the board can be fetched
+#[cfg(feature = "tui")]
+pub fn missing_credentials(config: &Config) -> Vec<&'static str> {
+    let jira = &config.jira;
+    let api_missing: Vec<&'static str> = [
+        (jira.url.is_none(), "jira.url or JIRA_URL"),
+        (jira.email.is_none(), "jira.email or JIRA_EMAIL"),
+        (jira.api_token.is_none(), "jira.api_token or JIRA_API_TOKEN"),
+    ]
+    .into_iter()
+    .filter_map(|(missing, hint)| missing.then_some(hint))
+    .collect();
+
+    match jira.backend {
+        Backend::Api => api_missing,
+        Backend::Acli if acli::installed() => Vec::new(),
+        Backend::Acli => vec!["acli on PATH (logged in with `acli jira auth login`)"],
+        Backend::Auto if api_missing.is_empty() || acli::installed() => Vec::new(),
+        Backend::Auto => api_missing,
+        Backend::Demo => Vec::new(),
+    }
+}
+
+fn token_message(has_token: bool) -> String {
+    if has_token { "API token set".to_string() } else { "API token not set (JIRA_API_TOKEN)".to_string() }
+}
+
+fn acli_message(found: bool) -> String {
+    if found { "acli found on PATH".to_string() } else { "acli not found on PATH".to_string() }
+}
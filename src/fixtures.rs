@@ -0,0 +1,140 @@
+// `--record DIR` / `--replay DIR`: save every JIRA response to a file, or
+// answer requests from those files instead of the network, so the whole
+// fetch, model and render pipeline can be run end to end against fixed
+// data. Requests are matched by method, path, query and body; the host and
+// credentials don't take part, so fixtures replay against any JIRA URL.
+use reqwest::blocking::{Request, Response};
+use serde_json::{json, Map, Value};
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+enum Mode {
+    Record(PathBuf),
+    Replay(PathBuf),
+}
+
+static MODE: OnceLock<Mode> = OnceLock::new();
+
+/// Save responses into `dir` from now on
+pub fn record_to(dir: PathBuf) {
+    let _ = MODE.set(Mode::Record(dir));
+}
+
+/// Answer requests from the responses saved in `dir`
+pub fn replay_from(dir: PathBuf) {
+    let _ = MODE.set(Mode::Replay(dir));
+}
+
+/// One request's file while recording or replaying
+pub struct Fixture {
+    path: PathBuf,
+    request: String,  // e.g. "GET /rest/api/3/myself", for error messages and the file
+    replay: bool,
+}
+
+impl Fixture {
+    /// The file for `request`, or None when neither mode is on
+    pub fn of(request: &Request) -> Option<Fixture> {
+        let (dir, replay) = match MODE.get()? {
+            Mode::Record(dir) => (dir, false),
+            Mode::Replay(dir) => (dir, true),
+        };
+        let url = request.url();
+        let target = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        };
+        let body = request.body().and_then(|body| body.as_bytes()).unwrap_or_default();
+
+        let mut hash = fnv1a(FNV_OFFSET, request.method().as_str().as_bytes());
+        hash = fnv1a(hash, target.as_bytes());
+        hash = fnv1a(hash, body);
+        let slug: String = url.path()
+            .trim_start_matches("/rest/")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .take(60)
+            .collect();
+        Some(Fixture {
+            path: dir.join(format!("{}-{}-{:016x}.json", request.method(), slug, hash)),
+            request: format!("{} {}", request.method(), target),
+            replay,
+        })
+    }
+
+    pub fn replaying(&self) -> bool {
+        self.replay
+    }
+
+    /// The saved response
+    pub fn load(&self) -> Result<Response, Box<dyn Error>> {
+        let contents = fs::read_to_string(&self.path)
+            .map_err(|_| format!("No recorded response for {} ({})", self.request, self.path.display()))?;
+        let saved: Value = serde_json::from_str(&contents)
+            .map_err(|e| format!("Fixture {} is not valid JSON: {}", self.path.display(), e))?;
+
+        let status = saved.get("status").and_then(Value::as_u64).unwrap_or(200);
+        let body = match (saved.get("json"), saved.get("text")) {
+            (Some(json), _) => serde_json::to_vec(json)?,
+            (None, Some(text)) => text.as_str().unwrap_or_default().as_bytes().to_vec(),
+            (None, None) => Vec::new(),
+        };
+        let mut response = http::Response::builder().status(u16::try_from(status)?);
+        for (name, value) in saved.get("headers").and_then(Value::as_object).into_iter().flatten() {
+            if let Some(value) = value.as_str() {
+                response = response.header(name, value);
+            }
+        }
+        Ok(Response::from(response.body(body)?))
+    }
+
+    /// Write `response` to the fixture file while recording, handing back
+    /// an equivalent response to parse as usual
+    pub fn save(&self, response: Response) -> Result<Response, Box<dyn Error>> {
+        if self.replay {
+            return Ok(response);
+        }
+        let status = response.status();
+        let version = response.version();
+        let headers = response.headers().clone();
+        let body = response.bytes()?;
+
+        // Cookies are the only headers that could let someone in
+        let saved_headers: Map<String, Value> = headers.iter()
+            .filter(|(name, _)| *name != http::header::SET_COOKIE)
+            .filter_map(|(name, value)| Some((name.to_string(), Value::from(value.to_str().ok()?))))
+            .collect();
+        let mut saved = json!({
+            "request": self.request,
+            "status": status.as_u16(),
+            "headers": saved_headers,
+        });
+        // JSON bodies are kept as JSON so fixtures read and diff well
+        match serde_json::from_slice::<Value>(&body) {
+            Ok(json) => saved["json"] = json,
+            Err(_) => saved["text"] = Value::from(String::from_utf8_lossy(&body)),
+        }
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&saved)?)?;
+
+        let mut rebuilt = http::Response::builder().status(status).version(version).body(body)?;
+        *rebuilt.headers_mut() = headers;
+        Ok(Response::from(rebuilt))
+    }
+}
+
+// FNV-1a, which unlike std's hasher is guaranteed to stay the same between
+// releases, so fixture names do too
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
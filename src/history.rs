@@ -0,0 +1,215 @@
+// Snapshots of each board over time, kept on disk so `kanbars diff` and
+// the `D` view can say which tickets entered, left or moved columns since a
+// given moment
+use crate::config::Config;
+use crate::model::Ticket;
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveTime, TimeZone};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+// Snapshots older than this are dropped
+const RETENTION_DAYS: i64 = 30;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotTicket {
+    pub key: String,
+    pub status: String,
+    pub summary: String,
+    pub assignee: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Snapshot {
+    time: String,  // RFC 3339
+    tickets: Vec<SnapshotTicket>,
+}
+
+impl Snapshot {
+    fn time(&self) -> Option<DateTime<Local>> {
+        DateTime::parse_from_rfc3339(&self.time).ok().map(|t| t.with_timezone(&Local))
+    }
+}
+
+// Last snapshot written per board, so unchanged refreshes aren't appended
+static LAST_WRITTEN: Mutex<Option<HashMap<String, Vec<SnapshotTicket>>>> = Mutex::new(None);
+
+fn history_path(board: &str) -> Option<PathBuf> {
+    let file_name: String = board.chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    Some(Config::data_dir()?.join("history").join(format!("{}.jsonl", file_name)))
+}
+
+fn snapshot_tickets(tickets: &[Ticket]) -> Vec<SnapshotTicket> {
+    let mut snapshot: Vec<SnapshotTicket> = tickets.iter()
+        .map(|t| SnapshotTicket {
+            key: t.key.clone(),
+            status: t.status.clone(),
+            summary: t.summary.clone(),
+            assignee: t.assignee.clone(),
+        })
+        .collect();
+    snapshot.sort_by(|a, b| a.key.cmp(&b.key));
+    snapshot
+}
+
+fn load(board: &str) -> Vec<Snapshot> {
+    let Some(path) = history_path(board) else { return Vec::new() };
+    let Ok(contents) = fs::read_to_string(path) else { return Vec::new() };
+    contents.lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// Append the board's tickets to its history when they differ from the last
+/// snapshot. The first write of a session also drops expired snapshots.
+/// History is a convenience, so failures are ignored.
+pub fn record(board: &str, tickets: &[Ticket]) {
+    let tickets = snapshot_tickets(tickets);
+    let Ok(mut last_written) = LAST_WRITTEN.lock() else { return };
+    let last_written = last_written.get_or_insert_with(HashMap::new);
+    let Some(path) = history_path(board) else { return };
+
+    let first_write = !last_written.contains_key(board);
+    let previous = match last_written.get(board) {
+        Some(previous) => Some(previous.clone()),
+        None => load(board).pop().map(|s| s.tickets),
+    };
+    if previous.as_ref() == Some(&tickets) {
+        last_written.insert(board.to_string(), tickets);
+        return;
+    }
+
+    let snapshot = Snapshot { time: Local::now().to_rfc3339(), tickets };
+    let Ok(line) = serde_json::to_string(&snapshot) else { return };
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+
+    if first_write {
+        let cutoff = Local::now() - Duration::days(RETENTION_DAYS);
+        let kept: Vec<String> = load(board).iter()
+            .filter(|s| s.time().is_some_and(|t| t >= cutoff))
+            .filter_map(|s| serde_json::to_string(s).ok())
+            .chain(std::iter::once(line))
+            .collect();
+        let _ = fs::write(&path, kept.join("\n") + "\n");
+    } else if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(&path) {
+        let _ = writeln!(file, "{}", line);
+    }
+    last_written.insert(board.to_string(), snapshot.tickets);
+}
+
+/// The board as last seen, for showing something before it can be fetched
+pub fn latest(board: &str) -> Option<(DateTime<Local>, Vec<Ticket>)> {
+    let snapshot = load(board).pop()?;
+    let time = snapshot.time()?;
+    let tickets = snapshot.tickets.into_iter()
+        .map(|t| Ticket {
+            key: t.key,
+            status: t.status,
+            summary: t.summary,
+            assignee: t.assignee,
+            ..Default::default()
+        })
+        .collect();
+    Some((time, tickets))
+}
+
+#[derive(Debug, Clone)]
+pub enum Change {
+    Entered(SnapshotTicket),
+    Left(SnapshotTicket),
+    Moved { ticket: SnapshotTicket, from: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct BoardDiff {
+    pub since: DateTime<Local>,
+    pub baseline: DateTime<Local>,  // When the snapshot compared against was taken
+    pub changes: Vec<Change>,
+}
+
+/// What changed on the board between the last snapshot at or before
+/// `since` (or the oldest one, if history starts later) and `tickets`
+pub fn diff(board: &str, since: DateTime<Local>, tickets: &[Ticket]) -> Result<BoardDiff, String> {
+    let snapshots = load(board);
+    let baseline = snapshots.iter()
+        .rfind(|s| s.time().is_some_and(|t| t <= since))
+        .or(snapshots.first())
+        .ok_or_else(|| format!("No history for board '{}' yet; it's recorded each time the board loads", board))?;
+
+    let before: BTreeMap<&str, &SnapshotTicket> = baseline.tickets.iter().map(|t| (t.key.as_str(), t)).collect();
+    let now = snapshot_tickets(tickets);
+    let after: BTreeMap<&str, &SnapshotTicket> = now.iter().map(|t| (t.key.as_str(), t)).collect();
+
+    let mut changes = Vec::new();
+    for (key, ticket) in &after {
+        match before.get(key) {
+            None => changes.push(Change::Entered((*ticket).clone())),
+            Some(old) if old.status != ticket.status => changes.push(Change::Moved {
+                ticket: (*ticket).clone(),
+                from: old.status.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+    for (key, ticket) in &before {
+        if !after.contains_key(key) {
+            changes.push(Change::Left((*ticket).clone()));
+        }
+    }
+
+    Ok(BoardDiff {
+        since,
+        baseline: baseline.time().unwrap_or(since),
+        changes,
+    })
+}
+
+/// Parse a point in time: "yesterday 09:00", "today", "09:30", "2024-05-01",
+/// "2024-05-01 14:00", RFC 3339, or an age like "24h", "3d", "90m"
+pub fn parse_since(text: &str) -> Result<DateTime<Local>, String> {
+    let text = text.trim();
+    let invalid = || format!("Can't read '{}' as a time (try \"yesterday 09:00\", \"24h\" or \"2024-05-01\")", text);
+
+    if let Ok(time) = DateTime::parse_from_rfc3339(text) {
+        return Ok(time.with_timezone(&Local));
+    }
+
+    // Ages: "90m", "24h", "3d", "2w"
+    if let Some(unit) = text.chars().last()
+        && let Ok(amount) = text[..text.len() - unit.len_utf8()].trim().parse::<i64>()
+    {
+        let age = match unit {
+            'm' => Duration::try_minutes(amount),
+            'h' => Duration::try_hours(amount),
+            'd' => Duration::try_days(amount),
+            'w' => Duration::try_weeks(amount),
+            _ => None,
+        };
+        // Ages too long to reach back to are as unreadable as bad units
+        return age.and_then(|age| Local::now().checked_sub_signed(age)).ok_or_else(invalid);
+    }
+
+    let (day, time) = match text.split_once(' ') {
+        Some((day, time)) => (day, Some(time.trim())),
+        None if text.contains(':') => ("today", Some(text)),
+        None => (text, None),
+    };
+    let today = Local::now().date_naive();
+    let date = match day.to_ascii_lowercase().as_str() {
+        "today" => today,
+        "yesterday" => today - Duration::days(1),
+        _ => NaiveDate::parse_from_str(day, "%Y-%m-%d").map_err(|_| invalid())?,
+    };
+    let time = match time {
+        Some(time) => NaiveTime::parse_from_str(time, "%H:%M").map_err(|_| invalid())?,
+        None => NaiveTime::MIN,
+    };
+    Local.from_local_datetime(&date.and_time(time)).earliest().ok_or_else(invalid)
+}
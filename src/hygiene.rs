@@ -0,0 +1,40 @@
+// Team rules checked over the board, like "nothing in the sprint without
+// an estimate". Tickets that break one get a ⚠ on their card, and `H` lists
+// them by rule.
+use crate::config::HygieneRule;
+use crate::model::{SprintState, StatusCategory, Ticket, TicketType};
+
+impl HygieneRule {
+    /// Whether `ticket` breaks the rule. Done tickets break none.
+    pub fn broken_by(self, ticket: &Ticket) -> bool {
+        if ticket.status_category == Some(StatusCategory::Done) {
+            return false;
+        }
+        match self {
+            HygieneRule::UnestimatedInSprint => {
+                ticket.sprint.as_ref().is_some_and(|s| s.state == SprintState::Active) && ticket.story_points.is_none()
+            }
+            HygieneRule::UnassignedInProgress => {
+                ticket.status_category == Some(StatusCategory::InProgress)
+                    && (ticket.assignee.is_empty() || ticket.assignee == "unassigned")
+            }
+            HygieneRule::BugWithoutPriority => {
+                matches!(ticket.ticket_type, TicketType::Bug) && ticket.priority.as_deref().is_none_or(str::is_empty)
+            }
+        }
+    }
+
+    /// Locale key of what the rule asks for
+    pub fn label(self) -> &'static str {
+        match self {
+            HygieneRule::UnestimatedInSprint => "hygiene.unestimated_in_sprint",
+            HygieneRule::UnassignedInProgress => "hygiene.unassigned_in_progress",
+            HygieneRule::BugWithoutPriority => "hygiene.bug_without_priority",
+        }
+    }
+}
+
+/// The rules `ticket` breaks
+pub fn problems(ticket: &Ticket, rules: &[HygieneRule]) -> Vec<HygieneRule> {
+    rules.iter().copied().filter(|rule| rule.broken_by(ticket)).collect()
+}
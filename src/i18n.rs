@@ -0,0 +1,90 @@
+// User-facing text by locale. Locale files are TOML tables of strings built
+// into the binary; English is complete and fills in whatever a community
+// locale leaves out, so a partial translation still works.
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+const ENGLISH: &str = include_str!("../locales/en.toml");
+
+// Locales besides English, by language code
+const LOCALES: &[(&str, &str)] = &[
+    ("de", include_str!("../locales/de.toml")),
+    ("es", include_str!("../locales/es.toml")),
+];
+
+static LANGUAGE: OnceLock<Option<String>> = OnceLock::new();
+static STRINGS: OnceLock<HashMap<String, String>> = OnceLock::new();
+
+/// Use `language` (e.g. "es") instead of the one from the environment.
+/// Must be called before the first lookup to take effect.
+pub fn set_language(language: Option<&str>) {
+    let _ = LANGUAGE.set(language.map(str::to_string));
+}
+
+/// Language codes with a locale file
+pub fn languages() -> impl Iterator<Item = &'static str> {
+    std::iter::once("en").chain(LOCALES.iter().map(|(code, _)| *code))
+}
+
+// "es_ES.UTF-8" → "es"; "C" and "POSIX" mean no preference
+fn language_code(locale: &str) -> Option<String> {
+    let code = locale.split(['_', '.', '@', '-']).next()?.to_lowercase();
+    (!code.is_empty() && code != "c" && code != "posix").then_some(code)
+}
+
+// Configured language, else the usual locale variables in order of precedence
+fn language() -> Option<String> {
+    if let Some(Some(language)) = LANGUAGE.get() {
+        return language_code(language);
+    }
+    ["LC_ALL", "LC_MESSAGES", "LANG"].iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+        .and_then(|value| language_code(&value))
+}
+
+// Flatten `[section] key = "…"` into "section.key"
+fn flatten(prefix: &str, table: &toml::Table, strings: &mut HashMap<String, String>) {
+    for (key, value) in table {
+        let key = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+        match value {
+            toml::Value::String(text) => {
+                strings.insert(key, text.clone());
+            }
+            toml::Value::Table(table) => flatten(&key, table, strings),
+            _ => {}
+        }
+    }
+}
+
+fn strings() -> &'static HashMap<String, String> {
+    STRINGS.get_or_init(|| {
+        let mut strings = HashMap::new();
+        if let Ok(table) = ENGLISH.parse::<toml::Table>() {
+            flatten("", &table, &mut strings);
+        }
+        let locale = language()
+            .and_then(|code| LOCALES.iter().find(|(name, _)| *name == code))
+            .and_then(|(_, contents)| contents.parse::<toml::Table>().ok());
+        if let Some(table) = locale {
+            flatten("", &table, &mut strings);
+        }
+        strings
+    })
+}
+
+/// The text for `key` (e.g. "help.quit") in the current language. Unknown
+/// keys come back as-is, so a typo shows up on screen rather than as a blank.
+pub fn t(key: &'static str) -> &'static str {
+    strings().get(key).map(String::as_str).unwrap_or(key)
+}
+
+/// `t`, with `{name}` placeholders filled in
+pub fn tf(key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+    let mut text = t(key).to_string();
+    for (name, value) in args {
+        text = text.replace(&format!("{{{}}}", name), &value.to_string());
+    }
+    text
+}
@@ -0,0 +1,103 @@
+// Other people's comments on tickets you watch, gathered into one list so
+// mentions don't need the JIRA web notification bell. Which comments have
+// been read is kept in the data dir between runs.
+use crate::config::Config;
+use crate::jira_api::{self, WatchedComment};
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+// How far back to look for comments
+const WINDOW_DAYS: u32 = 14;
+
+#[derive(Debug, Default)]
+pub struct Inbox {
+    pub items: Vec<WatchedComment>,
+    pub selected: usize,
+    pub error: Option<String>,  // Why the inbox couldn't be loaded
+    read: HashSet<String>,  // Comment ids
+}
+
+fn read_path() -> Option<PathBuf> {
+    Some(Config::data_dir()?.join("inbox-read"))
+}
+
+impl Inbox {
+    /// Fetch the inbox, marking what was read in earlier sessions
+    pub fn load(config: &Config) -> Self {
+        let read = read_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|contents| contents.lines().map(str::to_string).collect())
+            .unwrap_or_default();
+        let fetched = jira_api::fetch_myself(config)
+            .and_then(|me| jira_api::fetch_watched_comments(config, &me, WINDOW_DAYS));
+        match fetched {
+            Ok(items) => Inbox { items, read, ..Default::default() },
+            Err(e) => Inbox {
+                error: Some(e.to_string().lines().next().unwrap_or_default().to_string()),
+                read,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn is_unread(&self, item: &WatchedComment) -> bool {
+        !self.read.contains(&item.id)
+    }
+
+    pub fn unread_count(&self) -> usize {
+        self.items.iter().filter(|item| self.is_unread(item)).count()
+    }
+
+    pub fn selected_item(&self) -> Option<&WatchedComment> {
+        self.items.get(self.selected)
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.items.is_empty() {
+            self.selected = (self.selected + 1) % self.items.len();
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        if !self.items.is_empty() {
+            self.selected = (self.selected + self.items.len() - 1) % self.items.len();
+        }
+    }
+
+    /// Flip the selected comment between read and unread
+    pub fn toggle_read(&mut self) {
+        if let Some(id) = self.selected_item().map(|item| item.id.clone())
+            && !self.read.remove(&id)
+        {
+            self.read.insert(id);
+        }
+        self.save();
+    }
+
+    pub fn mark_read(&mut self) {
+        if let Some(id) = self.selected_item().map(|item| item.id.clone()) {
+            self.read.insert(id);
+        }
+        self.save();
+    }
+
+    pub fn mark_all_read(&mut self) {
+        self.read.extend(self.items.iter().map(|item| item.id.clone()));
+        self.save();
+    }
+
+    // Only ids still in the window are kept, so the file doesn't grow
+    // forever. Best effort: losing it just means comments show as unread.
+    fn save(&self) {
+        let Some(path) = read_path() else { return };
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        let ids: Vec<&str> = self.items.iter()
+            .filter(|item| self.read.contains(&item.id))
+            .map(|item| item.id.as_str())
+            .collect();
+        let _ = fs::write(path, ids.join("\n"));
+    }
+}
@@ -1,7 +1,173 @@
-use crate::config::Config;
-use crate::jira_api;
+use crate::acli;
+use crate::config::{Backend, Config};
+use crate::demo;
+use crate::jira_api::{self, ApiError, PageProgress, Search};
+use crate::model::Ticket;
+use crate::users;
 use std::error::Error;
+use std::thread;
 
-pub fn fetch_tickets(config: &Config) -> Result<Vec<crate::model::Ticket>, Box<dyn Error>> {
-    jira_api::fetch_tickets_api(config)
-}
\ No newline at end of file
+/// Tickets plus a short note on which backend produced them
+pub struct Fetched {
+    pub tickets: Vec<Ticket>,
+    pub source: String,
+    pub total: Option<usize>,  // Total matches when `tickets` was truncated
+    pub failed: Vec<(String, String)>,  // (query name, error) for queries that came back only in part, or not at all
+}
+
+/// A failed fetch as text, plus the typed API error when there is one, so it
+/// can cross threads
+pub type Failure = (String, Option<ApiError>);
+
+pub fn failure(error: Box<dyn Error>) -> Failure {
+    (error.to_string(), error.downcast_ref::<ApiError>().cloned())
+}
+
+/// Every ticket the configured queries match, failing if any part of the
+/// fetch did
+pub fn fetch_tickets(config: &Config) -> Result<Fetched, Box<dyn Error>> {
+    let fetched = fetch_tickets_paged(config, None)?;
+    match fetched.failed.first() {
+        Some((name, error)) => Err(format!("Query '{}' failed: {}", name, error).into()),
+        None => Ok(fetched),
+    }
+}
+
+/// `fetch_tickets`, reporting tickets page by page while a single-query
+/// board loads from the API. Merged queries and acli report only at the end.
+/// A query that fails for a passing reason, like a timeout, is listed in
+/// `failed` with whatever of it came back, unless every query failed.
+pub fn fetch_tickets_paged(
+    config: &Config,
+    on_page: Option<&mut dyn FnMut(PageProgress)>,
+) -> Result<Fetched, Box<dyn Error>> {
+    let fetched = fetch_queries(config, on_page);
+    // The names of everyone on the board, written once for the whole fetch
+    users::save();
+    fetched
+}
+
+fn fetch_queries(
+    config: &Config,
+    on_page: Option<&mut dyn FnMut(PageProgress)>,
+) -> Result<Fetched, Box<dyn Error>> {
+    if config.query.extra.is_empty() {
+        let mut fetched = fetch_query(config, on_page)?;
+        if let Some((name, _)) = fetched.failed.first_mut() {
+            name.clone_from(&config.query.name);
+        }
+        return Ok(fetched);
+    }
+    
+    // Fetch every configured query concurrently
+    let queries: Vec<(String, String)> = std::iter::once((config.query.name.clone(), config.query.jql.clone()))
+        .chain(config.query.extra.iter().map(|q| (q.name.clone(), q.jql.clone())))
+        .collect();
+    
+    let results: Vec<(String, Result<Fetched, Failure>)> = thread::scope(|scope| {
+        let handles: Vec<_> = queries.into_iter()
+            .map(|(name, jql)| {
+                let mut query_config = config.clone();
+                query_config.query.jql = jql;
+                scope.spawn(move || {
+                    let result = fetch_query(&query_config, None).map_err(failure);
+                    (name, result)
+                })
+            })
+            .collect();
+        handles.into_iter()
+            .map(|h| h.join().unwrap_or_else(|_| (String::new(), Err(("Fetch thread panicked".to_string(), None)))))
+            .collect()
+    });
+    
+    // Merge, keeping the first copy of each ticket and recording every
+    // query that matched it
+    let query_count = results.len();
+    let mut merged = Fetched { tickets: Vec::new(), source: String::new(), total: None, failed: Vec::new() };
+    let mut truncated = false;
+    let mut match_count = 0;
+    for (name, result) in results {
+        let fetched = match result {
+            Ok(fetched) => fetched,
+            Err((_, Some(ApiError::Query(message)))) => {
+                return Err(Box::new(ApiError::Query(format!("query '{}': {}", name, message))));
+            }
+            Err((_, Some(api_error))) => return Err(Box::new(api_error)),
+            Err((message, None)) => {
+                // The other queries can still be shown
+                if merged.failed.len() + 1 == query_count {
+                    return Err(format!("Query '{}' failed: {}", name, message).into());
+                }
+                merged.failed.push((name, message));
+                continue;
+            }
+        };
+        merged.failed.extend(fetched.failed.into_iter().map(|(_, error)| (name.clone(), error)));
+        if merged.source.is_empty() {
+            merged.source = fetched.source;
+        }
+        truncated |= fetched.total.is_some();
+        match_count += fetched.total.unwrap_or(fetched.tickets.len());
+        for mut ticket in fetched.tickets {
+            match merged.tickets.iter_mut().find(|t| t.key == ticket.key) {
+                Some(existing) => existing.queries.push(name.clone()),
+                None => {
+                    ticket.queries.push(name.clone());
+                    merged.tickets.push(ticket);
+                }
+            }
+        }
+    }
+    
+    // Overlapping queries make this an upper bound, which is fine for a warning
+    if truncated {
+        merged.total = Some(match_count);
+    }
+    
+    Ok(merged)
+}
+
+// Fetch a single query with the configured backend
+fn fetch_query(config: &Config, on_page: Option<&mut dyn FnMut(PageProgress)>) -> Result<Fetched, Box<dyn Error>> {
+    match config.jira.backend {
+        Backend::Demo => Ok(Fetched { tickets: demo::tickets(config), source: "demo".to_string(), total: None, failed: Vec::new() }),
+        Backend::Api => Ok(api_fetched(jira_api::fetch_tickets_api_paged(config, on_page)?)),
+        Backend::Acli => Ok(Fetched {
+            tickets: acli::fetch_tickets_acli(config)?,
+            source: "acli".to_string(),
+            total: None,
+            failed: Vec::new(),
+        }),
+        Backend::Auto => {
+            // Without an API token, go straight to an existing acli login
+            if config.jira.api_token.is_none() {
+                return Ok(Fetched {
+                    tickets: acli::fetch_tickets_acli(config)?,
+                    source: "acli".to_string(),
+                    total: None,
+                    failed: Vec::new(),
+                });
+            }
+            
+            match jira_api::fetch_tickets_api_paged(config, on_page) {
+                Ok(search) => Ok(api_fetched(search)),
+                // Report the API error if acli can't help either
+                Err(api_error) => match acli::fetch_tickets_acli(config) {
+                    Ok(tickets) => Ok(Fetched { tickets, source: "acli (API failed)".to_string(), total: None, failed: Vec::new() }),
+                    Err(_) => Err(api_error),
+                },
+            }
+        }
+    }
+}
+
+// A search's tickets, with a search that stopped early as a failed query;
+// the caller knows the query's name
+fn api_fetched(search: Search) -> Fetched {
+    Fetched {
+        tickets: search.tickets,
+        source: "API".to_string(),
+        total: search.total,
+        failed: search.incomplete.map(|error| (String::new(), error)).into_iter().collect(),
+    }
+}
@@ -1,15 +1,50 @@
 use crate::config::Config;
-use crate::model::{Ticket, TicketType, Comment};
+#[cfg(feature = "tui")]
+use crate::checklist;
+#[cfg(feature = "tui")]
+use crate::model::{ChecklistItem, ChecklistSource, Comment, Reaction, RemoteLink};
+use crate::model::{Ticket, TicketType, IssueLink, ParentRef, Sla, Sprint, SprintState, StatusCategory, TimeTracking};
+use crate::rate_limit::SendTracked;
+use crate::users;
 use base64::{Engine as _, engine::general_purpose};
 use reqwest::blocking::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::error::Error;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::{Mutex, OnceLock};
 
 #[derive(Debug, Deserialize)]
 struct JiraResponse {
     issues: Vec<JiraIssue>,
 }
 
+// Just the paging fields of a search response, so pages can be walked
+// without building every issue
+#[derive(Debug, Deserialize)]
+struct JiraPageCursor {
+    #[serde(default)]
+    issues: Vec<serde::de::IgnoredAny>,
+    // Only the legacy /search endpoint reports a total
+    total: Option<usize>,
+    #[serde(rename = "isLast")]
+    is_last: Option<bool>,
+    #[serde(rename = "nextPageToken")]
+    next_page_token: Option<String>,
+}
+
+// Largest page the search endpoint returns when requesting fields
+const PAGE_SIZE: usize = 100;
+
+// Last board per query, with a hash of the response bodies it was built
+// from; an unchanged hash means the board can be reused as is
+type BoardCache = HashMap<String, (u64, Vec<Ticket>, Option<usize>)>;
+
+fn board_cache() -> &'static Mutex<BoardCache> {
+    static CACHE: OnceLock<Mutex<BoardCache>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
 #[derive(Debug, Deserialize)]
 struct JiraIssue {
     key: String,
@@ -22,11 +57,50 @@ struct JiraFields {
     status: JiraStatus,
     issuetype: JiraIssueType,
     assignee: Option<JiraUser>,
+    parent: Option<JiraParent>,
+    #[serde(default)]
+    components: Vec<JiraNamed>,
+    #[serde(rename = "fixVersions", default)]
+    fix_versions: Vec<JiraNamed>,
+    resolution: Option<JiraNamed>,
+    priority: Option<JiraNamed>,
+    timetracking: Option<serde_json::Value>,
+    #[serde(default)]
+    labels: Vec<String>,
+    duedate: Option<String>,
+    // Custom fields such as Flagged, keyed by their configured id
+    #[serde(flatten)]
+    custom: HashMap<String, serde_json::Value>,
+}
+
+// Components and versions only need their name
+#[derive(Debug, Deserialize)]
+struct JiraNamed {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraParent {
+    key: String,
+    fields: Option<JiraParentFields>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraParentFields {
+    summary: Option<String>,
+    issuetype: Option<JiraIssueType>,
 }
 
 #[derive(Debug, Deserialize)]
 struct JiraStatus {
     name: String,
+    #[serde(rename = "statusCategory")]
+    status_category: Option<JiraStatusCategory>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JiraStatusCategory {
+    key: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -36,13 +110,111 @@ struct JiraIssueType {
 
 #[derive(Debug, Deserialize)]
 struct JiraUser {
+    #[serde(rename = "accountId")]
+    account_id: Option<String>,
     #[serde(rename = "displayName")]
     display_name: Option<String>,
     #[serde(rename = "emailAddress")]
     email_address: Option<String>,
 }
 
-pub fn fetch_tickets_api(config: &Config) -> Result<Vec<Ticket>, Box<dyn Error>> {
+impl JiraUser {
+    // Same resolution as `person_name`, for typed search results
+    fn name(self) -> Option<String> {
+        if let Some(ref name) = self.display_name {
+            for key in [&self.account_id, &self.email_address].into_iter().flatten() {
+                users::remember(key, name);
+            }
+            return self.display_name;
+        }
+        let cached = [&self.account_id, &self.email_address].into_iter()
+            .flatten()
+            .find_map(|key| users::display_name(key));
+        cached.or(self.email_address)
+    }
+}
+
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+compile_error!("enable the `native-tls` or `rustls` feature to talk to JIRA over HTTPS");
+
+// HTTP client using whichever TLS stack the build enabled, preferring
+// rustls when both are on
+fn client() -> Client {
+    let builder = Client::builder();
+    #[cfg(feature = "rustls")]
+    let builder = builder.use_rustls_tls();
+    #[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+    let builder = builder.use_native_tls();
+    builder.build().unwrap_or_default()
+}
+
+/// Failures that need the user to change something, so retrying on a
+/// timer won't help
+#[derive(Debug, Clone)]
+pub enum ApiError {
+    /// Credentials rejected, with the HTTP status (401 or 403)
+    Auth(u16),
+    /// JIRA couldn't run the query; the server's explanation
+    Query(String),
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ApiError::Auth(status) => write!(f, "JIRA rejected the credentials ({})", status),
+            ApiError::Query(message) => write!(f, "JQL error: {}", message),
+        }
+    }
+}
+
+impl Error for ApiError {}
+
+// The `errorMessages` (and field `errors`) of a JIRA error body, joined
+fn error_messages(body: &str) -> Option<String> {
+    let json: serde_json::Value = serde_json::from_str(body).ok()?;
+    let mut messages: Vec<String> = json.get("errorMessages")
+        .and_then(|m| m.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|m| m.as_str().map(str::to_string))
+        .collect();
+    if let Some(errors) = json.get("errors").and_then(|e| e.as_object()) {
+        messages.extend(errors.values().filter_map(|m| m.as_str().map(str::to_string)));
+    }
+    (!messages.is_empty()).then(|| messages.join("; "))
+}
+
+/// Tickets that have arrived while a search is still paging
+pub struct PageProgress {
+    pub tickets: Vec<Ticket>,
+    pub pages_done: usize,
+    pub pages_total: Option<usize>,
+}
+
+/// A board search's tickets
+pub struct Search {
+    pub tickets: Vec<Ticket>,
+    pub total: Option<usize>,  // Total matches when `tickets` was truncated
+    pub incomplete: Option<String>,  // Why the pages after `tickets` are missing
+}
+
+/// Board tickets, plus the total match count when results were truncated.
+/// A page failing fails the whole search.
+pub fn fetch_tickets_api(config: &Config) -> Result<(Vec<Ticket>, Option<usize>), Box<dyn Error>> {
+    let search = fetch_tickets_api_paged(config, None)?;
+    match search.incomplete {
+        Some(error) => Err(error.into()),
+        None => Ok((search.tickets, search.total)),
+    }
+}
+
+/// `fetch_tickets_api`, reporting the tickets so far after each page but
+/// the last so a board can show them before the search finishes. When a
+/// page after the first fails, the pages before it are kept.
+pub fn fetch_tickets_api_paged(
+    config: &Config,
+    mut on_page: Option<&mut dyn FnMut(PageProgress)>,
+) -> Result<Search, Box<dyn Error>> {
     let url = config.jira.url.as_ref()
         .ok_or("JIRA URL not configured. Set JIRA_URL or JIRA_SITE environment variable")?;
     let email = config.jira.email.as_ref()
@@ -50,7 +222,7 @@ pub fn fetch_tickets_api(config: &Config) -> Result<Vec<Ticket>, Box<dyn Error>>
     let token = config.jira.api_token.as_ref()
         .ok_or("JIRA API token not configured. Set JIRA_API_TOKEN environment variable")?;
     
-    let client = Client::new();
+    let client = client();
     
     // Create basic auth header
     let auth = format!("{}:{}", email, token);
@@ -59,58 +231,326 @@ pub fn fetch_tickets_api(config: &Config) -> Result<Vec<Ticket>, Box<dyn Error>>
     // Use the new v3 JQL search endpoint
     let api_url = format!("{}/rest/api/3/search/jql", url.trim_end_matches('/'));
     
-    let response = client
-        .get(&api_url)
-        .header("Authorization", format!("Basic {}", encoded))
-        .header("Accept", "application/json")
-        .query(&[
-            ("jql", config.query.jql.as_str()),
-            ("maxResults", "100"),
-            ("fields", "key,summary,status,issuetype,assignee"),
-        ])
-        .send()?;
+    let service_desk = service_desk_fields(&client, url, &encoded);
+    let fields = board_fields(config, &service_desk);
     
-    if !response.status().is_success() {
+    // Page through results until we have `max_results` or run out, keeping
+    // the raw bodies until we know whether anything changed
+    let max_results = config.query.max_results;
+    let mut bodies = Vec::new();
+    let mut issue_count = 0;
+    let mut hasher = DefaultHasher::new();
+    let mut next_page_token: Option<String> = None;
+    let mut total = None;
+    let mut more_available = false;
+    let mut streamed = Vec::new();
+    let mut pages_total = None;
+    let mut incomplete = None;
+    
+    let fetch_page = |page_size: usize, page_token: Option<&str>| -> Result<(String, JiraPageCursor), Box<dyn Error>> {
+        let page_size = page_size.to_string();
+        let mut query = vec![
+            ("jql", config.query.jql.as_str()),
+            ("maxResults", page_size.as_str()),
+            ("fields", fields.as_str()),
+        ];
+        if let Some(token) = page_token {
+            query.push(("nextPageToken", token));
+        }
+        
+        let response = client
+            .get(&api_url)
+            .header("Authorization", format!("Basic {}", encoded))
+            .header("Accept", "application/json")
+            .query(&query)
+            .send_tracked()?;
+        
         let status = response.status();
-        let body = response.text().unwrap_or_else(|_| "Could not read response body".to_string());
-        return Err(format!(
-            "JIRA API request failed with status: {}\nResponse: {}",
-            status,
-            body
-        ).into());
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+            return Err(Box::new(ApiError::Auth(status.as_u16())));
+        }
+        if status == reqwest::StatusCode::BAD_REQUEST {
+            let body = response.text().unwrap_or_default();
+            return Err(Box::new(ApiError::Query(error_messages(&body).unwrap_or(body))));
+        }
+        if !status.is_success() {
+            let body = response.text().unwrap_or_else(|_| "Could not read response body".to_string());
+            return Err(format!(
+                "JIRA API request failed with status: {}\nResponse: {}",
+                status,
+                body
+            ).into());
+        }
+        
+        let body = response.text()?;
+        let page = serde_json::from_str(&body)?;
+        Ok((body, page))
+    };
+    
+    loop {
+        let page_size = PAGE_SIZE.min(max_results.saturating_sub(issue_count));
+        let (body, page) = match fetch_page(page_size, next_page_token.as_deref()) {
+            Ok(fetched) => fetched,
+            // Keep the pages already in when a later one fails; errors that
+            // need fixing fail the search regardless
+            Err(e) if !bodies.is_empty() && !e.is::<ApiError>() => {
+                incomplete = Some(e.to_string());
+                break;
+            }
+            Err(e) => return Err(e),
+        };
+        body.hash(&mut hasher);
+        bodies.push(body);
+        total = total.or(page.total);
+        issue_count += page.issues.len();
+        
+        let is_last = page.is_last.unwrap_or(page.next_page_token.is_none());
+        if is_last {
+            break;
+        }
+        if issue_count >= max_results {
+            more_available = true;
+            break;
+        }
+        next_page_token = page.next_page_token;
+        
+        if let Some(ref mut on_page) = on_page {
+            // One count up front gives the page total for the progress note
+            if pages_total.is_none() {
+                total = total.or_else(|| fetch_approximate_count(&client, url, &encoded, &config.query.jql).ok());
+                pages_total = total.map(|t| t.min(max_results).div_ceil(PAGE_SIZE));
+            }
+            let page: JiraResponse = serde_json::from_str(bodies.last().map(String::as_str).unwrap_or_default())?;
+            streamed.extend(page.issues.into_iter().map(|issue| board_ticket(issue, config, &service_desk)));
+            on_page(PageProgress {
+                tickets: streamed.clone(),
+                pages_done: bodies.len(),
+                pages_total,
+            });
+        }
     }
     
-    let jira_response: JiraResponse = response.json()?;
+    let cache_key = format!("{}\n{}\n{}", config.query.jql, fields, max_results);
+    let body_hash = hasher.finish();
+    if incomplete.is_none()
+        && let Ok(cache) = board_cache().lock()
+        && let Some((hash, tickets, total)) = cache.get(&cache_key)
+        && *hash == body_hash
+    {
+        return Ok(Search { tickets: tickets.clone(), total: *total, incomplete });
+    }
+    
+    let mut issues = Vec::new();
+    for body in &bodies {
+        let page: JiraResponse = serde_json::from_str(body)?;
+        issues.extend(page.issues);
+    }
+    
+    // The token-based endpoint has no total, so ask for a count when we
+    // stopped early
+    if more_available && total.is_none() {
+        total = fetch_approximate_count(&client, url, &encoded, &config.query.jql).ok();
+    }
+    let total = total.filter(|t| *t > issues.len());
     
-    let tickets: Vec<Ticket> = jira_response.issues
+    let tickets: Vec<Ticket> = issues
         .into_iter()
-        .map(|issue| {
-            let assignee = issue.fields.assignee
-                .and_then(|u| u.display_name.or(u.email_address))
-                .unwrap_or_else(|| "unassigned".to_string());
-            
-            Ticket {
-                key: issue.key,
-                ticket_type: TicketType::from_str(&issue.fields.issuetype.name),
-                summary: issue.fields.summary,
-                status: issue.fields.status.name,
-                assignee,
-                description: None,
-                priority: None,
-                reporter: None,
-                created: None,
-                updated: None,
-                labels: None,
-                comments: None,
-            }
-        })
+        .map(|issue| board_ticket(issue, config, &service_desk))
         .collect();
     
-    Ok(tickets)
+    if incomplete.is_none() && let Ok(mut cache) = board_cache().lock() {
+        cache.insert(cache_key, (body_hash, tickets.clone(), total));
+    }
+    Ok(Search { tickets, total, incomplete })
+}
+
+// A card's worth of ticket from a search result
+fn board_ticket(issue: JiraIssue, config: &Config, service_desk: &ServiceDeskFields) -> Ticket {
+    let assignee_id = issue.fields.assignee.as_ref().and_then(|u| u.account_id.clone());
+    let assignee = issue.fields.assignee
+        .and_then(|u| u.name())
+        .unwrap_or_else(|| "unassigned".to_string());
+    
+    let parent = issue.fields.parent.map(|p| {
+        let fields = p.fields;
+        ParentRef {
+            key: p.key,
+            summary: fields.as_ref().and_then(|f| f.summary.clone()).unwrap_or_default(),
+            is_epic: fields.as_ref()
+                .and_then(|f| f.issuetype.as_ref())
+                .is_some_and(|t| t.name.eq_ignore_ascii_case("epic")),
+            url_index: None,
+        }
+    });
+    
+    let status_category = issue.fields.status.status_category
+        .and_then(|c| StatusCategory::from_key(&c.key));
+    
+    let priority = issue.fields.priority.map(|p| p.name);
+    let incident = config.incidents.matches(&issue.fields.issuetype.name, priority.as_deref());
+    
+    Ticket {
+        key: issue.key,
+        ticket_type: TicketType::from_name(&issue.fields.issuetype.name),
+        summary: issue.fields.summary,
+        status: issue.fields.status.name,
+        assignee,
+        assignee_id,
+        description: None,
+        priority,
+        reporter: None,
+        created: None,
+        updated: None,
+        labels: Some(issue.fields.labels),
+        due_date: issue.fields.duedate,
+        comments: None,
+        links: None,
+        checklist: Vec::new(),
+        urls: Vec::new(),
+        remote_links: None,
+        parent,
+        components: issue.fields.components.into_iter().map(|c| c.name).collect(),
+        fix_versions: issue.fields.fix_versions.into_iter().map(|v| v.name).collect(),
+        status_category,
+        resolution: issue.fields.resolution.map(|r| r.name),
+        time_tracking: issue.fields.timetracking.as_ref().and_then(parse_time_tracking),
+        story_points: issue.fields.custom.get(&config.fields.story_points).and_then(|v| v.as_f64()),
+        flagged: is_flagged(issue.fields.custom.get(&config.fields.flagged)),
+        incident,
+        watch_count: None,
+        vote_count: None,
+        watchers: None,
+        service_desk: false,
+        reaction_container: None,
+        request_type: service_desk.request_type.as_ref().and_then(|id| parse_request_type(issue.fields.custom.get(id))),
+        slas: service_desk.slas.iter().filter_map(|id| parse_sla(issue.fields.custom.get(id)?)).collect(),
+        sprint: parse_sprint(issue.fields.custom.get(&config.fields.sprint)),
+        queries: Vec::new(),
+    }
+}
+
+// Only the fields cards can show; the detail view fetches the rest
+fn board_fields(config: &Config, service_desk: &ServiceDeskFields) -> String {
+    let mut fields = vec!["summary", "status", "issuetype", "assignee", "priority", "components", "fixVersions", "timetracking", "labels", "duedate"];
+    if config.display.show_epic {
+        fields.push("parent");
+    }
+    fields.push(&config.fields.flagged);
+    fields.push(&config.fields.sprint);
+    fields.push(&config.fields.story_points);
+    fields.extend(service_desk.request_type.iter().chain(&service_desk.slas).map(String::as_str));
+    fields.join(",")
+}
+
+// Ids of the Service Management request type and SLA fields. Unlike the
+// ones in `[fields]` they're recognizable by their field type, so they
+// need no configuring.
+#[derive(Debug, Clone, Default)]
+struct ServiceDeskFields {
+    request_type: Option<String>,
+    slas: Vec<String>,
+}
+
+// Looked up once per site; sites without Service Management have none
+fn service_desk_fields(client: &Client, url: &str, encoded_auth: &str) -> ServiceDeskFields {
+    static FIELDS: OnceLock<Mutex<HashMap<String, ServiceDeskFields>>> = OnceLock::new();
+    let cache = FIELDS.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some(fields) = cache.lock().ok().and_then(|c| c.get(url).cloned()) {
+        return fields;
+    }
+    
+    let all: Vec<serde_json::Value> = client
+        .get(format!("{}/rest/api/3/field", url.trim_end_matches('/')))
+        .header("Authorization", format!("Basic {}", encoded_auth))
+        .header("Accept", "application/json")
+        .send_tracked()
+        .ok()
+        .filter(|response| response.status().is_success())
+        .and_then(|response| response.json().ok())
+        .unwrap_or_default();
+    let of_type = |custom: &str| -> Vec<String> {
+        all.iter()
+            .filter(|f| f.get("schema").and_then(|s| s.get("custom")).and_then(|c| c.as_str()) == Some(custom))
+            .filter_map(|f| f.get("id")?.as_str().map(str::to_string))
+            .collect()
+    };
+    let fields = ServiceDeskFields {
+        request_type: of_type("com.atlassian.servicedesk:vp-origin").into_iter().next(),
+        slas: of_type("com.atlassian.servicedesk:sd-sla-field"),
+    };
+    if let Ok(mut cache) = cache.lock() {
+        cache.insert(url.to_string(), fields.clone());
+    }
+    fields
+}
+
+fn parse_request_type(value: Option<&serde_json::Value>) -> Option<String> {
+    value?.get("requestType")?.get("name")?.as_str().map(str::to_string)
+}
+
+// The running cycle of an SLA field, else how the last one ended
+fn parse_sla(value: &serde_json::Value) -> Option<Sla> {
+    let name = value.get("name")?.as_str()?.to_string();
+    let flag = |cycle: &serde_json::Value, name: &str| cycle.get(name).and_then(|b| b.as_bool()).unwrap_or(false);
+    let remaining = |cycle: &serde_json::Value| cycle.get("remainingTime")
+        .and_then(|r| r.get("friendly"))
+        .and_then(|f| f.as_str())
+        .map(str::to_string);
+    
+    if let Some(cycle) = value.get("ongoingCycle") {
+        return Some(Sla {
+            name,
+            breach_at: cycle.get("breachTime")
+                .and_then(|b| b.get("epochMillis"))
+                .and_then(|m| m.as_i64())
+                .and_then(chrono::DateTime::from_timestamp_millis),
+            remaining: remaining(cycle),
+            paused: flag(cycle, "paused"),
+            breached: flag(cycle, "breached"),
+            done: false,
+        });
+    }
+    let cycle = value.get("completedCycles")?.as_array()?.last()?;
+    Some(Sla {
+        name,
+        breach_at: None,
+        remaining: remaining(cycle),
+        paused: false,
+        breached: flag(cycle, "breached"),
+        done: true,
+    })
+}
+
+// Approximate number of issues matching a JQL query
+fn fetch_approximate_count(
+    client: &Client,
+    url: &str,
+    encoded_auth: &str,
+    jql: &str,
+) -> Result<usize, Box<dyn Error>> {
+    let api_url = format!("{}/rest/api/3/search/approximate-count", url.trim_end_matches('/'));
+    
+    let response = client
+        .post(&api_url)
+        .header("Authorization", format!("Basic {}", encoded_auth))
+        .header("Accept", "application/json")
+        .json(&serde_json::json!({ "jql": jql }))
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(format!("Failed to count issues: {}", response.status()).into());
+    }
+    
+    let json: serde_json::Value = response.json()?;
+    json.get("count")
+        .and_then(|c| c.as_u64())
+        .map(|c| c as usize)
+        .ok_or_else(|| "No count in response".into())
 }
 
 // We use raw JSON parsing for ticket details to handle different JIRA configurations
 
+#[cfg(feature = "tui")]
 pub fn fetch_ticket_details(config: &Config, ticket_key: &str) -> Result<Ticket, Box<dyn Error>> {
     let url = config.jira.url.as_ref()
         .ok_or("JIRA URL not configured")?;
@@ -119,7 +559,7 @@ pub fn fetch_ticket_details(config: &Config, ticket_key: &str) -> Result<Ticket,
     let token = config.jira.api_token.as_ref()
         .ok_or("JIRA API token not configured")?;
     
-    let client = Client::new();
+    let client = client();
     
     // Create basic auth header
     let auth = format!("{}:{}", email, token);
@@ -133,7 +573,7 @@ pub fn fetch_ticket_details(config: &Config, ticket_key: &str) -> Result<Ticket,
         .get(&api_url)
         .header("Authorization", format!("Basic {}", encoded))
         .header("Accept", "application/json")
-        .send()?;
+        .send_tracked()?;
     
     if !response.status().is_success() {
         let status = response.status();
@@ -167,6 +607,19 @@ pub fn fetch_ticket_details(config: &Config, ticket_key: &str) -> Result<Ticket,
         .unwrap_or("Unknown")
         .to_string();
     
+    let status_category = fields.get("status")
+        .and_then(|s| s.get("statusCategory"))
+        .and_then(|c| c.get("key"))
+        .and_then(|k| k.as_str())
+        .and_then(StatusCategory::from_key);
+    
+    let resolution = fields.get("resolution")
+        .and_then(|r| r.get("name"))
+        .and_then(|n| n.as_str())
+        .map(|s| s.to_string());
+    
+    let time_tracking = fields.get("timetracking").and_then(parse_time_tracking);
+    
     let issue_type = fields.get("issuetype")
         .and_then(|t| t.get("name"))
         .and_then(|n| n.as_str())
@@ -174,19 +627,14 @@ pub fn fetch_ticket_details(config: &Config, ticket_key: &str) -> Result<Ticket,
         .to_string();
     
     let assignee = fields.get("assignee")
-        .and_then(|a| {
-            a.get("displayName").and_then(|d| d.as_str())
-                .or_else(|| a.get("emailAddress").and_then(|e| e.as_str()))
-        })
-        .unwrap_or("unassigned")
-        .to_string();
+        .and_then(person_name)
+        .unwrap_or_else(|| "unassigned".to_string());
+    let assignee_id = fields.get("assignee")
+        .and_then(|a| a.get("accountId"))
+        .and_then(|id| id.as_str())
+        .map(str::to_string);
     
-    let reporter = fields.get("reporter")
-        .and_then(|r| {
-            r.get("displayName").and_then(|d| d.as_str())
-                .or_else(|| r.get("emailAddress").and_then(|e| e.as_str()))
-        })
-        .map(|s| s.to_string());
+    let reporter = fields.get("reporter").and_then(person_name);
     
     let priority = fields.get("priority")
         .and_then(|p| p.get("name"))
@@ -201,6 +649,9 @@ pub fn fetch_ticket_details(config: &Config, ticket_key: &str) -> Result<Ticket,
         .and_then(|u| u.as_str())
         .map(|s| s.to_string());
     
+    let components = named_list(fields.get("components"));
+    let fix_versions = named_list(fields.get("fixVersions"));
+    
     let labels = fields.get("labels")
         .and_then(|l| l.as_array())
         .map(|arr| {
@@ -210,72 +661,1306 @@ pub fn fetch_ticket_details(config: &Config, ticket_key: &str) -> Result<Ticket,
                 .collect()
         });
     
-    // Parse description - can be string, null, or ADF object
+    // Link targets found in the description and comments, numbered in order
+    let mut urls = Vec::new();
+    
+    // Parse description - can be string, null, or ADF object. Its action
+    // items show as the checklist rather than in the text. Mentions in it
+    // and the comments are resolved below, all in one lookup; the flag
+    // says whether the text is wiki markup.
     let description = fields.get("description").and_then(|desc| {
         match desc {
-            serde_json::Value::String(s) => Some(s.clone()),
-            serde_json::Value::Object(_) => extract_text_from_adf(desc),
+            serde_json::Value::String(s) => Some((s.clone(), true)),
+            serde_json::Value::Object(_) => extract_text_from_adf(&checklist::without_tasks(desc), &mut urls)
+                .map(|text| (text, false)),
             serde_json::Value::Null => None,
             _ => None,
         }
     });
     
     // Parse comments
-    let comments = fields.get("comment")
+    let comments: Option<Vec<(Comment, bool)>> = fields.get("comment")
         .and_then(|c| c.get("comments"))
         .and_then(|c| c.as_array())
         .map(|arr| {
-            arr.iter().filter_map(|comment| {
+            arr.iter().map(|comment| {
                 let author = comment.get("author")
-                    .and_then(|a| {
-                        a.get("displayName").and_then(|d| d.as_str())
-                            .or_else(|| a.get("emailAddress").and_then(|e| e.as_str()))
-                    })
-                    .unwrap_or("Unknown")
-                    .to_string();
+                    .and_then(person_name)
+                    .unwrap_or_else(|| "Unknown".to_string());
                 
                 let created = comment.get("created")
                     .and_then(|c| c.as_str())
                     .unwrap_or("")
                     .to_string();
                 
+                let wiki = comment.get("body").is_some_and(|b| b.is_string());
                 let body = comment.get("body")
                     .and_then(|b| {
                         if b.is_string() {
                             b.as_str().map(|s| s.to_string())
                         } else {
-                            extract_text_from_adf(b)
+                            extract_text_from_adf(b, &mut urls)
                         }
                     })
                     .unwrap_or_else(|| "".to_string());
                 
-                Some(Comment { author, created, body })
+                let public = comment.get("jsdPublic").and_then(|p| p.as_bool());
+                let id = comment.get("id").and_then(|i| i.as_str()).unwrap_or_default().to_string();
+                
+                (Comment { author, created, body, public, id, ari: None, reactions: Vec::new() }, wiki)
             }).collect()
         });
     
+    let mentioned = description.iter()
+        .map(|(text, _)| text.as_str())
+        .chain(comments.iter().flatten().map(|(comment, _)| comment.body.as_str()))
+        .flat_map(mention_ids);
+    let names = display_names(config, mentioned);
+    let description = description.map(|(text, wiki)| resolve_mentions(&text, wiki, &names));
+    let mut comments: Option<Vec<Comment>> = comments.map(|comments| {
+        comments.into_iter()
+            .map(|(mut comment, wiki)| {
+                comment.body = resolve_mentions(&comment.body, wiki, &names);
+                comment
+            })
+            .collect()
+    });
+    
+    let links = parse_issue_links(fields);
+    
+    let mut checklist_items = fields.get("description").map(checklist::tasks).unwrap_or_default();
+    if let Some(text) = config.fields.checklist.as_ref().and_then(|id| fields.get(id)).and_then(|v| v.as_str()) {
+        checklist_items.extend(checklist::field_items(text));
+    }
+    
+    // Remote links are a separate endpoint; a failure there shouldn't hide
+    // the rest of the ticket
+    let remote_links = fetch_remote_links(&client, url, &encoded, &key, &mut urls).ok();
+    
+    // Parent (epic) with a browse link so it can be opened like other links
+    let parent = fields.get("parent").and_then(|p| {
+        let parent_key = p.get("key")?.as_str()?.to_string();
+        let parent_fields = p.get("fields");
+        let browse_url = format!("{}/browse/{}", url.trim_end_matches('/'), parent_key);
+        Some(ParentRef {
+            summary: parent_fields
+                .and_then(|f| f.get("summary"))
+                .and_then(|s| s.as_str())
+                .unwrap_or("")
+                .to_string(),
+            is_epic: parent_fields
+                .and_then(|f| f.get("issuetype"))
+                .and_then(|t| t.get("name"))
+                .and_then(|n| n.as_str())
+                .is_some_and(|n| n.eq_ignore_ascii_case("epic")),
+            url_index: Some(url_index(&browse_url, &mut urls)),
+            key: parent_key,
+        })
+    });
+    
+    // Reactions live in a separate service that knows comments by ARI, which
+    // needs the site's cloud id; Server/DC sites have neither
+    let issue_id = json.get("id").and_then(|i| i.as_str());
+    let project_id = fields.get("project").and_then(|p| p.get("id")).and_then(|i| i.as_str());
+    let mut reaction_container = None;
+    if let (Some(comments), Some(issue_id), Some(project_id)) = (comments.as_mut(), issue_id, project_id)
+        && !comments.is_empty()
+        && let Some(cloud_id) = cloud_id(&client, url)
+    {
+        let container = format!("ari:cloud:jira:{}:project/{}", cloud_id, project_id);
+        for comment in comments.iter_mut() {
+            comment.ari = Some(format!("ari:cloud:jira:{}:comment/{}/{}", cloud_id, issue_id, comment.id));
+        }
+        // Best effort, like remote links
+        if let Ok(mut reactions) = fetch_reactions(&client, url, &encoded, &container, comments) {
+            for comment in comments.iter_mut() {
+                comment.reactions = comment.ari.as_ref().and_then(|ari| reactions.remove(ari)).unwrap_or_default();
+            }
+        }
+        reaction_container = Some(container);
+    }
+    
+    // Requests carry their request type in a custom field
+    let service_desk_fields = service_desk_fields(&client, url, &encoded);
+    let request_type = service_desk_fields.request_type.as_ref().and_then(|id| parse_request_type(fields.get(id)));
+    let slas = service_desk_fields.slas.iter().filter_map(|id| parse_sla(fields.get(id)?)).collect();
+    let service_desk = request_type.is_some()
+        || fields.as_object().is_some_and(|f| f.values().any(|v| v.get("requestType").is_some()))
+        || comments.iter().flatten().any(|c| c.public.is_some());
+    let incident = config.incidents.matches(&issue_type, priority.as_deref());
+    
     Ok(Ticket {
         key,
-        ticket_type: TicketType::from_str(&issue_type),
+        ticket_type: TicketType::from_name(&issue_type),
         summary,
         status,
         assignee,
+        assignee_id,
         description,
         priority,
         reporter,
         created,
         updated,
         labels,
+        due_date: fields.get("duedate").and_then(|d| d.as_str()).map(str::to_string),
         comments,
+        links,
+        checklist: checklist_items,
+        urls,
+        remote_links,
+        parent,
+        components,
+        fix_versions,
+        status_category,
+        resolution,
+        time_tracking,
+        story_points: fields.get(&config.fields.story_points).and_then(|v| v.as_f64()),
+        flagged: is_flagged(fields.get(&config.fields.flagged)),
+        incident,
+        watch_count: fields.get("watches").and_then(|w| w.get("watchCount")).and_then(|c| c.as_u64()),
+        vote_count: fields.get("votes").and_then(|v| v.get("votes")).and_then(|c| c.as_u64()),
+        watchers: None,
+        service_desk,
+        reaction_container,
+        request_type,
+        slas,
+        sprint: parse_sprint(fields.get(&config.fields.sprint)),
+        queries: Vec::new(),
+    })
+}
+
+// Issue links of an issue's fields; each link has either an inward or an
+// outward issue
+fn parse_issue_links(fields: &serde_json::Value) -> Option<Vec<IssueLink>> {
+    let links = fields.get("issuelinks")?.as_array()?;
+    Some(links.iter().filter_map(|link| {
+        let (relation, other) = if let Some(issue) = link.get("outwardIssue") {
+            (link.get("type").and_then(|t| t.get("outward")), issue)
+        } else {
+            (link.get("type").and_then(|t| t.get("inward")), link.get("inwardIssue")?)
+        };
+        
+        let key = other.get("key")?.as_str()?.to_string();
+        let other_fields = other.get("fields");
+        let summary = other_fields
+            .and_then(|f| f.get("summary"))
+            .and_then(|s| s.as_str())
+            .unwrap_or("")
+            .to_string();
+        let status = other_fields
+            .and_then(|f| f.get("status"))
+            .and_then(|s| s.get("name"))
+            .and_then(|n| n.as_str())
+            .unwrap_or("")
+            .to_string();
+        
+        Some(IssueLink {
+            relation: relation.and_then(|r| r.as_str()).unwrap_or("relates to").to_string(),
+            key,
+            summary,
+            status,
+        })
+    }).collect())
+}
+
+/// Just an issue's links, for following chains of them
+pub fn fetch_issue_links(config: &Config, ticket_key: &str) -> Result<Vec<IssueLink>, Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let response = client()
+        .get(format!("{}/rest/api/3/issue/{}", url, ticket_key))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .query(&[("fields", "issuelinks")])
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(format!("Couldn't load {}: {}", ticket_key, response.status()).into());
+    }
+    
+    let json: serde_json::Value = response.json()?;
+    Ok(json.get("fields").and_then(parse_issue_links).unwrap_or_default())
+}
+
+// Base URL and Basic auth header for REST calls
+fn api_auth(config: &Config) -> Result<(String, String), Box<dyn Error>> {
+    let url = config.jira.url.as_ref()
+        .ok_or("JIRA URL not configured")?;
+    let email = config.jira.email.as_ref()
+        .ok_or("JIRA email not configured")?;
+    let token = config.jira.api_token.as_ref()
+        .ok_or("JIRA API token not configured")?;
+    
+    let auth = format!("{}:{}", email, token);
+    let encoded = general_purpose::STANDARD.encode(auth.as_bytes());
+    Ok((url.trim_end_matches('/').to_string(), format!("Basic {}", encoded)))
+}
+
+// Error for a failed write, with JIRA's explanation
+fn request_failed(action: &str, response: reqwest::blocking::Response) -> Box<dyn Error> {
+    let status = response.status();
+    let body = response.text().unwrap_or_else(|_| "Could not read response body".to_string());
+    format!("{}: {}\nResponse: {}", action, status, body).into()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Account {
+    pub account_id: String,
+    pub name: String,
+}
+
+impl Account {
+    fn from_json(json: &serde_json::Value) -> Option<Self> {
+        let account_id = json.get("accountId")?.as_str()?.to_string();
+        let name = person_name(json).unwrap_or_else(|| account_id.clone());
+        users::remember(&account_id, &name);
+        Some(Account { account_id, name })
+    }
+}
+
+/// The account the credentials belong to
+pub fn fetch_myself(config: &Config) -> Result<Account, Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let response = client()
+        .get(format!("{}/rest/api/3/myself", url))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(format!("JIRA rejected the credentials: {}", response.status()).into());
+    }
+    
+    let json: serde_json::Value = response.json()?;
+    Account::from_json(&json).ok_or_else(|| "JIRA returned no account for these credentials".into())
+}
+
+// Cloud account ids, e.g. "557058:f58131cb-…" or "5b10a2844c20165700ede21g",
+// as opposed to names and emails
+fn looks_like_account_id(query: &str) -> bool {
+    !query.contains(['@', ' '])
+        && (query.contains(':') || (query.len() == 24 && query.chars().all(|c| c.is_ascii_alphanumeric())))
+}
+
+/// Find a user by account id, email or display name, preferring exact
+/// matches. Sites in GDPR strict mode hide emails, so a search by email can
+/// come up empty there; a name several people share is an error listing
+/// their account ids, which work everywhere.
+pub fn find_user(config: &Config, query: &str) -> Result<Account, Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    if looks_like_account_id(query) {
+        let response = client()
+            .get(format!("{}/rest/api/3/user", url))
+            .header("Authorization", &auth)
+            .header("Accept", "application/json")
+            .query(&[("accountId", query)])
+            .send_tracked()?;
+        // Otherwise it may be a name after all, so search for it
+        if response.status().is_success() {
+            let json: serde_json::Value = response.json()?;
+            return Account::from_json(&json).ok_or_else(|| format!("No JIRA user has the account id '{}'", query).into());
+        }
+    }
+    
+    let response = client()
+        .get(format!("{}/rest/api/3/user/search", url))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .query(&[("query", query)])
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed("User search failed", response));
+    }
+    
+    let users: Vec<serde_json::Value> = response.json()?;
+    let matching = |field: &str| -> Vec<&serde_json::Value> {
+        users.iter()
+            .filter(|u| u.get(field).and_then(|v| v.as_str()).is_some_and(|v| v.eq_ignore_ascii_case(query)))
+            .collect()
+    };
+    let mut candidates = matching("emailAddress");
+    if candidates.is_empty() {
+        candidates = matching("displayName");
+    }
+    if candidates.is_empty() {
+        candidates = users.iter().collect();
+    }
+    match candidates.as_slice() {
+        [] if query.contains('@') => Err(format!(
+            "No JIRA user matches '{}'; the site may hide email addresses, so try a display name or account id",
+            query,
+        ).into()),
+        [] => Err(format!("No JIRA user matches '{}'", query).into()),
+        [user] => Account::from_json(user).ok_or_else(|| format!("No JIRA user matches '{}'", query).into()),
+        several => {
+            let people: Vec<String> = several.iter()
+                .filter_map(|u| Account::from_json(u))
+                .map(|account| format!("{} ({})", account.name, account.account_id))
+                .collect();
+            Err(format!("'{}' matches {} people, so give an account id: {}", query, several.len(), people.join(", ")).into())
+        }
+    }
+}
+
+/// Make `account` the ticket's assignee
+pub fn assign(config: &Config, ticket_key: &str, account: &Account) -> Result<(), Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let response = client()
+        .put(format!("{}/rest/api/3/issue/{}/assignee", url, ticket_key))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .json(&serde_json::json!({ "accountId": account.account_id }))
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed(&format!("Could not assign {}", ticket_key), response));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transition {
+    pub id: String,
+    pub name: String,
+    pub to_status: String,
+}
+
+/// Workflow transitions available from the ticket's current status
+pub fn fetch_transitions(config: &Config, ticket_key: &str) -> Result<Vec<Transition>, Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let response = client()
+        .get(format!("{}/rest/api/3/issue/{}/transitions", url, ticket_key))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed(&format!("Could not list transitions for {}", ticket_key), response));
+    }
+    
+    let json: serde_json::Value = response.json()?;
+    let transitions = json.get("transitions").and_then(|t| t.as_array()).cloned().unwrap_or_default();
+    Ok(transitions.iter()
+        .filter_map(|t| Some(Transition {
+            id: t.get("id")?.as_str()?.to_string(),
+            name: t.get("name")?.as_str()?.to_string(),
+            to_status: t.get("to")?.get("name")?.as_str()?.to_string(),
+        }))
+        .collect())
+}
+
+pub fn transition_issue(config: &Config, ticket_key: &str, transition: &Transition) -> Result<(), Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let response = client()
+        .post(format!("{}/rest/api/3/issue/{}/transitions", url, ticket_key))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .json(&serde_json::json!({ "transition": { "id": transition.id } }))
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed(&format!("Could not move {}", ticket_key), response));
+    }
+    Ok(())
+}
+
+/// Post a comment, `body` being an ADF document. On Service Management
+/// requests, `internal` keeps it from the customer.
+#[cfg(feature = "tui")]
+pub fn add_comment(config: &Config, ticket_key: &str, body: &serde_json::Value, internal: bool) -> Result<(), Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let mut comment = serde_json::json!({ "body": body });
+    if internal {
+        comment["properties"] = serde_json::json!([{ "key": "sd.public.comment", "value": { "internal": true } }]);
+    }
+    let response = client()
+        .post(format!("{}/rest/api/3/issue/{}/comment", url, ticket_key))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .json(&comment)
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed(&format!("Could not comment on {}", ticket_key), response));
+    }
+    Ok(())
+}
+
+/// A comment on a watched ticket, for the inbox
+#[derive(Debug, Clone)]
+pub struct WatchedComment {
+    pub id: String,
+    pub key: String,
+    pub summary: String,
+    pub author: String,
+    pub created: String,
+    pub body: String,
+    pub mentions_me: bool,
+}
+
+/// Other people's comments on tickets `me` watches that were updated in
+/// the last `days` days, newest first
+#[cfg(feature = "tui")]
+pub fn fetch_watched_comments(config: &Config, me: &Account, days: u32) -> Result<Vec<WatchedComment>, Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let jql = format!("watcher = currentUser() AND updated >= -{}d ORDER BY updated DESC", days);
+    let response = client()
+        .get(format!("{}/rest/api/3/search/jql", url))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .query(&[("jql", jql.as_str()), ("maxResults", "50"), ("fields", "summary,comment")])
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed("Could not load watched tickets", response));
+    }
+    
+    let json: serde_json::Value = response.json()?;
+    // With their text unresolved until every mention is looked up at once
+    let mut comments = Vec::new();
+    for issue in json.get("issues").and_then(|i| i.as_array()).into_iter().flatten() {
+        let key = issue.get("key").and_then(|k| k.as_str()).unwrap_or_default();
+        let fields = issue.get("fields");
+        let summary = fields.and_then(|f| f.get("summary")).and_then(|s| s.as_str()).unwrap_or_default();
+        let issue_comments = fields
+            .and_then(|f| f.get("comment"))
+            .and_then(|c| c.get("comments"))
+            .and_then(|c| c.as_array());
+        
+        for comment in issue_comments.into_iter().flatten() {
+            let author = comment.get("author");
+            if author.and_then(|a| a.get("accountId")).and_then(|id| id.as_str()) == Some(me.account_id.as_str()) {
+                continue;
+            }
+            let body = comment.get("body");
+            let text = match body {
+                Some(serde_json::Value::String(s)) => s.clone(),
+                Some(adf) => extract_text_from_adf(adf, &mut Vec::new()).unwrap_or_default(),
+                None => String::new(),
+            };
+            let wiki = body.is_some_and(|b| b.is_string());
+            comments.push((WatchedComment {
+                id: comment.get("id").and_then(|id| id.as_str()).unwrap_or_default().to_string(),
+                key: key.to_string(),
+                summary: summary.to_string(),
+                author: author.and_then(person_name).unwrap_or_else(|| "Unknown".to_string()),
+                created: comment.get("created").and_then(|c| c.as_str()).unwrap_or_default().to_string(),
+                mentions_me: body.is_some_and(|b| mentions(b, &me.account_id)),
+                body: text,
+            }, wiki));
+        }
+    }
+    
+    let names = display_names(config, comments.iter().flat_map(|(comment, _)| mention_ids(&comment.body)));
+    let mut comments: Vec<WatchedComment> = comments.into_iter()
+        .map(|(mut comment, wiki)| {
+            comment.body = resolve_mentions(&comment.body, wiki, &names);
+            comment.mentions_me |= comment.body.contains(&format!("@{}", me.name));
+            comment
+        })
+        .collect();
+    comments.sort_by(|a, b| b.created.cmp(&a.created));
+    Ok(comments)
+}
+
+// Whether an ADF tree has a mention node for the account
+#[cfg(feature = "tui")]
+fn mentions(node: &serde_json::Value, account_id: &str) -> bool {
+    if node.get("type").and_then(|t| t.as_str()) == Some("mention")
+        && node.get("attrs").and_then(|a| a.get("id")).and_then(|id| id.as_str()) == Some(account_id)
+    {
+        return true;
+    }
+    node.get("content")
+        .and_then(|c| c.as_array())
+        .is_some_and(|children| children.iter().any(|child| mentions(child, account_id)))
+}
+
+/// Time someone logged on a ticket
+#[derive(Debug, Clone)]
+pub struct Worklog {
+    pub key: String,
+    pub summary: String,
+    pub started: chrono::DateTime<chrono::Local>,
+    pub seconds: u64,
+}
+
+// Worklog times look like "2026-10-13T09:00:00.000+0000"
+#[cfg(feature = "tui")]
+const WORKLOG_TIME: &str = "%Y-%m-%dT%H:%M:%S%.3f%z";
+
+/// Time `me` logged from the start of `from` to the end of `to`, oldest
+/// first
+#[cfg(feature = "tui")]
+pub fn fetch_worklogs(config: &Config, me: &Account, from: chrono::NaiveDate, to: chrono::NaiveDate) -> Result<Vec<Worklog>, Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let jql = format!(
+        "worklogAuthor = currentUser() AND worklogDate >= \"{}\" AND worklogDate <= \"{}\"",
+        from.format("%Y-%m-%d"),
+        to.format("%Y-%m-%d"),
+    );
+    let response = client()
+        .get(format!("{}/rest/api/3/search/jql", url))
+        .header("Authorization", &auth)
+        .header("Accept", "application/json")
+        .query(&[("jql", jql.as_str()), ("maxResults", "100"), ("fields", "summary")])
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed("Could not load worklogs", response));
+    }
+    
+    // The search only says which tickets; their worklogs come one ticket at
+    // a time, narrowed to the range
+    let local_millis = |date: chrono::NaiveDate| {
+        date.and_time(chrono::NaiveTime::MIN)
+            .and_local_timezone(chrono::Local)
+            .earliest()
+            .map_or(0, |t| t.timestamp_millis())
+    };
+    let started_after = local_millis(from).to_string();
+    let started_before = local_millis(to + chrono::Days::new(1)).to_string();
+    
+    let json: serde_json::Value = response.json()?;
+    let mut worklogs = Vec::new();
+    for issue in json.get("issues").and_then(|i| i.as_array()).into_iter().flatten() {
+        let key = issue.get("key").and_then(|k| k.as_str()).unwrap_or_default();
+        let summary = issue.get("fields").and_then(|f| f.get("summary")).and_then(|s| s.as_str()).unwrap_or_default();
+        let response = client()
+            .get(format!("{}/rest/api/3/issue/{}/worklog", url, key))
+            .header("Authorization", &auth)
+            .header("Accept", "application/json")
+            .query(&[("startedAfter", started_after.as_str()), ("startedBefore", started_before.as_str()), ("maxResults", "1000")])
+            .send_tracked()?;
+        if !response.status().is_success() {
+            return Err(request_failed(&format!("Could not load worklogs of {}", key), response));
+        }
+        
+        let json: serde_json::Value = response.json()?;
+        for worklog in json.get("worklogs").and_then(|w| w.as_array()).into_iter().flatten() {
+            let author = worklog.get("author").and_then(|a| a.get("accountId")).and_then(|id| id.as_str());
+            let started = worklog.get("started")
+                .and_then(|s| s.as_str())
+                .and_then(|s| chrono::DateTime::parse_from_str(s, WORKLOG_TIME).ok())
+                .map(|t| t.with_timezone(&chrono::Local));
+            let seconds = worklog.get("timeSpentSeconds").and_then(|s| s.as_u64());
+            if author == Some(me.account_id.as_str())
+                && let (Some(started), Some(seconds)) = (started, seconds)
+                && (from..=to).contains(&started.date_naive())
+            {
+                worklogs.push(Worklog { key: key.to_string(), summary: summary.to_string(), started, seconds });
+            }
+        }
+    }
+    worklogs.sort_by_key(|w| w.started);
+    Ok(worklogs)
+}
+
+/// When each of `keys` entered its current status: its latest status change
+/// in the changelog, or its creation if it never moved
+#[cfg(feature = "tui")]
+pub fn fetch_status_since(config: &Config, keys: &[String]) -> Result<HashMap<String, chrono::DateTime<chrono::Local>>, Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let mut since = HashMap::new();
+    // Keys go in the JQL, so a long list is split to keep the URL short
+    for chunk in keys.chunks(50) {
+        let jql = format!("key in ({})", chunk.join(","));
+        let response = client()
+            .get(format!("{}/rest/api/3/search/jql", url))
+            .header("Authorization", &auth)
+            .header("Accept", "application/json")
+            .query(&[("jql", jql.as_str()), ("maxResults", "50"), ("fields", "created"), ("expand", "changelog")])
+            .send_tracked()?;
+        
+        if !response.status().is_success() {
+            return Err(request_failed("Could not load ticket history", response));
+        }
+        
+        let json: serde_json::Value = response.json()?;
+        let time = |value: Option<&serde_json::Value>| {
+            value.and_then(|v| v.as_str())
+                .and_then(|s| chrono::DateTime::parse_from_str(s, WORKLOG_TIME).ok())
+                .map(|t| t.with_timezone(&chrono::Local))
+        };
+        for issue in json.get("issues").and_then(|i| i.as_array()).into_iter().flatten() {
+            let Some(key) = issue.get("key").and_then(|k| k.as_str()) else { continue };
+            let moved = issue.get("changelog")
+                .and_then(|c| c.get("histories"))
+                .and_then(|h| h.as_array())
+                .into_iter()
+                .flatten()
+                .filter(|history| {
+                    history.get("items").and_then(|i| i.as_array()).into_iter().flatten()
+                        .any(|item| item.get("field").and_then(|f| f.as_str()) == Some("status"))
+                })
+                .filter_map(|history| time(history.get("created")))
+                .max();
+            if let Some(entered) = moved.or_else(|| time(issue.get("fields").and_then(|f| f.get("created")))) {
+                since.insert(key.to_string(), entered);
+            }
+        }
+    }
+    Ok(since)
+}
+
+/// Log time on a ticket, started at `started`
+#[cfg(feature = "tui")]
+pub fn add_worklog(config: &Config, ticket_key: &str, seconds: u64, started: chrono::DateTime<chrono::Local>) -> Result<(), Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let response = client()
+        .post(format!("{}/rest/api/3/issue/{}/worklog", url, ticket_key))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .json(&serde_json::json!({
+            "timeSpentSeconds": seconds,
+            "started": started.format(WORKLOG_TIME).to_string(),
+        }))
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed(&format!("Could not log time on {}", ticket_key), response));
+    }
+    Ok(())
+}
+
+/// A release in a project's versions list
+#[derive(Debug, Clone)]
+pub struct ProjectVersion {
+    pub name: String,
+    pub released: bool,
+    pub overdue: bool,
+    pub release_date: Option<chrono::NaiveDate>,
+}
+
+/// A project's versions, leaving out archived ones
+#[cfg(feature = "tui")]
+pub fn fetch_versions(config: &Config, project: &str) -> Result<Vec<ProjectVersion>, Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let response = client()
+        .get(format!("{}/rest/api/3/project/{}/versions", url, project))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed(&format!("Could not load versions of {}", project), response));
+    }
+    
+    let versions: Vec<serde_json::Value> = response.json()?;
+    Ok(versions.iter()
+        .filter(|v| !v.get("archived").and_then(|a| a.as_bool()).unwrap_or(false))
+        .filter_map(|v| Some(ProjectVersion {
+            name: v.get("name")?.as_str()?.to_string(),
+            released: v.get("released").and_then(|r| r.as_bool()).unwrap_or(false),
+            overdue: v.get("overdue").and_then(|o| o.as_bool()).unwrap_or(false),
+            release_date: v.get("releaseDate")
+                .and_then(|d| d.as_str())
+                .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok()),
+        }))
+        .collect())
+}
+
+/// Names of the issue types a project offers
+pub fn fetch_issue_types(config: &Config, project: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let response = client()
+        .get(format!("{}/rest/api/3/project/{}", url, project))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed(&format!("Could not load project {}", project), response));
+    }
+    
+    let json: serde_json::Value = response.json()?;
+    Ok(named_list(json.get("issueTypes")))
+}
+
+/// Names of the priorities, highest first
+pub fn fetch_priorities(config: &Config) -> Result<Vec<String>, Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let response = client()
+        .get(format!("{}/rest/api/3/priority", url))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed("Could not load priorities", response));
+    }
+    
+    let json: serde_json::Value = response.json()?;
+    Ok(named_list(Some(&json)))
+}
+
+/// Which of `permissions` (JIRA's keys, e.g. "ADD_COMMENTS") you have in a
+/// project
+pub fn fetch_my_permissions(config: &Config, project: &str, permissions: &[&str]) -> Result<Vec<String>, Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let response = client()
+        .get(format!("{}/rest/api/3/mypermissions", url))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .query(&[("projectKey", project), ("permissions", &permissions.join(","))])
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed(&format!("Could not check your permissions in {}", project), response));
+    }
+    
+    let json: serde_json::Value = response.json()?;
+    Ok(permissions.iter()
+        .filter(|key| {
+            json.get("permissions")
+                .and_then(|p| p.get(**key))
+                .and_then(|p| p.get("havePermission"))
+                .and_then(|h| h.as_bool())
+                .unwrap_or(false)
+        })
+        .map(|key| key.to_string())
+        .collect())
+}
+
+/// People who can be assigned tickets in a project
+pub fn fetch_assignable_users(config: &Config, project: &str) -> Result<Vec<Account>, Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let response = client()
+        .get(format!("{}/rest/api/3/user/assignable/search", url))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .query(&[("project", project), ("maxResults", "1000")])
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed(&format!("Could not list the people in {}", project), response));
+    }
+    
+    let users: Vec<serde_json::Value> = response.json()?;
+    Ok(users.iter().filter_map(Account::from_json).collect())
+}
+
+/// Replace the ticket's summary
+#[cfg(feature = "tui")]
+pub fn update_summary(config: &Config, ticket_key: &str, summary: &str) -> Result<(), Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let response = client()
+        .put(format!("{}/rest/api/3/issue/{}", url, ticket_key))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .json(&serde_json::json!({ "fields": { "summary": summary } }))
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed(&format!("Could not edit {}", ticket_key), response));
+    }
+    Ok(())
+}
+
+/// Tick or untick a checklist item where it lives: an action item in the
+/// description, or a line of the checklist field. Both are read fresh, so
+/// edits made meanwhile in the browser aren't written over.
+#[cfg(feature = "tui")]
+pub fn set_checklist_item(config: &Config, ticket_key: &str, item: &ChecklistItem, done: bool) -> Result<(), Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let field = match item.source {
+        ChecklistSource::Task(_) => "description",
+        ChecklistSource::Field(_) => config.fields.checklist.as_deref().ok_or("No checklist field configured")?,
+    };
+    let response = client()
+        .get(format!("{}/rest/api/3/issue/{}", url, ticket_key))
+        .header("Authorization", &auth)
+        .header("Accept", "application/json")
+        .query(&[("fields", field)])
+        .send_tracked()?;
+    if !response.status().is_success() {
+        return Err(request_failed(&format!("Could not load {}", ticket_key), response));
+    }
+    let json: serde_json::Value = response.json()?;
+    let mut value = json.get("fields").and_then(|f| f.get(field)).cloned().unwrap_or_default();
+    
+    let value = match &item.source {
+        ChecklistSource::Task(id) => {
+            if !checklist::set_task(&mut value, id, done) {
+                return Err("The item is no longer in the description".into());
+            }
+            value
+        }
+        ChecklistSource::Field(line) => {
+            let text = value.as_str().ok_or("The checklist field isn't plain text")?;
+            let text = checklist::set_field_item(text, *line, &item.text, done)
+                .ok_or("The checklist changed since it was loaded")?;
+            serde_json::Value::String(text)
+        }
+    };
+    
+    let response = client()
+        .put(format!("{}/rest/api/3/issue/{}", url, ticket_key))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .json(&serde_json::json!({ "fields": { field: value } }))
+        .send_tracked()?;
+    if !response.status().is_success() {
+        return Err(request_failed(&format!("Could not update the checklist of {}", ticket_key), response));
+    }
+    Ok(())
+}
+
+/// Fields for a ticket to create
+#[derive(Debug, Clone, Default)]
+pub struct NewIssue {
+    pub project: String,
+    pub issue_type: String,
+    pub summary: String,
+    pub description: Option<String>,
+    pub labels: Vec<String>,
+    pub components: Vec<String>,
+}
+
+/// Create a ticket, returning its key
+pub fn create_issue(config: &Config, issue: &NewIssue) -> Result<String, Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let mut fields = serde_json::json!({
+        "project": { "key": issue.project },
+        "issuetype": { "name": issue.issue_type },
+        "summary": issue.summary,
+    });
+    if let Some(description) = issue.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        fields["description"] = adf_document(description);
+    }
+    if !issue.labels.is_empty() {
+        fields["labels"] = serde_json::json!(issue.labels);
+    }
+    if !issue.components.is_empty() {
+        fields["components"] = issue.components.iter()
+            .map(|name| serde_json::json!({ "name": name }))
+            .collect();
+    }
+    
+    let response = client()
+        .post(format!("{}/rest/api/3/issue", url))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .json(&serde_json::json!({ "fields": fields }))
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed("Could not create ticket", response));
+    }
+    
+    let json: serde_json::Value = response.json()?;
+    json.get("key").and_then(|k| k.as_str()).map(str::to_string)
+        .ok_or_else(|| "JIRA created the ticket but returned no key".into())
+}
+
+/// Plain text as an ADF document: blank lines separate paragraphs and
+/// single line breaks are kept
+pub fn adf_document(text: &str) -> serde_json::Value {
+    let paragraphs: Vec<serde_json::Value> = text.split("\n\n")
+        .map(|p| p.trim_matches('\n'))
+        .filter(|p| !p.trim().is_empty())
+        .map(|p| {
+            let mut content = Vec::new();
+            for (i, line) in p.lines().enumerate() {
+                if i > 0 {
+                    content.push(serde_json::json!({ "type": "hardBreak" }));
+                }
+                if !line.is_empty() {
+                    content.push(serde_json::json!({ "type": "text", "text": line }));
+                }
+            }
+            serde_json::json!({ "type": "paragraph", "content": content })
+        })
+        .collect();
+    serde_json::json!({ "type": "doc", "version": 1, "content": paragraphs })
+}
+
+#[cfg(feature = "tui")]
+pub fn fetch_watchers(config: &Config, ticket_key: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let api_url = format!("{}/rest/api/3/issue/{}/watchers", url, ticket_key);
+    
+    let response = client()
+        .get(&api_url)
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(format!("Failed to fetch watchers: {}", response.status()).into());
+    }
+    
+    let json: serde_json::Value = response.json()?;
+    let watchers = json.get("watchers")
+        .and_then(|w| w.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(person_name)
+                .collect()
+        })
+        .unwrap_or_default();
+    
+    Ok(watchers)
+}
+
+// A ticket can carry over through several sprints; prefer the active one,
+// then a future one, then the most recent closed one
+fn parse_sprint(value: Option<&serde_json::Value>) -> Option<Sprint> {
+    let sprints: Vec<Sprint> = value?
+        .as_array()?
+        .iter()
+        .filter_map(|sprint| match sprint {
+            serde_json::Value::Object(_) => Some(Sprint {
+                name: sprint.get("name")?.as_str()?.to_string(),
+                state: SprintState::from_name(sprint.get("state").and_then(|s| s.as_str()).unwrap_or("")),
+            }),
+            // Older Server versions serialize sprints as "...[id=1,state=ACTIVE,name=Sprint 4,...]"
+            serde_json::Value::String(s) => {
+                let attr = |name: &str| {
+                    let start = s.find(&format!("{}=", name))? + name.len() + 1;
+                    let end = s[start..].find([',', ']']).map_or(s.len(), |e| start + e);
+                    Some(s[start..end].to_string())
+                };
+                Some(Sprint {
+                    name: attr("name")?,
+                    state: SprintState::from_name(&attr("state").unwrap_or_default()),
+                })
+            }
+            _ => None,
+        })
+        .collect();
+    
+    [SprintState::Active, SprintState::Future]
+        .iter()
+        .find_map(|state| sprints.iter().find(|s| s.state == *state).cloned())
+        .or_else(|| sprints.last().cloned())
+}
+
+// The Flagged field is an array of options (`[{"value": "Impediment"}]`)
+// when set and null otherwise
+fn is_flagged(value: Option<&serde_json::Value>) -> bool {
+    value
+        .and_then(|v| v.as_array())
+        .is_some_and(|arr| !arr.is_empty())
+}
+
+#[cfg(feature = "tui")]
+pub fn set_flagged(config: &Config, ticket_key: &str, flagged: bool) -> Result<(), Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let api_url = format!("{}/rest/api/3/issue/{}", url, ticket_key);
+    
+    let value = if flagged {
+        serde_json::json!([{ "value": "Impediment" }])
+    } else {
+        serde_json::json!(null)
+    };
+    let body = serde_json::json!({ "fields": { config.fields.flagged.as_str(): value } });
+    
+    let response = client()
+        .put(&api_url)
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .json(&body)
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed("Failed to update flag", response));
+    }
+    
+    Ok(())
+}
+
+// Time tracking is `{}` when nothing is estimated or logged
+fn parse_time_tracking(value: &serde_json::Value) -> Option<TimeTracking> {
+    let text = |name: &str| value.get(name).and_then(|v| v.as_str()).map(|s| s.to_string());
+    let seconds = |name: &str| value.get(name).and_then(|v| v.as_u64());
+    
+    let tracking = TimeTracking {
+        original_estimate: text("originalEstimate"),
+        remaining_estimate: text("remainingEstimate"),
+        time_spent: text("timeSpent"),
+        original_estimate_seconds: seconds("originalEstimateSeconds"),
+        remaining_estimate_seconds: seconds("remainingEstimateSeconds"),
+        time_spent_seconds: seconds("timeSpentSeconds"),
+    };
+    
+    if tracking.is_empty() { None } else { Some(tracking) }
+}
+
+// Names from an array of `{ "name": ... }` objects (components, versions)
+fn named_list(value: Option<&serde_json::Value>) -> Vec<String> {
+    value
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|item| item.get("name").and_then(|n| n.as_str()))
+                .map(|s| s.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// The site's cloud id, looked up once per site. None on Server/DC, which
+// has no such thing.
+#[cfg(feature = "tui")]
+fn cloud_id(client: &Client, url: &str) -> Option<String> {
+    static CLOUD_IDS: OnceLock<Mutex<HashMap<String, Option<String>>>> = OnceLock::new();
+    let cache = CLOUD_IDS.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some(id) = cache.lock().ok()?.get(url) {
+        return id.clone();
+    }
+    
+    let id = client
+        .get(format!("{}/_edge/tenant_info", url.trim_end_matches('/')))
+        .send_tracked()
+        .ok()
+        .filter(|response| response.status().is_success())
+        .and_then(|response| response.json::<serde_json::Value>().ok())
+        .and_then(|json| json.get("cloudId")?.as_str().map(str::to_string));
+    cache.lock().ok()?.insert(url.to_string(), id.clone());
+    id
+}
+
+// Emoji ids are code points in hex, joined by dashes ("1f44d" is 👍);
+// Atlassian's own emoji have names instead, shown as `:name:`
+#[cfg(feature = "tui")]
+fn emoji_from_id(id: &str) -> String {
+    let chars: Option<String> = id.split('-')
+        .map(|part| u32::from_str_radix(part, 16).ok().and_then(char::from_u32))
+        .collect();
+    chars.unwrap_or_else(|| format!(":{}:", id.trim_start_matches("atlassian-")))
+}
+
+// Reactions on `comments`, keyed by comment ARI
+#[cfg(feature = "tui")]
+fn fetch_reactions(
+    client: &Client,
+    url: &str,
+    encoded_auth: &str,
+    container: &str,
+    comments: &[Comment],
+) -> Result<HashMap<String, Vec<Reaction>>, Box<dyn Error>> {
+    let aris: Vec<&str> = comments.iter().filter_map(|c| c.ari.as_deref()).collect();
+    let response = client
+        .get(format!("{}/rest/reactions/1.0/reactions/view", url.trim_end_matches('/')))
+        .header("Authorization", format!("Basic {}", encoded_auth))
+        .header("Accept", "application/json")
+        .query(&[("containerAri", container), ("aris", &aris.join(","))])
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(format!("Failed to fetch reactions: {}", response.status()).into());
+    }
+    
+    let json: serde_json::Value = response.json()?;
+    let mut reactions: HashMap<String, Vec<Reaction>> = HashMap::new();
+    for reaction in json.as_array().into_iter().flatten() {
+        let (Some(ari), Some(emoji_id)) = (
+            reaction.get("ari").and_then(|a| a.as_str()),
+            reaction.get("emojiId").and_then(|e| e.as_str()),
+        ) else {
+            continue;
+        };
+        let count = reaction.get("count").and_then(|c| c.as_u64()).unwrap_or(0);
+        if count > 0 {
+            reactions.entry(ari.to_string()).or_default().push(Reaction {
+                emoji: emoji_from_id(emoji_id),
+                count,
+                mine: reaction.get("reacted").and_then(|r| r.as_bool()).unwrap_or(false),
+            });
+        }
+    }
+    Ok(reactions)
+}
+
+// Emoji id of 👍 to the reactions service
+#[cfg(feature = "tui")]
+const THUMBS_UP: &str = "1f44d";
+
+/// React to a comment with 👍
+#[cfg(feature = "tui")]
+pub fn add_thumbs_up(config: &Config, container: &str, comment_ari: &str) -> Result<(), Box<dyn Error>> {
+    let (url, auth) = api_auth(config)?;
+    let response = client()
+        .post(format!("{}/rest/reactions/1.0/reactions", url))
+        .header("Authorization", auth)
+        .header("Accept", "application/json")
+        .json(&serde_json::json!({ "containerAri": container, "ari": comment_ari, "emojiId": THUMBS_UP }))
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(request_failed("Could not add the reaction", response));
+    }
+    Ok(())
+}
+
+// Fetch an issue's remote links, numbering their targets alongside `urls`
+#[cfg(feature = "tui")]
+fn fetch_remote_links(
+    client: &Client,
+    url: &str,
+    encoded_auth: &str,
+    ticket_key: &str,
+    urls: &mut Vec<String>,
+) -> Result<Vec<RemoteLink>, Box<dyn Error>> {
+    let api_url = format!("{}/rest/api/3/issue/{}/remotelink",
+        url.trim_end_matches('/'), ticket_key);
+    
+    let response = client
+        .get(&api_url)
+        .header("Authorization", format!("Basic {}", encoded_auth))
+        .header("Accept", "application/json")
+        .send_tracked()?;
+    
+    if !response.status().is_success() {
+        return Err(format!("Failed to fetch remote links: {}", response.status()).into());
+    }
+    
+    let json: serde_json::Value = response.json()?;
+    let links = json.as_array()
+        .map(|arr| {
+            arr.iter().filter_map(|link| {
+                let object = link.get("object")?;
+                let link_url = object.get("url")?.as_str()?.to_string();
+                let title = object.get("title")
+                    .and_then(|t| t.as_str())
+                    .unwrap_or(&link_url)
+                    .to_string();
+                
+                let app_type = link.get("application")
+                    .and_then(|a| a.get("type"))
+                    .and_then(|t| t.as_str())
+                    .unwrap_or("");
+                let app_name = link.get("application")
+                    .and_then(|a| a.get("name"))
+                    .and_then(|n| n.as_str());
+                let source = if app_type.contains("confluence") || link_url.contains("/wiki/") {
+                    "Confluence".to_string()
+                } else if link_url.contains("slack.com") {
+                    "Slack".to_string()
+                } else {
+                    app_name.unwrap_or("Web").to_string()
+                };
+                
+                Some(RemoteLink { source, title, url_index: url_index(&link_url, urls) })
+            }).collect()
+        })
+        .unwrap_or_default();
+    
+    Ok(links)
+}
+
+// Display name for a user object, recording it in the user directory.
+// Falls back to a cached name when the instance hides display names, and
+// to the raw email as a last resort.
+fn person_name(user: &serde_json::Value) -> Option<String> {
+    let account_id = user.get("accountId").and_then(|a| a.as_str());
+    let email = user.get("emailAddress").and_then(|e| e.as_str());
+    
+    if let Some(name) = user.get("displayName").and_then(|d| d.as_str()) {
+        for key in [account_id, email].into_iter().flatten() {
+            users::remember(key, name);
+        }
+        return Some(name.to_string());
+    }
+    
+    [account_id, email].into_iter()
+        .flatten()
+        .find_map(users::display_name)
+        .or_else(|| email.map(|e| e.to_string()))
+}
+
+// Wiki mentions, as text extracted from ADF has them too
+const MENTION: &str = "[~accountid:";
+
+// Account ids mentioned in `text`
+#[cfg(feature = "tui")]
+fn mention_ids(text: &str) -> impl Iterator<Item = &str> {
+    text.match_indices(MENTION).filter_map(|(start, _)| {
+        let id = &text[start + MENTION.len()..];
+        id.find(']').map(|end| &id[..end])
     })
 }
 
-// Extract plain text from Atlassian Document Format
-fn extract_text_from_adf(adf: &serde_json::Value) -> Option<String> {
+// Display names of `account_ids`, each looked up once: from the user cache,
+// and the rest in bulk requests. Accounts that can't be looked up are left
+// out, so they show as their id.
+#[cfg(feature = "tui")]
+fn display_names<'a>(config: &Config, account_ids: impl IntoIterator<Item = &'a str>) -> HashMap<String, String> {
+    let mut names = HashMap::new();
+    let mut missing = Vec::new();
+    for id in account_ids {
+        if names.contains_key(id) || missing.contains(&id) {
+            continue;
+        }
+        match users::display_name(id) {
+            Some(name) => {
+                names.insert(id.to_string(), name);
+            }
+            None => missing.push(id),
+        }
+    }
+    let Ok((url, auth)) = api_auth(config) else { return names };
+    
+    // The bulk endpoint takes up to 200 ids, each as its own parameter
+    for chunk in missing.chunks(200) {
+        let mut query: Vec<(&str, &str)> = chunk.iter().map(|id| ("accountId", *id)).collect();
+        query.push(("maxResults", "200"));
+        let response = client()
+            .get(format!("{}/rest/api/3/user/bulk", url))
+            .header("Authorization", &auth)
+            .header("Accept", "application/json")
+            .query(&query)
+            .send_tracked();
+        let Ok(response) = response else { break };
+        if !response.status().is_success() {
+            break;
+        }
+        let Ok(json) = response.json::<serde_json::Value>() else { break };
+        for user in json.get("values").and_then(|v| v.as_array()).into_iter().flatten() {
+            // Remembered in the user cache as they're read
+            if let Some(account) = Account::from_json(user) {
+                names.insert(account.account_id, account.name);
+            }
+        }
+    }
+    names
+}
+
+// Replace `[~accountid:...]` references with display names from `names`.
+// Wiki markup keeps the `[~name]` form so its renderer still styles it as a
+// mention.
+#[cfg(feature = "tui")]
+fn resolve_mentions(text: &str, wiki: bool, names: &HashMap<String, String>) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    
+    while let Some(start) = rest.find(MENTION) {
+        let Some(len) = rest[start..].find(']') else { break };
+        let account_id = &rest[start + MENTION.len()..start + len];
+        let name = names.get(account_id).map_or(account_id, String::as_str);
+        
+        result.push_str(&rest[..start]);
+        if wiki {
+            result.push_str(&format!("[~{}]", name));
+        } else {
+            result.push_str(&format!("@{}", name));
+        }
+        rest = &rest[start + len + 1..];
+    }
+    
+    result.push_str(rest);
+    result
+}
+
+// Extract plain text from Atlassian Document Format. Link targets are
+// appended to `urls` and referenced in the text by `[n]` markers.
+pub fn extract_text_from_adf(adf: &serde_json::Value, urls: &mut Vec<String>) -> Option<String> {
     let mut text = String::new();
     
     if let Some(content) = adf.get("content").and_then(|c| c.as_array()) {
         for node in content {
-            extract_node_text(node, &mut text);
+            extract_node_text(node, &mut text, urls);
         }
     }
     
@@ -286,32 +1971,147 @@ fn extract_text_from_adf(adf: &serde_json::Value) -> Option<String> {
     }
 }
 
-fn extract_node_text(node: &serde_json::Value, text: &mut String) {
+fn extract_node_text(node: &serde_json::Value, text: &mut String, urls: &mut Vec<String>) {
     if let Some(node_type) = node.get("type").and_then(|t| t.as_str()) {
         match node_type {
             "text" => {
                 if let Some(t) = node.get("text").and_then(|t| t.as_str()) {
                     text.push_str(t);
                 }
+                let href = node.get("marks")
+                    .and_then(|m| m.as_array())
+                    .and_then(|marks| marks.iter().find(|m| m.get("type").and_then(|t| t.as_str()) == Some("link")))
+                    .and_then(|m| m.get("attrs"))
+                    .and_then(|a| a.get("href"))
+                    .and_then(|h| h.as_str());
+                if let Some(href) = href {
+                    push_url_marker(href, text, urls);
+                }
+            }
+            "inlineCard" | "blockCard" | "embedCard" => {
+                if let Some(url) = node.get("attrs").and_then(|a| a.get("url")).and_then(|u| u.as_str()) {
+                    text.push_str(url);
+                    push_url_marker(url, text, urls);
+                }
+            }
+            // Indented four spaces, like Markdown, so it's shown unwrapped
+            "codeBlock" => {
+                let mut code = String::new();
+                for child in node.get("content").and_then(|c| c.as_array()).into_iter().flatten() {
+                    extract_node_text(child, &mut code, urls);
+                }
+                for line in code.lines() {
+                    text.push_str("    ");
+                    text.push_str(line);
+                    text.push('\n');
+                }
+            }
+            // Marked with `> `, like Markdown, so replies can be told apart
+            "blockquote" => {
+                let mut quote = String::new();
+                for child in node.get("content").and_then(|c| c.as_array()).into_iter().flatten() {
+                    extract_node_text(child, &mut quote, urls);
+                }
+                for line in quote.trim_end().lines() {
+                    text.push_str("> ");
+                    text.push_str(line);
+                    text.push('\n');
+                }
             }
-            "paragraph" | "heading" | "blockquote" | "codeBlock" | 
+            "paragraph" | "heading" | 
             "bulletList" | "orderedList" | "listItem" | "panel" => {
                 if let Some(content) = node.get("content").and_then(|c| c.as_array()) {
                     for child in content {
-                        extract_node_text(child, text);
+                        extract_node_text(child, text, urls);
                     }
                     text.push('\n');
                 }
             }
             "hardBreak" => text.push('\n'),
+            "table" => render_adf_table(node, text, urls),
+            "mention" => {
+                // Mentions carry the display name in `text`; fall back to the
+                // account id so it can be resolved after extraction
+                let attrs = node.get("attrs");
+                if let Some(name) = attrs.and_then(|a| a.get("text")).and_then(|t| t.as_str()) {
+                    if !name.starts_with('@') {
+                        text.push('@');
+                    }
+                    text.push_str(name);
+                } else if let Some(id) = attrs.and_then(|a| a.get("id")).and_then(|i| i.as_str()) {
+                    text.push_str(&format!("{}{}]", MENTION, id));
+                }
+            }
             _ => {
                 // Try to extract content from unknown nodes
                 if let Some(content) = node.get("content").and_then(|c| c.as_array()) {
                     for child in content {
-                        extract_node_text(child, text);
+                        extract_node_text(child, text, urls);
                     }
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
+
+// Number a link target, reusing the marker when the same URL appears again
+fn push_url_marker(url: &str, text: &mut String, urls: &mut Vec<String>) {
+    let index = url_index(url, urls);
+    text.push_str(&format!(" [{}]", index + 1));
+}
+
+fn url_index(url: &str, urls: &mut Vec<String>) -> usize {
+    match urls.iter().position(|u| u == url) {
+        Some(i) => i,
+        None => {
+            urls.push(url.to_string());
+            urls.len() - 1
+        }
+    }
+}
+
+// Render an ADF table as aligned text rows, with a rule under header rows
+fn render_adf_table(node: &serde_json::Value, text: &mut String, urls: &mut Vec<String>) {
+    let mut rows: Vec<(bool, Vec<String>)> = Vec::new();
+    
+    for row in node.get("content").and_then(|c| c.as_array()).into_iter().flatten() {
+        let mut is_header = false;
+        let cells = row.get("content")
+            .and_then(|c| c.as_array())
+            .map(|cells| {
+                cells.iter().map(|cell| {
+                    is_header |= cell.get("type").and_then(|t| t.as_str()) == Some("tableHeader");
+                    let mut cell_text = String::new();
+                    for child in cell.get("content").and_then(|c| c.as_array()).into_iter().flatten() {
+                        extract_node_text(child, &mut cell_text, urls);
+                    }
+                    cell_text.split_whitespace().collect::<Vec<_>>().join(" ")
+                }).collect()
+            })
+            .unwrap_or_default();
+        rows.push((is_header, cells));
+    }
+    
+    let column_count = rows.iter().map(|(_, cells)| cells.len()).max().unwrap_or(0);
+    let mut widths = vec![0; column_count];
+    for (_, cells) in &rows {
+        for (i, cell) in cells.iter().enumerate() {
+            widths[i] = widths[i].max(cell.chars().count());
+        }
+    }
+    
+    for (is_header, cells) in &rows {
+        let padded: Vec<String> = widths.iter().enumerate()
+            .map(|(i, width)| {
+                let cell = cells.get(i).map(|c| c.as_str()).unwrap_or("");
+                format!("{}{}", cell, " ".repeat(width - cell.chars().count()))
+            })
+            .collect();
+        text.push_str(&format!("│ {} │\n", padded.join(" │ ")));
+        
+        if *is_header {
+            let rule: Vec<String> = widths.iter().map(|w| "─".repeat(*w)).collect();
+            text.push_str(&format!("├─{}─┤\n", rule.join("─┼─")));
+        }
+    }
+}
@@ -0,0 +1,111 @@
+// Helpers for composing JQL from user-supplied values
+
+/// A user-supplied value checked for things no quoting can make sense of
+pub fn value<'a>(flag: &str, value: &'a str) -> Result<&'a str, String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Err(format!("{} needs a value", flag));
+    }
+    if trimmed.chars().any(|c| c.is_control()) {
+        return Err(format!("{} can't contain control characters or line breaks", flag));
+    }
+    Ok(trimmed)
+}
+
+/// A JQL string literal, with quotes and backslashes escaped
+pub fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        if c == '"' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// `field = "a"` for one value, `field in ("a", "b")` for several
+#[cfg(feature = "tui")]
+pub fn in_clause(field: &str, values: &[&str]) -> String {
+    match values {
+        [value] => format!("{} = {}", field, quote(value)),
+        _ => format!(
+            "{} in ({})",
+            field,
+            values.iter().map(|v| quote(v)).collect::<Vec<_>>().join(", ")
+        ),
+    }
+}
+
+/// `clause AND (jql)`, keeping any ORDER BY at the end where JQL needs it
+pub fn and(clause: &str, jql: &str) -> String {
+    let (filter, order) = split_order_by(jql);
+    let mut combined = if filter.trim().is_empty() {
+        clause.to_string()
+    } else {
+        format!("{} AND ({})", clause, filter.trim())
+    };
+    if !order.is_empty() {
+        combined.push(' ');
+        combined.push_str(order);
+    }
+    combined
+}
+
+// Split off a trailing ORDER BY, matched case-insensitively outside quotes
+fn split_order_by(jql: &str) -> (&str, &str) {
+    let lower = jql.to_ascii_lowercase();
+    let mut in_quote = None;
+    let mut prev = ' ';
+    for (i, c) in jql.char_indices() {
+        match in_quote {
+            // A backslash escapes the next character inside a string
+            Some(_) if prev == '\\' => {
+                prev = ' ';
+                continue;
+            }
+            Some(q) if c == q => in_quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => in_quote = Some(c),
+            None if prev.is_whitespace() && lower[i..].starts_with("order by") => {
+                return (&jql[..i], &jql[i..]);
+            }
+            None => {}
+        }
+        prev = c;
+    }
+    (jql, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quoting_escapes_quotes_and_backslashes() {
+        assert_eq!(quote("Alice"), r#""Alice""#);
+        assert_eq!(quote(r#"say "hi" \o/"#), r#""say \"hi\" \\o/""#);
+        assert_eq!(quote("{{me}} AND order by"), r#""{{me}} AND order by""#);
+    }
+
+    #[test]
+    fn clauses_go_before_the_order_by() {
+        assert_eq!(and("a = 1", "b = 2 ORDER BY rank"), "a = 1 AND (b = 2) ORDER BY rank");
+        assert_eq!(and("a = 1", "order by created DESC"), "a = 1 order by created DESC");
+        assert_eq!(and("a = 1", "  "), "a = 1");
+        assert_eq!(and("a = 1", "b = 2 OR c = 3"), "a = 1 AND (b = 2 OR c = 3)");
+    }
+
+    #[test]
+    fn order_by_inside_strings_is_text() {
+        assert_eq!(split_order_by(r#"summary ~ "order by" ORDER BY key"#), (r#"summary ~ "order by" "#, "ORDER BY key"));
+        assert_eq!(split_order_by(r#"summary ~ 'x order by y'"#), (r#"summary ~ 'x order by y'"#, ""));
+        // An escaped quote doesn't end the string
+        assert_eq!(split_order_by(r#"summary ~ "a \" order by b""#), (r#"summary ~ "a \" order by b""#, ""));
+        assert_eq!(split_order_by(r#"summary ~ "a \\" order by b"#), (r#"summary ~ "a \\" "#, "order by b"));
+        // Only a whole word after whitespace starts it
+        assert_eq!(split_order_by("reorder by = 1"), ("reorder by = 1", ""));
+    }
+}
@@ -0,0 +1,165 @@
+// The key bindings shown to the user, by where they apply. The help overlay
+// and the footer bar are both generated from this table, so a binding
+// added here shows up in each.
+use crate::i18n::t;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Context {
+    Board,
+    Detail,
+    Inbox,
+    Timesheet,
+    Releases,
+}
+
+/// When a binding does something, and so is worth showing in the footer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum When {
+    Always,
+    Selected,  // A card is selected
+    Connected,  // JIRA is set up
+    CardAction,  // A card is selected and JIRA is set up
+    Editable,  // JIRA lets you edit the ticket
+    Commentable,  // JIRA lets you comment on the ticket
+    Split,  // Several boards share the screen
+    NeedsSetup,  // JIRA isn't set up yet
+    Filtered,  // The board is narrowed, e.g. to one release
+    Searching,  // A search is active in the detail view
+    NotSearching,
+}
+
+pub struct Binding {
+    pub keys: &'static str,
+    pub context: Context,
+    pub when: When,
+    pub help: &'static str,  // Locale key of the help overlay text
+    pub label: Option<&'static str>,  // Locale key of the footer label; None keeps it out of the footer
+}
+
+const fn bind(keys: &'static str, context: Context, when: When, help: &'static str, label: Option<&'static str>) -> Binding {
+    Binding { keys, context, when, help, label }
+}
+
+use Context::{Board, Detail, Inbox, Releases, Timesheet};
+use When::*;
+
+pub const BINDINGS: &[Binding] = &[
+    bind("↑↓/jk", Board, Selected, "help.select", Some("keys.navigate")),
+    bind("Enter", Board, Selected, "help.open", Some("keys.detail")),
+    bind("0-9", Board, Selected, "help.jump", None),
+    bind("r", Board, Connected, "help.refresh", Some("keys.refresh")),
+    bind("R", Board, CardAction, "help.refresh_card", None),
+    bind("l", Board, CardAction, "help.refresh_lane", None),
+    bind("p", Board, Always, "help.pause", Some("keys.pause")),
+    bind("v", Board, Always, "help.density", None),
+    bind("+/-", Board, Always, "help.zoom", None),
+    bind("f", Board, Editable, "help.flag", Some("keys.flag")),
+    bind("i", Board, Editable, "help.edit_summary", Some("keys.edit")),
+    bind("N", Board, Connected, "help.new_ticket", None),
+    bind("b", Board, Connected, "help.build_query", None),
+    bind("Ctrl+W", Board, Split, "help.switch_board", Some("keys.switch")),
+    bind("Ctrl+R", Board, Connected, "help.reload_metadata", None),
+    bind("s", Board, NeedsSetup, "help.setup", Some("keys.setup")),
+    bind("K", Board, Always, "help.legend", None),
+    bind("L", Board, Always, "help.activity", None),
+    bind("M", Board, Always, "help.stats", None),
+    bind("H", Board, Always, "help.hygiene", None),
+    bind("J", Board, Selected, "help.raw", None),
+    bind("D", Board, Connected, "help.changes", None),
+    bind("B", Board, CardAction, "help.blockers", None),
+    bind("A", Board, Connected, "help.aging", None),
+    bind("a", Board, Always, "help.assignee_filter", None),
+    bind("Y", Board, Selected, "help.snippet", None),
+    bind("F", Board, Selected, "help.reminder", None),
+    bind("I", Board, Connected, "help.inbox", None),
+    bind("T", Board, Connected, "help.timesheet", None),
+    bind("V", Board, Connected, "help.releases", None),
+    bind("Esc", Board, Filtered, "help.clear_filter", Some("keys.clear_filter")),
+    bind("?", Board, Always, "help.help", Some("keys.help")),
+    bind("q/Esc", Board, Always, "help.quit", Some("keys.quit")),
+
+    bind("Esc/q", Detail, NotSearching, "help.back", Some("keys.back")),
+    bind("↑↓/jk", Detail, Always, "help.scroll", Some("keys.scroll")),
+    bind("PgUp/PgDn", Detail, NotSearching, "help.page", Some("keys.page")),
+    bind("Ctrl+D/U", Detail, NotSearching, "help.half_page", None),
+    bind("g/G", Detail, NotSearching, "help.top_bottom", Some("keys.top_bottom")),
+    bind("Home/End", Detail, NotSearching, "help.top_bottom", None),
+    bind("]]/[[", Detail, NotSearching, "help.section", Some("keys.section")),
+    bind("c", Detail, NotSearching, "help.comments", Some("keys.comments")),
+    bind("/", Detail, Always, "help.search", Some("keys.search")),
+    bind("n/N", Detail, Searching, "help.next_match", Some("keys.next_match")),
+    bind("Esc", Detail, Searching, "help.clear_search", Some("keys.clear_search")),
+    bind("1-9/gx", Detail, NotSearching, "help.open_link", Some("keys.open_link")),
+    bind("W", Detail, NotSearching, "help.watchers", Some("keys.watchers")),
+    bind("Tab/Space", Detail, Editable, "help.checklist", None),
+    bind("C", Detail, Commentable, "help.add_comment", Some("keys.comment")),
+    bind("r", Detail, Commentable, "help.reply", Some("keys.reply")),
+    bind("+", Detail, Connected, "help.thumbs_up", None),
+    bind("J", Detail, NotSearching, "help.raw", None),
+    bind("B", Detail, Connected, "help.blockers", None),
+    bind("Y", Detail, Always, "help.snippet", None),
+    bind("F", Detail, Always, "help.reminder", None),
+
+    bind("↑↓/jk", Inbox, Always, "help.select", Some("keys.select")),
+    bind("Enter", Inbox, Always, "help.open", Some("keys.open")),
+    bind("m", Inbox, Always, "help.toggle_read", Some("keys.toggle_read")),
+    bind("a", Inbox, Always, "help.mark_all_read", Some("keys.mark_all_read")),
+    bind("r", Inbox, Always, "help.reload", Some("keys.reload")),
+    bind("Esc", Inbox, Always, "help.back", Some("keys.back")),
+
+    bind("↑↓/jk", Timesheet, Always, "help.select", Some("keys.select")),
+    bind("l", Timesheet, Always, "help.log_time", Some("keys.log_time")),
+    bind("←→/[]", Timesheet, Always, "help.week", Some("keys.week")),
+    bind("Enter", Timesheet, Always, "help.open", Some("keys.open")),
+    bind("r", Timesheet, Always, "help.reload", Some("keys.reload")),
+    bind("Esc", Timesheet, Always, "help.back", Some("keys.back")),
+
+    bind("↑↓/jk", Releases, Always, "help.select", Some("keys.select")),
+    bind("Enter", Releases, Always, "help.filter_release", Some("keys.filter")),
+    bind("r", Releases, Always, "help.reload", Some("keys.reload")),
+    bind("Esc", Releases, Always, "help.back", Some("keys.back")),
+];
+
+/// What's on screen, deciding which bindings apply
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    pub selected: bool,
+    pub connected: bool,
+    pub split: bool,
+    pub searching: bool,
+    pub filtered: bool,
+    pub editable: bool,  // Implies connected, and on the board a selected card
+    pub commentable: bool,
+}
+
+impl When {
+    fn applies(self, state: &State) -> bool {
+        match self {
+            Always => true,
+            Selected => state.selected,
+            Connected => state.connected,
+            CardAction => state.selected && state.connected,
+            Editable => state.editable,
+            Commentable => state.commentable,
+            Split => state.split,
+            NeedsSetup => !state.connected,
+            Filtered => state.filtered,
+            Searching => state.searching,
+            NotSearching => !state.searching,
+        }
+    }
+}
+
+/// Every binding for a context, for the help overlay
+pub fn bindings(context: Context) -> impl Iterator<Item = &'static Binding> {
+    BINDINGS.iter().filter(move |b| b.context == context)
+}
+
+/// The footer hint for a context: the labelled bindings that apply now
+pub fn footer(context: Context, state: &State) -> String {
+    bindings(context)
+        .filter(|b| b.when.applies(state))
+        .filter_map(|b| b.label.map(|label| format!("{}: {}", b.keys, t(label))))
+        .collect::<Vec<_>>()
+        .join("  ")
+}
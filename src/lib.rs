@@ -0,0 +1,60 @@
+// kanbars as a library: the binary's modules, so benchmarks and tests can
+// drive the fetch, model and render code directly
+
+pub mod acli;
+pub mod activity;
+#[cfg(feature = "tui")]
+pub mod aging;
+#[cfg(feature = "tui")]
+pub mod app;
+pub mod audit;
+pub mod blockers;
+#[cfg(feature = "tui")]
+pub mod board;
+#[cfg(feature = "tui")]
+pub mod catch_up;
+#[cfg(feature = "tui")]
+pub mod browser;
+pub mod checklist;
+#[cfg(feature = "tui")]
+pub mod clipboard;
+pub mod cli;
+pub mod commands;
+pub mod config;
+pub mod debug;
+pub mod demo;
+#[cfg(feature = "tui")]
+pub mod desktop;
+pub mod doctor;
+pub mod fixtures;
+pub mod history;
+pub mod hygiene;
+pub mod i18n;
+#[cfg(feature = "tui")]
+pub mod inbox;
+pub mod jira;
+pub mod jira_api;
+pub mod jql;
+#[cfg(feature = "tui")]
+pub mod keymap;
+#[cfg(feature = "tui")]
+pub mod markdown;
+pub mod metadata;
+pub mod model;
+pub mod permissions;
+#[cfg(feature = "tui")]
+pub mod query_builder;
+pub mod rate_limit;
+#[cfg(feature = "tui")]
+pub mod releases;
+pub mod reminders;
+pub mod telemetry;
+#[cfg(feature = "tui")]
+pub mod timesheet;
+#[cfg(feature = "tui")]
+pub mod tui;
+#[cfg(feature = "tui")]
+pub mod ui;
+pub mod users;
+#[cfg(feature = "tui")]
+pub mod wiki;
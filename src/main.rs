@@ -1,31 +1,35 @@
-use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
-use ratatui::{
-    backend::{Backend, CrosstermBackend},
-    Terminal,
-};
-use std::{error::Error, io, time::{Duration, Instant}};
+use std::error::Error;
 
-mod cli;
-mod config;
-mod jira;
-mod jira_api;
-mod model;
-mod ui;
-
-use crate::cli::Args;
-use crate::config::Config;
-use crate::jira::fetch_tickets;
-use crate::model::StatusGroups;
-use crate::ui::{draw_ui, AppState, UiMode};
-use clap::Parser;
+use kanbars::cli::{Args, Command};
+use kanbars::config::Config;
+use kanbars::jira::fetch_tickets;
+use kanbars::model::StatusGroups;
+#[cfg(feature = "tui")]
+use kanbars::tui;
+use kanbars::{activity, audit, commands, debug, doctor, fixtures, history, i18n, model, telemetry, users};
+use clap::{CommandFactory, Parser};
 
 fn main() -> Result<(), Box<dyn Error>> {
     let args = Args::parse();
-    let mut config = Config::load();
+    telemetry::start();
+    if let Some(ref path) = args.config {
+        Config::use_path(path.clone());
+    }
+    if let Some(ref path) = args.activity_log {
+        activity::log_to(path.clone());
+    }
+    if args.a11y {
+        model::enable_a11y();
+    }
+    if args.debug {
+        debug::enable();
+    }
+    if let Some(ref dir) = args.record {
+        fixtures::record_to(dir.clone());
+    }
+    if let Some(ref dir) = args.replay {
+        fixtures::replay_from(dir.clone());
+    }
     
     // Handle --init flag
     if args.init {
@@ -36,193 +40,109 @@ fn main() -> Result<(), Box<dyn Error>> {
         return Ok(());
     }
     
-    // Override config with CLI args
-    if let Some(ref url) = args.url {
-        config.jira.url = Some(url.clone());
+    if args.man {
+        clap_mangen::Man::new(Args::command()).render(&mut std::io::stdout())?;
+        return Ok(());
     }
-    config.query.jql = args.build_jql(&config.query.jql);
     
-    // Handle --once mode (display and exit)
-    if args.once {
-        let tickets = fetch_tickets(&config)?;
-        let columns = StatusGroups::from_tickets(tickets);
-        
-        // Simple non-TUI output for use with watch
-        println!("🦀 KANBARS - JIRA Board\n");
-        columns.print_simple();
-        return Ok(());
+    match args.command {
+        Some(Command::Doctor) => std::process::exit(if doctor::run() { 0 } else { 1 }),
+        Some(Command::Audit) => return finish(audit::print()),
+        Some(Command::Completions { shell }) => {
+            clap_complete::generate(shell, &mut Args::command(), "kanbars", &mut std::io::stdout());
+            return Ok(());
+        }
+        _ => {}
     }
     
-    // Fetch tickets before setting up terminal
-    let tickets = fetch_tickets(&config)?;
-    let columns = StatusGroups::from_tickets(tickets);
+    let config = match Config::load() {
+        _ if args.demo => Config::demo(),
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}\n\nRun `kanbars doctor` for a full check.", e);
+            std::process::exit(1);
+        }
+    };
+    i18n::set_language(config.display.language.as_deref());
     
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-
-    let res = run_app(&mut terminal, columns, &config, args.refresh);
-
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
-
-    if let Err(err) = res {
-        println!("{:?}", err)
+    // Ticket subcommands use the connection settings but no board
+    match args.command {
+        Some(Command::Assign { ref key, ref user }) => return finish(commands::assign(&config, key, user)),
+        Some(Command::Take { ref key }) => return finish(commands::take(&config, key)),
+        Some(Command::Transition { ref jql, ref status, yes, limit }) => {
+            return finish(commands::transition(&config, jql, status, yes, limit));
+        }
+        Some(Command::Create { ref project, ref issue_type, ref summary, ref description_file }) => {
+            return finish(commands::create(&config, project, issue_type, summary, description_file.as_deref()));
+        }
+        _ => {}
     }
-
-    Ok(())
-}
-
-fn run_app<B: Backend>(
-    terminal: &mut Terminal<B>,
-    mut columns: StatusGroups,
-    config: &Config,
-    refresh_seconds: u64,
-) -> Result<(), Box<dyn Error>> {
-    let mut last_refresh = Instant::now();
-    let refresh_interval = Duration::from_secs(refresh_seconds);
-    let mut paused = false;
-    let mut last_update_time = chrono::Local::now();
     
-    let mut app_state = AppState {
-        mode: UiMode::Board,
-        selected_index: 0,  // Global index across all tickets
-        detail_ticket: None,
-        detail_scroll: 0,
+    let configs = match args.board_configs(config) {
+        Ok(configs) => configs,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
     };
     
-    loop {
-        // Draw UI with current state
-        terminal.draw(|f| draw_ui(f, &columns, Some(&last_update_time), paused, refresh_seconds, &mut app_state))?;
-        
-        // Check for keyboard input with timeout
-        let timeout = if paused {
-            Duration::from_millis(100) // Short timeout when paused
+    if let Some(Command::Diff { ref since }) = args.command {
+        return finish(commands::diff(&configs, since));
+    }
+    
+    // Handle --once mode (display and exit); headless builds always print
+    if args.once || !cfg!(feature = "tui") {
+        let a11y = model::a11y();
+        if a11y {
+            println!("KANBARS, JIRA board.\n");
         } else {
-            // Calculate time until next refresh
-            let elapsed = last_refresh.elapsed();
-            if elapsed >= refresh_interval {
-                Duration::from_millis(0) // Refresh immediately
-            } else {
-                refresh_interval - elapsed
-            }
-        };
-        
-        if event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                match app_state.mode {
-                    UiMode::Board => {
-                        match key.code {
-                            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
-                            KeyCode::Char('r') => {
-                                // Manual refresh
-                                match fetch_tickets(config) {
-                                    Ok(tickets) => {
-                                        columns = StatusGroups::from_tickets(tickets);
-                                        last_update_time = chrono::Local::now();
-                                        last_refresh = Instant::now();
-                                    }
-                                    Err(e) => {
-                                        // TODO: Show error in UI
-                                        eprintln!("Refresh failed: {}", e);
-                                    }
-                                }
-                            }
-                            KeyCode::Char('p') => {
-                                // Toggle pause
-                                paused = !paused;
-                            }
-                            // Simple navigation - up/down cycles through all tickets
-                            KeyCode::Up | KeyCode::Char('k') => {
-                                let total_tickets = columns.total_tickets();
-                                if app_state.selected_index > 0 {
-                                    app_state.selected_index -= 1;
-                                } else if total_tickets > 0 {
-                                    // Wrap around to last ticket
-                                    app_state.selected_index = total_tickets - 1;
-                                }
-                            }
-                            KeyCode::Down | KeyCode::Char('j') => {
-                                let total_tickets = columns.total_tickets();
-                                if total_tickets > 0 {
-                                    app_state.selected_index = (app_state.selected_index + 1) % total_tickets;
-                                }
-                            }
-                            KeyCode::Enter => {
-                                // Enter detail view for selected ticket
-                                if let Some(ticket) = columns.get_ticket_by_index(app_state.selected_index) {
-                                    // Try to fetch full details
-                                    let mut detailed_ticket = ticket.clone();
-                                    if detailed_ticket.description.is_none() {
-                                        // Only fetch if we don't already have details
-                                        match jira_api::fetch_ticket_details(config, &ticket.key) {
-                                            Ok(full_ticket) => {
-                                                detailed_ticket = full_ticket;
-                                            }
-                                            Err(e) => {
-                                                // Store error message in description field for display
-                                                detailed_ticket.description = Some(format!(
-                                                    "[Error fetching details]\n\n{}", 
-                                                    e.to_string()
-                                                ));
-                                            }
-                                        }
-                                    }
-                                    app_state.detail_ticket = Some(detailed_ticket);
-                                    app_state.detail_scroll = 0;
-                                    app_state.mode = UiMode::Detail;
-                                }
-                            }
-                            _ => {}
-                        }
-                    }
-                    UiMode::Detail => {
-                        match key.code {
-                            KeyCode::Char('q') | KeyCode::Esc => {
-                                // Return to board view
-                                app_state.mode = UiMode::Board;
-                                app_state.detail_ticket = None;
-                            }
-                            KeyCode::Up | KeyCode::Char('k') => {
-                                if app_state.detail_scroll > 0 {
-                                    app_state.detail_scroll -= 1;
-                                }
-                            }
-                            KeyCode::Down | KeyCode::Char('j') => {
-                                app_state.detail_scroll += 1;
-                            }
-                            KeyCode::PageUp => {
-                                app_state.detail_scroll = app_state.detail_scroll.saturating_sub(10);
-                            }
-                            KeyCode::PageDown => {
-                                app_state.detail_scroll += 10;
-                            }
-                            _ => {}
-                        }
-                    }
+            println!("🦀 KANBARS - JIRA Board\n");
+        }
+        for config in &configs {
+            let fetched = fetch_tickets(config)?;
+            history::record(&config.query.name, &fetched.tickets);
+            let shown = fetched.tickets.len();
+            let columns = StatusGroups::from_tickets(fetched.tickets, config.display.group_by);
+            
+            // Simple non-TUI output for use with watch
+            if configs.len() > 1 {
+                if a11y {
+                    println!("Board: {}.\n", config.query.name);
+                } else {
+                    println!("━━ {} ━━\n", config.query.name);
                 }
             }
-        } else if !paused && last_refresh.elapsed() >= refresh_interval {
-            // Auto-refresh
-            match fetch_tickets(config) {
-                Ok(tickets) => {
-                    columns = StatusGroups::from_tickets(tickets);
-                    last_update_time = chrono::Local::now();
-                    last_refresh = Instant::now();
-                }
-                Err(e) => {
-                    // TODO: Show error in UI
-                    eprintln!("Auto-refresh failed: {}", e);
-                    last_refresh = Instant::now(); // Reset timer even on error
+            if let Some(total) = fetched.total {
+                if a11y {
+                    println!("Warning: showing {} of {} tickets. Raise the limit with --max-results.\n", shown, total);
+                } else {
+                    println!("⚠️  Showing {} of {} tickets (raise with --max-results)\n", shown, total);
                 }
             }
+            if a11y {
+                columns.print_linear();
+            } else {
+                columns.print_simple(&config.display);
+            }
         }
+        return Ok(());
     }
-}
\ No newline at end of file
+    
+    #[cfg(feature = "tui")]
+    tui::run(&args, configs)?;
+    // Names learned since the last board fetch
+    users::save();
+    
+    Ok(())
+}
+
+// Subcommand errors are read by people (and scripts checking the exit
+// code), so print them plainly rather than as Debug output
+fn finish(result: Result<(), Box<dyn Error>>) -> Result<(), Box<dyn Error>> {
+    users::save();
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+    Ok(())
+}
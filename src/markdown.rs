@@ -0,0 +1,225 @@
+// Markdown-ish text for comments typed in the terminal: **bold**, `code`,
+// `-`/`1.` lists, `>` quotes, ``` fenced code and @mentions. Parsed once
+// into blocks that the composer previews and that convert to ADF for posting.
+use crate::jira_api::Account;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Inline {
+    Text(String),
+    Bold(String),
+    Code(String),
+    Mention(String),  // Name as typed, without the `@`
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    Paragraph(Vec<Vec<Inline>>),  // One entry per line; lines are joined with hard breaks
+    Bullets(Vec<Vec<Inline>>),
+    Numbered(Vec<Vec<Inline>>),
+    Quote(Vec<Vec<Inline>>),
+    Code(String),
+}
+
+// "- item" or "* item"
+fn bullet(line: &str) -> Option<&str> {
+    line.strip_prefix("- ").or_else(|| line.strip_prefix("* "))
+}
+
+// "> quoted", or a bare ">" for a blank quoted line
+fn quoted(line: &str) -> Option<&str> {
+    let rest = line.strip_prefix('>')?;
+    Some(rest.strip_prefix(' ').unwrap_or(rest))
+}
+
+// "1. item"
+fn numbered(line: &str) -> Option<&str> {
+    let (number, rest) = line.split_once(". ")?;
+    (!number.is_empty() && number.chars().all(|c| c.is_ascii_digit())).then_some(rest)
+}
+
+pub fn parse(text: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut lines = text.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if trimmed.starts_with("```") {
+            // An unclosed fence runs to the end
+            let mut code = Vec::new();
+            for line in lines.by_ref() {
+                if line.trim_start().starts_with("```") {
+                    break;
+                }
+                code.push(line);
+            }
+            blocks.push(Block::Code(code.join("\n")));
+        } else if let Some(item) = bullet(trimmed) {
+            let mut items = vec![parse_inline(item)];
+            while let Some(item) = lines.peek().and_then(|l| bullet(l.trim_start())) {
+                items.push(parse_inline(item));
+                lines.next();
+            }
+            blocks.push(Block::Bullets(items));
+        } else if let Some(item) = numbered(trimmed) {
+            let mut items = vec![parse_inline(item)];
+            while let Some(item) = lines.peek().and_then(|l| numbered(l.trim_start())) {
+                items.push(parse_inline(item));
+                lines.next();
+            }
+            blocks.push(Block::Numbered(items));
+        } else if let Some(line) = quoted(trimmed) {
+            let mut quote = vec![parse_inline(line)];
+            while let Some(line) = lines.peek().and_then(|l| quoted(l.trim_start())) {
+                quote.push(parse_inline(line));
+                lines.next();
+            }
+            blocks.push(Block::Quote(quote));
+        } else {
+            // A paragraph runs until a blank line or another kind of block
+            let mut paragraph = vec![parse_inline(line)];
+            while let Some(next) = lines.peek().map(|l| l.trim_start()) {
+                if next.is_empty() || next.starts_with("```") || bullet(next).is_some() || numbered(next).is_some() || quoted(next).is_some() {
+                    break;
+                }
+                paragraph.push(parse_inline(lines.next().unwrap_or_default()));
+            }
+            blocks.push(Block::Paragraph(paragraph));
+        }
+    }
+    blocks
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '.' | '_' | '-')
+}
+
+fn parse_inline(line: &str) -> Vec<Inline> {
+    let mut inlines = Vec::new();
+    let mut text = String::new();
+    let mut rest = line;
+
+    while let Some(c) = rest.chars().next() {
+        let styled = if let Some(after) = rest.strip_prefix("**")
+            && let Some((bold, after)) = after.split_once("**")
+            && !bold.is_empty()
+        {
+            Some((Inline::Bold(bold.to_string()), after))
+        } else if let Some(after) = rest.strip_prefix('`')
+            && let Some((code, after)) = after.split_once('`')
+            && !code.is_empty()
+        {
+            Some((Inline::Code(code.to_string()), after))
+        } else if c == '@' && !text.ends_with(|c: char| !c.is_whitespace()) {
+            // `@` only starts a mention after a space, so emails stay text;
+            // a trailing period ends the sentence rather than the name
+            let after = &rest[1..];
+            let end = after.find(|c| !is_name_char(c)).unwrap_or(after.len());
+            let name = after[..end].trim_end_matches('.');
+            (!name.is_empty()).then(|| (Inline::Mention(name.to_string()), &after[name.len()..]))
+        } else {
+            None
+        };
+
+        match styled {
+            Some((inline, after)) => {
+                if !text.is_empty() {
+                    inlines.push(Inline::Text(std::mem::take(&mut text)));
+                }
+                inlines.push(inline);
+                rest = after;
+            }
+            None => {
+                text.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    if !text.is_empty() {
+        inlines.push(Inline::Text(text));
+    }
+    inlines
+}
+
+/// Names after `@`, each once, in the order they first appear
+pub fn mentions(blocks: &[Block]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for block in blocks {
+        let lines = match block {
+            Block::Paragraph(lines) | Block::Bullets(lines) | Block::Numbered(lines) | Block::Quote(lines) => lines,
+            Block::Code(_) => continue,
+        };
+        for inline in lines.iter().flatten() {
+            if let Inline::Mention(name) = inline
+                && !names.contains(name)
+            {
+                names.push(name.clone());
+            }
+        }
+    }
+    names
+}
+
+fn adf_inline(inline: &Inline, accounts: &HashMap<String, Account>) -> serde_json::Value {
+    match inline {
+        Inline::Text(text) => serde_json::json!({ "type": "text", "text": text }),
+        Inline::Bold(text) => serde_json::json!({ "type": "text", "text": text, "marks": [{ "type": "strong" }] }),
+        Inline::Code(text) => serde_json::json!({ "type": "text", "text": text, "marks": [{ "type": "code" }] }),
+        Inline::Mention(name) => match accounts.get(name) {
+            Some(account) => serde_json::json!({
+                "type": "mention",
+                "attrs": { "id": account.account_id, "text": format!("@{}", account.name) },
+            }),
+            // Nobody matched, so it goes out as typed
+            None => serde_json::json!({ "type": "text", "text": format!("@{}", name) }),
+        },
+    }
+}
+
+fn adf_paragraph(lines: &[Vec<Inline>], accounts: &HashMap<String, Account>) -> serde_json::Value {
+    let mut content = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            content.push(serde_json::json!({ "type": "hardBreak" }));
+        }
+        content.extend(line.iter().map(|inline| adf_inline(inline, accounts)));
+    }
+    serde_json::json!({ "type": "paragraph", "content": content })
+}
+
+fn adf_list(kind: &str, items: &[Vec<Inline>], accounts: &HashMap<String, Account>) -> serde_json::Value {
+    let items: Vec<serde_json::Value> = items.iter()
+        .map(|item| serde_json::json!({
+            "type": "listItem",
+            "content": [adf_paragraph(std::slice::from_ref(item), accounts)],
+        }))
+        .collect();
+    serde_json::json!({ "type": kind, "content": items })
+}
+
+/// The blocks as an ADF document, with mentions of `accounts` linked to
+/// their users
+pub fn to_adf(blocks: &[Block], accounts: &HashMap<String, Account>) -> serde_json::Value {
+    let content: Vec<serde_json::Value> = blocks.iter()
+        .map(|block| match block {
+            Block::Paragraph(lines) => adf_paragraph(lines, accounts),
+            Block::Bullets(items) => adf_list("bulletList", items, accounts),
+            Block::Numbered(items) => adf_list("orderedList", items, accounts),
+            Block::Quote(lines) => serde_json::json!({
+                "type": "blockquote",
+                "content": [adf_paragraph(lines, accounts)],
+            }),
+            // ADF rejects empty text nodes
+            Block::Code(code) if code.is_empty() => serde_json::json!({ "type": "codeBlock" }),
+            Block::Code(code) => serde_json::json!({
+                "type": "codeBlock",
+                "content": [{ "type": "text", "text": code }],
+            }),
+        })
+        .collect();
+    serde_json::json!({ "type": "doc", "version": 1, "content": content })
+}
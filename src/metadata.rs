@@ -0,0 +1,174 @@
+// What a project offers, such as its issue types, the priorities, who can
+// be assigned and the workflow's transitions, kept in the data dir for
+// `[metadata] ttl_hours` so lookups answer at once, and from the last copy
+// while JIRA can't be reached. Ctrl+R on the board throws it all away.
+use crate::activity::{self, Kind};
+use crate::config::Config;
+use crate::jira_api::{self, Account, Transition};
+use crate::permissions;
+use crate::users;
+use chrono::{DateTime, Duration, Local};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectMeta {
+    pub fetched: String,  // RFC 3339
+    pub issue_types: Vec<String>,
+    pub priorities: Vec<String>,
+    pub users: Vec<Account>,  // Assignable ones
+    // Transitions out of each "issue type/status", learned as tickets are
+    // moved, with when each was learned
+    #[serde(default)]
+    pub transitions: BTreeMap<String, (String, Vec<Transition>)>,
+    // Which of permissions::CHECKED you have here; None until asked
+    #[serde(default)]
+    pub permissions: Option<Vec<String>>,
+}
+
+fn metadata_dir() -> Option<PathBuf> {
+    Some(Config::data_dir()?.join("metadata"))
+}
+
+fn project_path(project: &str) -> Option<PathBuf> {
+    Some(metadata_dir()?.join(format!("{}.json", project)))
+}
+
+/// The project part of a ticket key, e.g. "PROJ" for PROJ-123
+pub fn project_of(key: &str) -> &str {
+    key.rsplit_once('-').map_or(key, |(project, _)| project)
+}
+
+/// Whether something fetched at `time` is still within the TTL
+pub fn fresh(config: &Config, time: &str) -> bool {
+    DateTime::parse_from_rfc3339(time)
+        .is_ok_and(|time| Local::now() - time.with_timezone(&Local) < Duration::hours(config.metadata.ttl_hours as i64))
+}
+
+/// The project's cached copy, however old, without asking JIRA
+pub fn load(project: &str) -> Option<ProjectMeta> {
+    let contents = fs::read_to_string(project_path(project)?).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Keep `meta` as the project's copy. Best effort: a read-only data dir
+/// only means fetching again next time.
+pub fn save(project: &str, meta: &ProjectMeta) {
+    let Some(path) = project_path(project) else { return };
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    if let Ok(json) = serde_json::to_string_pretty(meta) {
+        let _ = fs::write(path, json);
+    }
+}
+
+/// The project's metadata, fetched again once it's older than the TTL.
+/// When that fails, the old copy still answers.
+pub fn project(config: &Config, project: &str) -> Result<ProjectMeta, Box<dyn Error>> {
+    let cached = load(project);
+    if let Some(ref meta) = cached
+        && fresh(config, &meta.fetched)
+    {
+        return Ok(meta.clone());
+    }
+    let fetched = jira_api::fetch_issue_types(config, project).and_then(|issue_types| {
+        Ok(ProjectMeta {
+            fetched: Local::now().to_rfc3339(),
+            issue_types,
+            priorities: jira_api::fetch_priorities(config)?,
+            users: jira_api::fetch_assignable_users(config, project)?,
+            transitions: cached.as_ref().map(|meta| meta.transitions.clone()).unwrap_or_default(),
+            permissions: jira_api::fetch_my_permissions(config, project, &permissions::CHECKED).ok(),
+        })
+    });
+    match (fetched, cached) {
+        (Ok(meta), _) => {
+            save(project, &meta);
+            Ok(meta)
+        }
+        (Err(e), Some(meta)) => {
+            activity::record(Kind::Error, format!("Using cached metadata of {}: {}", project, e.to_string().lines().next().unwrap_or_default()));
+            Ok(meta)
+        }
+        (Err(e), None) => Err(e),
+    }
+}
+
+/// Transitions out of the ticket's status, as JIRA last listed them for
+/// its issue type and status
+pub fn transitions(config: &Config, key: &str, issue_type: &str, status: &str) -> Result<Vec<Transition>, Box<dyn Error>> {
+    let project = project_of(key);
+    let from = format!("{}/{}", issue_type, status);
+    let mut meta = load(project);
+    let known = meta.as_ref().and_then(|meta| meta.transitions.get(&from));
+    if let Some((learned, transitions)) = known
+        && fresh(config, learned)
+    {
+        return Ok(transitions.clone());
+    }
+    match jira_api::fetch_transitions(config, key) {
+        Ok(transitions) => {
+            // Worth keeping even before the rest of the project is fetched
+            let meta = meta.get_or_insert_with(|| ProjectMeta {
+                fetched: String::new(),
+                issue_types: Vec::new(),
+                priorities: Vec::new(),
+                users: Vec::new(),
+                transitions: BTreeMap::new(),
+                permissions: None,
+            });
+            meta.transitions.insert(from, (Local::now().to_rfc3339(), transitions.clone()));
+            save(project, meta);
+            Ok(transitions)
+        }
+        Err(e) => match known {
+            Some((_, transitions)) => Ok(transitions.clone()),
+            None => Err(e),
+        },
+    }
+}
+
+/// Someone who can be assigned tickets in `project`, by account id or a
+/// display name only they have in the cached list or the user directory,
+/// else by a search of all users
+pub fn find_user(config: &Config, project: &str, query: &str) -> Result<Account, Box<dyn Error>> {
+    let assignable = self::project(config, project).map(|meta| meta.users).unwrap_or_default();
+    let mut matching = assignable.into_iter().filter(|user| user.account_id == query || user.name.eq_ignore_ascii_case(query));
+    match (matching.next(), matching.next()) {
+        (Some(account), None) => Ok(account),
+        _ => match users::account_id(query) {
+            Some(account_id) => {
+                let name = users::display_name(&account_id).unwrap_or_else(|| query.to_string());
+                Ok(Account { account_id, name })
+            }
+            None => jira_api::find_user(config, query),
+        },
+    }
+}
+
+/// An error naming the project's issue types when `issue_type` isn't one
+/// of them. Unknown when the metadata can't be had, so it passes.
+pub fn check_issue_type(config: &Config, project: &str, issue_type: &str) -> Result<(), String> {
+    let Ok(meta) = self::project(config, project) else { return Ok(()) };
+    if meta.issue_types.is_empty() || meta.issue_types.iter().any(|t| t.eq_ignore_ascii_case(issue_type)) {
+        return Ok(());
+    }
+    Err(format!("{} has no issue type '{}' (it has {})", project, issue_type, meta.issue_types.join(", ")))
+}
+
+/// Forget all cached metadata, so the next lookup fetches it again.
+/// Returns how many projects were cached.
+#[cfg(feature = "tui")]
+pub fn reload() -> Result<usize, String> {
+    let Some(dir) = metadata_dir() else { return Ok(0) };
+    let count = fs::read_dir(&dir).map_or(0, |entries| entries.count());
+    match fs::remove_dir_all(&dir) {
+        Ok(()) => Ok(count),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(0),
+        Err(e) => Err(crate::i18n::tf("status.clear_failed", &[("path", &dir.display()), ("error", &e)])),
+    }
+}
@@ -1,12 +1,19 @@
+use crate::config::{DisplayConfig, GroupBy, IconSet};
+use crate::i18n;
+use chrono::{DateTime, Utc};
+#[cfg(feature = "tui")]
+use chrono::{Local, NaiveDate};
 use std::collections::BTreeMap;
+use std::sync::OnceLock;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Ticket {
     pub key: String,
     pub ticket_type: TicketType,
     pub summary: String,
     pub status: String,
     pub assignee: String,
+    pub assignee_id: Option<String>,  // Account id, which tells apart people sharing a display name
     // Extended fields (fetched on demand)
     pub description: Option<String>,
     pub priority: Option<String>,
@@ -14,7 +21,165 @@ pub struct Ticket {
     pub created: Option<String>,
     pub updated: Option<String>,
     pub labels: Option<Vec<String>>,
+    pub due_date: Option<String>,  // e.g. "2025-03-20"
     pub comments: Option<Vec<Comment>>,
+    pub links: Option<Vec<IssueLink>>,
+    pub checklist: Vec<ChecklistItem>,  // Definition of done and other checklists, detail view only
+    pub urls: Vec<String>,  // Link targets referenced by `[n]` markers in text
+    pub remote_links: Option<Vec<RemoteLink>>,
+    pub parent: Option<ParentRef>,
+    pub components: Vec<String>,
+    pub fix_versions: Vec<String>,
+    pub status_category: Option<StatusCategory>,
+    pub resolution: Option<String>,
+    pub time_tracking: Option<TimeTracking>,
+    pub story_points: Option<f64>,
+    pub flagged: bool,
+    pub incident: bool,  // Matches `[incidents]`, so it leads its lane
+    pub watch_count: Option<u64>,
+    pub vote_count: Option<u64>,
+    pub watchers: Option<Vec<String>>,  // Fetched on demand in the detail view
+    pub service_desk: bool,  // Service Management request, whose comments are internal or customer-visible
+    pub reaction_container: Option<String>,  // The project, to the reactions service
+    pub request_type: Option<String>,  // Service Management request type
+    pub slas: Vec<Sla>,
+    pub sprint: Option<Sprint>,
+    pub queries: Vec<String>,  // Names of the configured queries that matched, when there are several
+}
+
+#[derive(Debug, Clone)]
+pub struct Sprint {
+    pub name: String,
+    pub state: SprintState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SprintState {
+    Active,
+    Future,
+    Closed,
+}
+
+impl SprintState {
+    pub fn from_name(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "active" => SprintState::Active,
+            "future" => SprintState::Future,
+            _ => SprintState::Closed,
+        }
+    }
+    
+    #[cfg(feature = "tui")]
+    pub fn color(&self) -> ratatui::style::Color {
+        use ratatui::style::Color;
+        match self {
+            SprintState::Active => Color::Green,
+            SprintState::Future => Color::Blue,
+            SprintState::Closed => Color::DarkGray,
+        }
+    }
+}
+
+/// Estimates as JIRA formats them ("1d 2h"), plus raw seconds for math
+#[derive(Debug, Clone, Default)]
+pub struct TimeTracking {
+    pub original_estimate: Option<String>,
+    pub remaining_estimate: Option<String>,
+    pub time_spent: Option<String>,
+    pub original_estimate_seconds: Option<u64>,
+    pub remaining_estimate_seconds: Option<u64>,
+    pub time_spent_seconds: Option<u64>,
+}
+
+impl TimeTracking {
+    pub fn is_empty(&self) -> bool {
+        self.original_estimate_seconds.is_none()
+            && self.remaining_estimate_seconds.is_none()
+            && self.time_spent_seconds.is_none()
+    }
+    
+    /// Compact spent/estimate badge for cards, e.g. "2h/1d"
+    pub fn badge(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let spent = self.time_spent.as_deref().unwrap_or("0m");
+        Some(match self.original_estimate {
+            Some(ref estimate) => format!("{}/{}", spent, estimate),
+            None => spent.to_string(),
+        })
+    }
+    
+    /// Fraction of work logged relative to logged + remaining
+    pub fn progress(&self) -> Option<f64> {
+        let spent = self.time_spent_seconds.unwrap_or(0);
+        let remaining = self.remaining_estimate_seconds.unwrap_or(0);
+        if spent + remaining == 0 {
+            None
+        } else {
+            Some(spent as f64 / (spent + remaining) as f64)
+        }
+    }
+}
+
+/// A Service Management SLA clock, as of the last fetch
+#[derive(Debug, Clone)]
+pub struct Sla {
+    pub name: String,  // e.g. "Time to first response"
+    pub breach_at: Option<DateTime<Utc>>,  // When a running clock runs out
+    pub remaining: Option<String>,  // JIRA's own "2h 5m", for clocks that aren't running
+    pub paused: bool,
+    pub breached: bool,
+    pub done: bool,  // The clock stopped, e.g. the first response was sent
+}
+
+impl Sla {
+    /// Initials of the name, e.g. "TFR" for "Time to first response"
+    #[cfg(feature = "tui")]
+    pub fn short_name(&self) -> String {
+        self.name.split_whitespace()
+            .filter(|word| !matches!(word.to_lowercase().as_str(), "to" | "of" | "the"))
+            .filter_map(|word| word.chars().next())
+            .flat_map(char::to_uppercase)
+            .collect()
+    }
+    
+    /// Time left on the clock ("1h 20m"), or how far past it is ("-15m"),
+    /// and whether it's breached. Counts down from `breach_at` between
+    /// fetches.
+    pub fn countdown(&self, now: DateTime<Utc>) -> (String, bool) {
+        let Some(breach_at) = self.breach_at.filter(|_| !self.paused && !self.done) else {
+            return (self.remaining.clone().unwrap_or_default(), self.breached);
+        };
+        let minutes = (breach_at - now).num_minutes();
+        let (sign, minutes) = if minutes < 0 { ("-", -minutes) } else { ("", minutes) };
+        let text = match minutes {
+            m if m >= 24 * 60 => format!("{}{}d {}h", sign, m / (24 * 60), m % (24 * 60) / 60),
+            m if m >= 60 => format!("{}{}h {}m", sign, m / 60, m % 60),
+            m => format!("{}{}m", sign, m),
+        };
+        (text, self.breached || breach_at <= now)
+    }
+}
+
+/// JIRA's own bucketing of workflow statuses, independent of status names
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StatusCategory {
+    ToDo,
+    InProgress,
+    Done,
+}
+
+impl StatusCategory {
+    /// Parse the `statusCategory.key` returned by the API
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "new" => Some(StatusCategory::ToDo),
+            "indeterminate" => Some(StatusCategory::InProgress),
+            "done" => Some(StatusCategory::Done),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -22,18 +187,201 @@ pub struct Comment {
     pub author: String,
     pub created: String,
     pub body: String,
+    pub public: Option<bool>,  // Whether customers see it, on Service Management requests
+    pub id: String,
+    pub ari: Option<String>,  // Identifies the comment to the reactions service; None when it's unreachable
+    pub reactions: Vec<Reaction>,
 }
 
+/// An emoji reaction on a comment and how many people left it
 #[derive(Debug, Clone)]
+pub struct Reaction {
+    pub emoji: String,
+    pub count: u64,
+    pub mine: bool,  // The credentials' owner is among them
+}
+
+impl Comment {
+    // Split off the quote a reply opens with: `> ` lines, `bq. ` lines or a
+    // `{quote}` block. Returns the quoted lines and where the rest starts.
+    fn opening_quote(&self) -> (Vec<&str>, usize) {
+        let lines: Vec<&str> = self.body.lines().collect();
+        let mut quoted = Vec::new();
+        if let Some(first) = lines.first().and_then(|l| l.trim_start().strip_prefix("{quote}")) {
+            if let Some(inline) = first.strip_suffix("{quote}") {
+                return (vec![inline], 1);
+            }
+            quoted.push(first);
+            for (i, line) in lines.iter().enumerate().skip(1) {
+                match line.trim_start().strip_prefix("{quote}") {
+                    Some(_) => return (quoted, i + 1),
+                    None => quoted.push(line.strip_suffix("{quote}").unwrap_or(line)),
+                }
+            }
+            return (quoted, lines.len());
+        }
+        for line in &lines {
+            let line = line.trim_start();
+            match line.strip_prefix('>').or_else(|| line.strip_prefix("bq. ")) {
+                Some(rest) => quoted.push(rest.strip_prefix(' ').unwrap_or(rest)),
+                None => break,
+            }
+        }
+        let rest = quoted.len();
+        (quoted, rest)
+    }
+    
+    /// The comment's own words, without a quote it opens with
+    pub fn own_lines(&self) -> impl Iterator<Item = &str> {
+        self.body.lines().skip(self.opening_quote().1)
+    }
+    
+    // Whether `quote` is taken from this comment
+    #[cfg(feature = "tui")]
+    fn is_quoted_by(&self, quote: &str) -> bool {
+        self.own_lines().any(|line| line.contains(quote))
+    }
+}
+
+/// Comments in display order, each with its depth in a thread. JIRA has no
+/// replies as such, so a comment that opens by quoting an earlier one is
+/// taken as a reply and placed under it.
+#[cfg(feature = "tui")]
+pub fn comment_threads(comments: &[Comment]) -> Vec<(usize, usize)> {
+    let parents: Vec<Option<usize>> = comments.iter().enumerate()
+        .map(|(i, comment)| {
+            let (quoted, _) = comment.opening_quote();
+            let quote = quoted.iter().map(|l| l.trim()).find(|l| !l.is_empty())?;
+            (0..i).rev().find(|&j| comments[j].is_quoted_by(quote))
+        })
+        .collect();
+    
+    fn visit(index: usize, depth: usize, parents: &[Option<usize>], order: &mut Vec<(usize, usize)>) {
+        order.push((index, depth));
+        for (child, _) in parents.iter().enumerate().filter(|(_, parent)| **parent == Some(index)) {
+            visit(child, depth + 1, parents, order);
+        }
+    }
+    
+    let mut order = Vec::with_capacity(comments.len());
+    for (root, _) in parents.iter().enumerate().filter(|(_, parent)| parent.is_none()) {
+        visit(root, 0, &parents, &mut order);
+    }
+    order
+}
+
+#[derive(Debug, Clone)]
+pub struct IssueLink {
+    pub relation: String, // e.g. "blocks", "is blocked by"
+    pub key: String,
+    pub summary: String,
+    pub status: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChecklistItem {
+    pub text: String,
+    pub done: bool,
+    pub source: ChecklistSource,
+}
+
+// Where a checklist item is written back to
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChecklistSource {
+    Task(String),  // Action item in the description, by ADF local id
+    Field(usize),  // Line of the `[fields] checklist` field
+}
+
+// Parent issue (usually the epic) of a ticket
+#[derive(Debug, Clone)]
+pub struct ParentRef {
+    pub key: String,
+    pub summary: String,
+    pub is_epic: bool,
+    pub url_index: Option<usize>,  // Browse link in `Ticket::urls`, detail view only
+}
+
+// Confluence pages, Slack threads and other external links
+#[derive(Debug, Clone)]
+pub struct RemoteLink {
+    pub source: String,  // e.g. "Confluence", "Slack", "Web"
+    pub title: String,
+    pub url_index: usize,  // Position in `Ticket::urls`
+}
+
+#[derive(Debug, Clone, Default)]
 pub enum TicketType {
     Story,
     Bug,
+    #[default]
     Task,
     Epic,
 }
 
+impl Ticket {
+    /// One sentence describing the ticket, for screen readers
+    pub fn describe(&self) -> String {
+        let mut details = vec![self.ticket_type.name().to_string()];
+        if self.flagged {
+            details.push("flagged".to_string());
+        }
+        if self.assignee.is_empty() || self.assignee == "unassigned" {
+            details.push("unassigned".to_string());
+        } else {
+            details.push(format!("assigned to {}", self.assignee.split('@').next().unwrap_or(&self.assignee)));
+        }
+        for sla in self.slas.iter().filter(|s| !s.done) {
+            let (time, breached) = sla.countdown(Utc::now());
+            details.push(match (breached, sla.paused) {
+                (true, _) => format!("{} breached", sla.name.to_lowercase()),
+                (false, true) => format!("{} paused", sla.name.to_lowercase()),
+                (false, false) => format!("{} due in {}", sla.name.to_lowercase(), time),
+            });
+        }
+        format!("{}: {}. {}.", self.key, self.summary.trim_end_matches('.'), details.join(", "))
+    }
+    
+    /// A line to paste into Slack when talking about the ticket, e.g.
+    /// "*ABC-12*: Fix login (_In Progress_, Alice) https://…/browse/ABC-12".
+    /// The link is left off without a site to point to.
+    pub fn snippet(&self, site: Option<&str>) -> String {
+        let assignee = if self.assignee.is_empty() || self.assignee == "unassigned" {
+            "unassigned"
+        } else {
+            self.assignee.split('@').next().unwrap_or(&self.assignee)
+        };
+        let mut snippet = format!("*{}*: {} (_{}_, {})", self.key, self.summary.trim(), self.status, assignee);
+        if let Some(site) = site {
+            snippet.push_str(&format!(" {}/browse/{}", site.trim_end_matches('/'), self.key));
+        }
+        snippet
+    }
+    
+    /// Past its due date and not done yet
+    #[cfg(feature = "tui")]
+    pub fn is_overdue(&self) -> bool {
+        let due = self.due_date.as_deref().and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
+        due.is_some_and(|due| due < Local::now().date_naive()) && self.status_category != Some(StatusCategory::Done)
+    }
+}
+
+/// "1 ticket", "3 tickets"
+pub fn count_tickets(count: usize) -> String {
+    format!("{} ticket{}", count, if count == 1 { "" } else { "s" })
+}
+
+/// Story points of `tickets` added up to a tenth, or None when none of
+/// them is estimated. Whole numbers print without a decimal point.
+#[cfg(feature = "tui")]
+pub fn total_points(tickets: &[Ticket]) -> Option<f64> {
+    tickets.iter()
+        .filter_map(|t| t.story_points)
+        .reduce(|a, b| a + b)
+        .map(|points| (points * 10.0).round() / 10.0)
+}
+
 impl TicketType {
-    pub fn from_str(s: &str) -> Self {
+    pub fn from_name(s: &str) -> Self {
         match s.trim().to_lowercase().as_str() {
             "story" => TicketType::Story,
             "bug" => TicketType::Bug,
@@ -43,32 +391,157 @@ impl TicketType {
         }
     }
 
-    pub fn emoji(&self) -> &str {
+    pub fn name(&self) -> &'static str {
         match self {
-            TicketType::Bug => "🐛",
-            TicketType::Story => "📖",
-            TicketType::Task => "✓",
-            TicketType::Epic => "🎯",
+            TicketType::Bug => "bug",
+            TicketType::Story => "story",
+            TicketType::Task => "task",
+            TicketType::Epic => "epic",
+        }
+    }
+
+    pub fn icon(&self, icons: IconSet) -> &'static str {
+        match (icon_set(icons), self) {
+            (IconSet::Emoji, TicketType::Bug) => "🐛",
+            (IconSet::Emoji, TicketType::Story) => "📖",
+            (IconSet::Emoji, TicketType::Task) => "✓",
+            (IconSet::Emoji, TicketType::Epic) => "🎯",
+            (IconSet::Nerd, TicketType::Bug) => "\u{f188}",  // nf-fa-bug
+            (IconSet::Nerd, TicketType::Story) => "\u{f02d}",  // nf-fa-book
+            (IconSet::Nerd, TicketType::Task) => "\u{f046}",  // nf-fa-check_square_o
+            (IconSet::Nerd, TicketType::Epic) => "\u{f140}",  // nf-fa-bullseye
+            (IconSet::Ascii, TicketType::Bug) => "B",
+            (IconSet::Ascii, TicketType::Story) => "S",
+            (IconSet::Ascii, TicketType::Task) => "T",
+            (IconSet::Ascii, TicketType::Epic) => "E",
         }
     }
 }
 
+/// Legacy Windows consoles (ConHost) draw emoji at unpredictable widths,
+/// which knocks cards out of line, so they get plain symbols instead.
+/// `KANBARS_ASCII=1` or `=0` forces either way.
+pub fn plain_symbols() -> bool {
+    if a11y() {
+        return true;
+    }
+    static PLAIN: OnceLock<bool> = OnceLock::new();
+    *PLAIN.get_or_init(|| match std::env::var("KANBARS_ASCII") {
+        Ok(value) => value != "0",
+        // Windows Terminal, VS Code and ConEmu all identify themselves
+        Err(_) => cfg!(windows)
+            && ["WT_SESSION", "TERM_PROGRAM", "ConEmuANSI"].iter().all(|var| std::env::var_os(var).is_none()),
+    })
+}
+
+static A11Y: OnceLock<bool> = OnceLock::new();
+
+/// Switch to output for screen readers (`--a11y`): linear lists in words,
+/// without box drawing, emoji or meaning carried only by color. Call before
+/// anything is printed.
+pub fn enable_a11y() {
+    let _ = A11Y.set(true);
+}
+
+pub fn a11y() -> bool {
+    A11Y.get().copied().unwrap_or(false)
+}
+
+/// `emoji`, or `plain` where emoji don't render reliably
+pub fn symbol(emoji: &'static str, plain: &'static str) -> &'static str {
+    if plain_symbols() { plain } else { emoji }
+}
+
+// Lane name for tickets that aren't in any sprint
+pub const NO_SPRINT: &str = "Backlog";
+
+// Lane name for tickets without a component
+pub const NO_COMPONENT: &str = "No component";
+
 #[derive(Debug)]
 pub struct StatusGroups {
     pub groups: BTreeMap<String, Vec<Ticket>>,
+    pub group_by: GroupBy,
 }
 
 impl StatusGroups {
-    pub fn new() -> Self {
+    pub fn new(group_by: GroupBy) -> Self {
         StatusGroups {
             groups: BTreeMap::new(),
+            group_by,
         }
     }
     
+    /// Color for a lane, based on what the lanes are grouped by
+    #[cfg(feature = "tui")]
+    pub fn lane_color(&self, name: &str, tickets: &[Ticket]) -> ratatui::style::Color {
+        let first = tickets.first();
+        match self.group_by {
+            GroupBy::Status => get_status_color(name, first.and_then(|t| t.status_category)),
+            GroupBy::Sprint => first
+                .and_then(|t| t.sprint.as_ref())
+                .map(|s| s.state.color())
+                .unwrap_or(ratatui::style::Color::Cyan),
+            GroupBy::Component if name == NO_COMPONENT => ratatui::style::Color::DarkGray,
+            GroupBy::Component => ratatui::style::Color::LightBlue,
+        }
+    }
+    
+    fn lane_icon<'a>(&self, name: &str, tickets: &[Ticket], display: &'a DisplayConfig) -> &'a str {
+        let state = match self.group_by {
+            GroupBy::Status => return status_icon(name, tickets.first().and_then(|t| t.status_category), display),
+            GroupBy::Sprint => tickets.first().and_then(|t| t.sprint.as_ref()).map(|s| s.state),
+            GroupBy::Component => return match icon_set(display.icons) {
+                IconSet::Emoji => "🧩",
+                IconSet::Nerd => "\u{f12e}",  // nf-fa-puzzle_piece
+                IconSet::Ascii => "+",
+            },
+        };
+        match (icon_set(display.icons), state) {
+            (IconSet::Emoji, Some(SprintState::Active)) => "🏃",
+            (IconSet::Emoji, Some(SprintState::Future)) => "🔜",
+            (IconSet::Emoji, Some(SprintState::Closed)) => "🏁",
+            (IconSet::Emoji, None) => "📋",
+            (IconSet::Nerd, Some(SprintState::Active)) => "\u{f04b}",  // nf-fa-play
+            (IconSet::Nerd, Some(SprintState::Future)) => "\u{f017}",  // nf-fa-clock_o
+            (IconSet::Nerd, Some(SprintState::Closed)) => "\u{f11e}",  // nf-fa-flag_checkered
+            (IconSet::Nerd, None) => "\u{f0ca}",  // nf-fa-list_ul
+            (IconSet::Ascii, Some(SprintState::Active)) => ">",
+            (IconSet::Ascii, Some(SprintState::Future)) => "-",
+            (IconSet::Ascii, Some(SprintState::Closed)) => "#",
+            (IconSet::Ascii, None) => "*",
+        }
+    }
+    
+    /// Whether tickets on the board come from more than one sprint
+    #[cfg(feature = "tui")]
+    pub fn spans_multiple_sprints(&self) -> bool {
+        let mut sprints = self.groups.values()
+            .flatten()
+            .filter_map(|t| t.sprint.as_ref().map(|s| s.name.as_str()));
+        match sprints.next() {
+            Some(first) => sprints.any(|name| name != first),
+            None => false,
+        }
+    }
+    
+    /// Each ticket once, though with component lanes one can sit in several
+    #[cfg(feature = "tui")]
+    pub fn unique_tickets(&self) -> Vec<&Ticket> {
+        let mut seen = std::collections::HashSet::new();
+        self.groups.values().flatten().filter(|t| seen.insert(t.key.as_str())).collect()
+    }
+    
+    /// Apply `change` to every copy of the ticket
+    pub fn update(&mut self, key: &str, change: impl Fn(&mut Ticket)) {
+        self.groups.values_mut().flatten().filter(|t| t.key == key).for_each(change);
+    }
+    
     pub fn total_tickets(&self) -> usize {
         self.groups.values().map(|v| v.len()).sum()
     }
     
+    #[cfg(feature = "tui")]
     pub fn get_ticket_by_index(&self, global_index: usize) -> Option<&Ticket> {
         let mut current_index = 0;
         
@@ -82,38 +555,99 @@ impl StatusGroups {
         None
     }
     
-    pub fn from_tickets(mut tickets: Vec<Ticket>) -> Self {
-        let mut groups = StatusGroups::new();
+    /// Global index of the first ticket whose key number starts with
+    /// `digits`, else the first whose number contains them
+    #[cfg(feature = "tui")]
+    pub fn find_key(&self, digits: &str) -> Option<usize> {
+        let numbers: Vec<&str> = self.groups.values()
+            .flatten()
+            .map(|t| t.key.rsplit('-').next().unwrap_or(&t.key))
+            .collect();
+        numbers.iter().position(|n| n.starts_with(digits))
+            .or_else(|| numbers.iter().position(|n| n.contains(digits)))
+    }
+    
+    /// Name of the lane holding the ticket at `global_index`
+    #[cfg(feature = "tui")]
+    pub fn lane_of(&self, global_index: usize) -> Option<&str> {
+        let mut current_index = 0;
+        
+        for (lane, tickets) in self.groups.iter() {
+            if global_index < current_index + tickets.len() {
+                return Some(lane);
+            }
+            current_index += tickets.len();
+        }
+        
+        None
+    }
+    
+    pub fn from_tickets(mut tickets: Vec<Ticket>, group_by: GroupBy) -> Self {
+        let mut groups = StatusGroups::new(group_by);
         
-        // Sort tickets by status priority first
+        // Sort tickets by status priority first, with incidents pinned to
+        // the top of each lane
         tickets.sort_by(|a, b| {
-            let a_priority = get_status_priority(&a.status);
-            let b_priority = get_status_priority(&b.status);
-            a_priority.cmp(&b_priority)
+            let a_priority = get_status_priority(&a.status, a.status_category);
+            let b_priority = get_status_priority(&b.status, b.status_category);
+            a_priority.cmp(&b_priority).then(b.incident.cmp(&a.incident))
         });
         
-        // Group tickets by their actual status
+        // Group tickets by their actual status (or sprint, or component)
         for ticket in tickets {
+            let lane = match group_by {
+                GroupBy::Status => ticket.status.clone(),
+                GroupBy::Sprint => ticket.sprint.as_ref()
+                    .map(|s| s.name.clone())
+                    .unwrap_or_else(|| NO_SPRINT.to_string()),
+                GroupBy::Component => match ticket.components.split_last() {
+                    Some((last, others)) => {
+                        // A copy in every lane but the last
+                        for component in others {
+                            groups.groups.entry(component.clone()).or_default().push(ticket.clone());
+                        }
+                        last.clone()
+                    }
+                    None => NO_COMPONENT.to_string(),
+                },
+            };
             groups.groups
-                .entry(ticket.status.clone())
-                .or_insert_with(Vec::new)
+                .entry(lane)
+                .or_default()
                 .push(ticket);
         }
         
         groups
     }
     
-    pub fn print_simple(&self) {
+    /// The board as a list of sections in words, for screen readers
+    pub fn print_linear(&self) {
+        let lanes: Vec<_> = self.groups.iter().filter(|(_, tickets)| !tickets.is_empty()).collect();
+        if lanes.is_empty() {
+            println!("No tickets found.");
+            return;
+        }
+        
+        for (i, (lane, tickets)) in lanes.iter().enumerate() {
+            println!("Section {} of {}: {}, {}.", i + 1, lanes.len(), lane, count_tickets(tickets.len()));
+            for ticket in tickets.iter() {
+                println!("  {}", ticket.describe());
+            }
+            println!();
+        }
+    }
+    
+    pub fn print_simple(&self, display: &DisplayConfig) {
         if self.groups.is_empty() {
-            println!("No tickets found! 🎉");
+            println!("{}", i18n::t("board.empty"));
             return;
         }
         
         // Print each status group
         for (status, tickets) in &self.groups {
             if !tickets.is_empty() {
-                let emoji = get_status_emoji(status);
-                println!("{} {} ({})", emoji, status.to_uppercase(), tickets.len());
+                let icon = self.lane_icon(status, tickets, display);
+                println!("{} {} ({})", icon, status.to_uppercase(), tickets.len());
                 
                 for ticket in tickets {
                     let assignee = if !ticket.assignee.is_empty() && ticket.assignee != "unassigned" {
@@ -121,9 +655,10 @@ impl StatusGroups {
                     } else {
                         String::new()
                     };
-                    println!("  {} {}{} - {}", 
-                        ticket.ticket_type.emoji(), 
+                    println!("  {} {}{}{} - {}", 
+                        ticket.ticket_type.icon(display.icons), 
                         ticket.key, 
+                        if ticket.flagged { symbol(" 🚩", " !") } else { "" },
                         assignee,
                         ticket.summary
                     );
@@ -135,13 +670,14 @@ impl StatusGroups {
 }
 
 
-// Get a priority value for sorting statuses in logical workflow order
-fn get_status_priority(status: &str) -> u8 {
+// Get a priority value for sorting statuses in logical workflow order.
+// Known status names win; JIRA's status category covers custom names.
+fn get_status_priority(status: &str, category: Option<StatusCategory>) -> u8 {
     let status_lower = status.to_lowercase();
     
     // Priority 0-3: Todo-like statuses (leftmost)
     if status_lower.contains("backlog") { return 0; }
-    if status_lower.contains("todo") || status_lower == "to do" { return 1; }
+    if status_lower.contains("todo") || status_lower.contains("to do") { return 1; }
     if status_lower.contains("open") || status_lower.contains("new") { return 2; }
     if status_lower.contains("ready for development") || status_lower.contains("ready to start") { return 3; }
     
@@ -164,37 +700,92 @@ fn get_status_priority(status: &str) -> u8 {
     if status_lower.contains("shipped") || status_lower.contains("deployed") { return 33; }
     if status_lower.contains("complete") { return 34; }
     
-    // Unknown statuses go in the middle
-    return 15;
+    match category {
+        Some(StatusCategory::ToDo) => 4,
+        Some(StatusCategory::InProgress) => 13,
+        Some(StatusCategory::Done) => 35,
+        // Unknown statuses go in the middle
+        None => 15,
+    }
 }
 
-// Get an appropriate emoji for a status
-fn get_status_emoji(status: &str) -> &str {
-    let status_lower = status.to_lowercase();
-    
-    if status_lower.contains("done") || status_lower.contains("closed") || 
-       status_lower.contains("resolved") || status_lower.contains("complete") {
-        return "✅";
-    }
-    if status_lower.contains("progress") || status_lower.contains("development") || 
-       status_lower.contains("coding") || status_lower.contains("ship") {
-        return "🚀";
-    }
-    if status_lower.contains("review") || status_lower.contains("testing") || 
-       status_lower.contains("qa") || status_lower.contains("verification") {
-        return "🔍";
-    }
-    if status_lower.contains("todo") || status_lower.contains("backlog") || 
-       status_lower == "to do" || status_lower.contains("open") {
-        return "📋";
+// What a status means on the board, which decides its icon
+#[derive(Debug, Clone, Copy)]
+enum StatusKind {
+    ToDo,
+    InProgress,
+    Review,
+    Done,
+    Unknown,
+}
+
+impl StatusKind {
+    // Guess from the name; JIRA's status category covers custom names
+    fn of(status: &str, category: Option<StatusCategory>) -> Self {
+        let status_lower = status.to_lowercase();
+        
+        if status_lower.contains("done") || status_lower.contains("closed") || 
+           status_lower.contains("resolved") || status_lower.contains("complete") {
+            return StatusKind::Done;
+        }
+        if status_lower.contains("progress") || status_lower.contains("development") || 
+           status_lower.contains("coding") || status_lower.contains("ship") {
+            return StatusKind::InProgress;
+        }
+        if status_lower.contains("review") || status_lower.contains("testing") || 
+           status_lower.contains("qa") || status_lower.contains("verification") {
+            return StatusKind::Review;
+        }
+        if status_lower.contains("todo") || status_lower.contains("backlog") || 
+           status_lower == "to do" || status_lower.contains("open") {
+            return StatusKind::ToDo;
+        }
+        
+        match category {
+            Some(StatusCategory::ToDo) => StatusKind::ToDo,
+            Some(StatusCategory::InProgress) => StatusKind::InProgress,
+            Some(StatusCategory::Done) => StatusKind::Done,
+            None => StatusKind::Unknown,
+        }
     }
     
-    // Default emoji for unknown statuses
-    "📌"
+    fn icon(self, icons: IconSet) -> &'static str {
+        match (icon_set(icons), self) {
+            (IconSet::Emoji, StatusKind::ToDo) => "📋",
+            (IconSet::Emoji, StatusKind::InProgress) => "🚀",
+            (IconSet::Emoji, StatusKind::Review) => "🔍",
+            (IconSet::Emoji, StatusKind::Done) => "✅",
+            (IconSet::Emoji, StatusKind::Unknown) => "📌",
+            (IconSet::Nerd, StatusKind::ToDo) => "\u{f0ca}",  // nf-fa-list_ul
+            (IconSet::Nerd, StatusKind::InProgress) => "\u{f135}",  // nf-fa-rocket
+            (IconSet::Nerd, StatusKind::Review) => "\u{f002}",  // nf-fa-search
+            (IconSet::Nerd, StatusKind::Done) => "\u{f00c}",  // nf-fa-check
+            (IconSet::Nerd, StatusKind::Unknown) => "\u{f08d}",  // nf-fa-thumb_tack
+            (IconSet::Ascii, StatusKind::ToDo) => "*",
+            (IconSet::Ascii, StatusKind::InProgress) => ">",
+            (IconSet::Ascii, StatusKind::Review) => "?",
+            (IconSet::Ascii, StatusKind::Done) => "#",
+            (IconSet::Ascii, StatusKind::Unknown) => "-",
+        }
+    }
+}
+
+/// The configured icon set, falling back to ASCII where emoji don't render
+pub fn icon_set(icons: IconSet) -> IconSet {
+    if icons == IconSet::Emoji && plain_symbols() { IconSet::Ascii } else { icons }
+}
+
+/// Icon for a status: the configured one for that name, else a guess
+pub fn status_icon<'a>(status: &str, category: Option<StatusCategory>, display: &'a DisplayConfig) -> &'a str {
+    display.status_icons.iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(status))
+        .map(|(_, icon)| icon.as_str())
+        .unwrap_or_else(|| StatusKind::of(status, category).icon(display.icons))
 }
 
 // Get color for UI rendering
-pub fn get_status_color(status: &str) -> ratatui::style::Color {
+#[cfg(feature = "tui")]
+pub fn get_status_color(status: &str, category: Option<StatusCategory>) -> ratatui::style::Color {
     use ratatui::style::Color;
     let status_lower = status.to_lowercase();
     
@@ -215,6 +806,111 @@ pub fn get_status_color(status: &str) -> ratatui::style::Color {
         return Color::Cyan;
     }
     
-    // Default color for unknown statuses
-    Color::Blue
-}
\ No newline at end of file
+    match category {
+        Some(StatusCategory::ToDo) => Color::Cyan,
+        Some(StatusCategory::InProgress) => Color::Yellow,
+        Some(StatusCategory::Done) => Color::Green,
+        // Default color for unknown statuses
+        None => Color::Blue,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const CATEGORIES: [Option<StatusCategory>; 4] = [
+        None,
+        Some(StatusCategory::ToDo),
+        Some(StatusCategory::InProgress),
+        Some(StatusCategory::Done),
+    ];
+
+    fn category() -> impl Strategy<Value = Option<StatusCategory>> {
+        prop::sample::select(CATEGORIES.to_vec())
+    }
+
+    // Letters that can't spell any of the status keywords, so only the
+    // category decides
+    const NEUTRAL: &str = "[xyzjkm0-9 _/]{0,20}";
+
+    proptest! {
+        #[test]
+        fn any_status_sorts_somewhere_in_the_workflow(status in any::<String>(), category in category()) {
+            prop_assert!(get_status_priority(&status, category) <= 35);
+        }
+
+        #[test]
+        fn status_names_ignore_case(status in "[ -~]{0,30}", category in category()) {
+            prop_assert_eq!(
+                get_status_priority(&status.to_uppercase(), category),
+                get_status_priority(&status.to_lowercase(), category),
+            );
+        }
+
+        #[test]
+        fn unknown_names_follow_their_category(status in NEUTRAL) {
+            let todo = get_status_priority(&status, Some(StatusCategory::ToDo));
+            let doing = get_status_priority(&status, Some(StatusCategory::InProgress));
+            let done = get_status_priority(&status, Some(StatusCategory::Done));
+            prop_assert!(todo < doing && doing < done);
+            prop_assert!(todo < get_status_priority(&status, None));
+            prop_assert!(get_status_priority(&status, None) < done);
+        }
+
+        #[test]
+        fn known_names_beat_the_category(prefix in NEUTRAL, suffix in NEUTRAL, category in category()) {
+            let priority = |name: &str| get_status_priority(&format!("{}{}{}", prefix, name, suffix), category);
+            prop_assert_eq!(priority("Backlog"), 0);
+            prop_assert!(priority("To Do") < priority("In Progress"));
+            prop_assert!(priority("In Progress") < priority("In Review"));
+            prop_assert!(priority("In Review") < priority("Done"));
+            prop_assert!(priority("Done") < priority("Closed"));
+        }
+
+        #[test]
+        fn tickets_land_in_their_status_lane_with_incidents_first(
+            statuses in prop::collection::vec((NEUTRAL, category()), 1..6),
+            picks in prop::collection::vec((any::<prop::sample::Index>(), any::<bool>()), 1..30),
+        ) {
+            // Each status belongs to one category, as in JIRA
+            let tickets: Vec<Ticket> = picks.into_iter().enumerate()
+                .map(|(i, (pick, incident))| {
+                    let name = &pick.get(&statuses).0;
+                    let (status, status_category) = statuses.iter().find(|(s, _)| s == name).cloned().unwrap_or_default();
+                    Ticket { key: format!("T-{}", i), status, status_category, incident, ..Default::default() }
+                })
+                .collect();
+            let count = tickets.len();
+            let groups = StatusGroups::from_tickets(tickets, GroupBy::Status);
+            prop_assert_eq!(groups.total_tickets(), count);
+            for (lane, lane_tickets) in &groups.groups {
+                prop_assert!(lane_tickets.iter().all(|t| &t.status == lane));
+                let first_routine = lane_tickets.iter().position(|t| !t.incident).unwrap_or(lane_tickets.len());
+                prop_assert!(lane_tickets[first_routine..].iter().all(|t| !t.incident));
+            }
+        }
+    }
+
+    #[test]
+    fn snippets_name_the_ticket_for_slack() {
+        let mut ticket = Ticket {
+            key: "ABC-12".to_string(),
+            summary: "  Fix login — für 🦀 ".to_string(),
+            status: "In Progress".to_string(),
+            assignee: "alice@example.com".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            ticket.snippet(Some("https://acme.atlassian.net/")),
+            "*ABC-12*: Fix login — für 🦀 (_In Progress_, alice) https://acme.atlassian.net/browse/ABC-12",
+        );
+        ticket.assignee = "Alice Liddell".to_string();
+        assert_eq!(ticket.snippet(None), "*ABC-12*: Fix login — für 🦀 (_In Progress_, Alice Liddell)");
+        for nobody in ["", "unassigned"] {
+            ticket.assignee = nobody.to_string();
+            assert!(ticket.snippet(None).ends_with("(_In Progress_, unassigned)"));
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// What JIRA lets you do in each project, asked once through mypermissions
+// and kept with the project metadata, so an action you can't take is greyed
+// out with the reason instead of failing with a 403 after you've typed it.
+// The board asks in the background; until a project's answer is known,
+// everything is allowed.
+use crate::config::Config;
+use crate::i18n::{t, tf};
+use crate::metadata::{self, project_of};
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::thread;
+
+/// The permissions that are checked, by JIRA's keys
+pub const CHECKED: [&str; 6] = [
+    "EDIT_ISSUES",
+    "ADD_COMMENTS",
+    "TRANSITION_ISSUES",
+    "ASSIGN_ISSUES",
+    "CREATE_ISSUES",
+    "WORK_ON_ISSUES",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    Edit,
+    Comment,
+    Transition,
+    Assign,
+    Create,
+    LogWork,
+}
+
+impl Permission {
+    /// JIRA's key for it
+    pub fn key(self) -> &'static str {
+        match self {
+            Permission::Edit => "EDIT_ISSUES",
+            Permission::Comment => "ADD_COMMENTS",
+            Permission::Transition => "TRANSITION_ISSUES",
+            Permission::Assign => "ASSIGN_ISSUES",
+            Permission::Create => "CREATE_ISSUES",
+            Permission::LogWork => "WORK_ON_ISSUES",
+        }
+    }
+
+    // What it lets you do, and its name in JIRA's permission scheme
+    fn describe(self) -> (&'static str, &'static str) {
+        match self {
+            Permission::Edit => (t("permissions.edit"), "Edit Issues"),
+            Permission::Comment => (t("permissions.comment"), "Add Comments"),
+            Permission::Transition => (t("permissions.transition"), "Transition Issues"),
+            Permission::Assign => (t("permissions.assign"), "Assign Issues"),
+            Permission::Create => (t("permissions.create"), "Create Issues"),
+            Permission::LogWork => (t("permissions.log_work"), "Work On Issues"),
+        }
+    }
+}
+
+/// Why `project` doesn't allow `permission`
+pub fn denied(project: &str, permission: Permission) -> String {
+    let (doing, name) = permission.describe();
+    tf("permissions.denied", &[("action", &doing), ("project", &project), ("permission", &name)])
+}
+
+type Answer = (String, Option<Vec<String>>);
+
+/// Your permissions by project key, None where they aren't known (yet)
+#[derive(Debug)]
+pub struct Permissions {
+    known: HashMap<String, Option<Vec<String>>>,
+    // Answers of the background fetches, picked up by `poll`
+    sender: mpsc::Sender<Answer>,
+    answers: mpsc::Receiver<Answer>,
+}
+
+impl Default for Permissions {
+    fn default() -> Self {
+        let (sender, answers) = mpsc::channel();
+        Permissions { known: HashMap::new(), sender, answers }
+    }
+}
+
+impl Permissions {
+    /// Find out what you may do in `project`, once, without waiting on
+    /// JIRA: the cached answer counts right away, and a missing or stale
+    /// one is fetched in the background
+    pub fn learn(&mut self, config: &Config, project: &str) {
+        if self.known.contains_key(project) {
+            return;
+        }
+        let cached = metadata::load(project);
+        let fresh = cached.as_ref().is_some_and(|meta| meta.permissions.is_some() && metadata::fresh(config, &meta.fetched));
+        self.known.insert(project.to_string(), cached.and_then(|meta| meta.permissions));
+        if fresh {
+            return;
+        }
+        let sender = self.sender.clone();
+        let (config, project) = (config.clone(), project.to_string());
+        thread::spawn(move || {
+            let granted = metadata::project(&config, &project).ok().and_then(|meta| meta.permissions);
+            let _ = sender.send((project, granted));
+        });
+    }
+
+    /// Take in the answers fetched since the last call
+    pub fn poll(&mut self) {
+        while let Ok((project, granted)) = self.answers.try_recv() {
+            // A failed fetch keeps what the cache said
+            if granted.is_some() {
+                self.known.insert(project, granted);
+            }
+        }
+    }
+
+    /// Set what you may do in `project`, e.g. as already fetched
+    pub fn set(&mut self, project: &str, granted: Option<Vec<String>>) {
+        self.known.insert(project.to_string(), granted);
+    }
+
+    /// Whether `permission` is allowed on a ticket (or project) key; true
+    /// while it isn't known
+    pub fn allows(&self, key: &str, permission: Permission) -> bool {
+        match self.known.get(project_of(key)) {
+            Some(Some(granted)) => granted.iter().any(|p| p == permission.key()),
+            _ => true,
+        }
+    }
+
+    /// Ok, or why `permission` isn't allowed on `key`
+    pub fn check(&self, key: &str, permission: Permission) -> Result<(), String> {
+        if self.allows(key, permission) {
+            Ok(())
+        } else {
+            Err(denied(project_of(key), permission))
+        }
+    }
+
+    /// Forget them all, e.g. when the metadata is reloaded
+    pub fn clear(&mut self) {
+        self.known.clear();
+    }
+}
+
+/// The CLI's check, which waits on JIRA when the cache has no answer: Ok,
+/// or why `permission` isn't allowed on `key`. Passes when the permissions
+/// can't be had.
+pub fn check(config: &Config, key: &str, permission: Permission) -> Result<(), String> {
+    let project = project_of(key);
+    match metadata::project(config, project).ok().and_then(|meta| meta.permissions) {
+        Some(granted) if !granted.iter().any(|p| p == permission.key()) => Err(denied(project, permission)),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,173 @@
+// Filter builder for people who'd rather not write JQL: a handful of
+// fields composed into a query that can be tried on the board or saved as
+// a profile
+use crate::jql;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BuilderField {
+    Project,
+    Assignee,
+    StatusCategory,
+    Labels,
+    UpdatedWithin,
+}
+
+impl BuilderField {
+    pub const ALL: [BuilderField; 5] = [
+        BuilderField::Project,
+        BuilderField::Assignee,
+        BuilderField::StatusCategory,
+        BuilderField::Labels,
+        BuilderField::UpdatedWithin,
+    ];
+
+    pub fn label(&self) -> &str {
+        match self {
+            BuilderField::Project => "Project",
+            BuilderField::Assignee => "Assignee",
+            BuilderField::StatusCategory => "Status",
+            BuilderField::Labels => "Labels",
+            BuilderField::UpdatedWithin => "Updated within",
+        }
+    }
+
+    /// Hint shown next to an empty field
+    pub fn hint(&self) -> &str {
+        match self {
+            BuilderField::Project => "keys, comma separated",
+            BuilderField::Assignee => "me, none, or a name/email",
+            BuilderField::Labels => "comma separated",
+            BuilderField::StatusCategory | BuilderField::UpdatedWithin => "←→ to change",
+        }
+    }
+}
+
+// Choices for the fields picked with ←→, as (label, JQL clause)
+const STATUS_CHOICES: [(&str, &str); 5] = [
+    ("Any", ""),
+    ("Not done", "statusCategory != Done"),
+    ("To Do", "statusCategory = \"To Do\""),
+    ("In Progress", "statusCategory = \"In Progress\""),
+    ("Done", "statusCategory = Done"),
+];
+
+const UPDATED_CHOICES: [(&str, &str); 5] = [
+    ("Any time", ""),
+    ("1 day", "updated >= -1d"),
+    ("1 week", "updated >= -1w"),
+    ("2 weeks", "updated >= -2w"),
+    ("30 days", "updated >= -30d"),
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct QueryBuilder {
+    pub selected: usize,
+    pub project: String,
+    pub assignee: String,
+    pub status: usize,
+    pub labels: String,
+    pub updated: usize,
+    pub profile_name: Option<String>,  // Name being typed after Ctrl+S
+}
+
+impl QueryBuilder {
+    pub fn new() -> Self {
+        QueryBuilder {
+            assignee: "me".to_string(),
+            status: 1,
+            ..Default::default()
+        }
+    }
+
+    pub fn field(&self) -> BuilderField {
+        BuilderField::ALL[self.selected]
+    }
+
+    pub fn next_field(&mut self) {
+        self.selected = (self.selected + 1) % BuilderField::ALL.len();
+    }
+
+    pub fn prev_field(&mut self) {
+        self.selected = (self.selected + BuilderField::ALL.len() - 1) % BuilderField::ALL.len();
+    }
+
+    /// Current value of a field, as displayed
+    pub fn value(&self, field: BuilderField) -> &str {
+        match field {
+            BuilderField::Project => &self.project,
+            BuilderField::Assignee => &self.assignee,
+            BuilderField::StatusCategory => STATUS_CHOICES[self.status].0,
+            BuilderField::Labels => &self.labels,
+            BuilderField::UpdatedWithin => UPDATED_CHOICES[self.updated].0,
+        }
+    }
+
+    fn text_mut(&mut self) -> Option<&mut String> {
+        match self.field() {
+            BuilderField::Project => Some(&mut self.project),
+            BuilderField::Assignee => Some(&mut self.assignee),
+            BuilderField::Labels => Some(&mut self.labels),
+            BuilderField::StatusCategory | BuilderField::UpdatedWithin => None,
+        }
+    }
+
+    pub fn type_char(&mut self, c: char) {
+        if let Some(text) = self.text_mut() {
+            text.push(c);
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        if let Some(text) = self.text_mut() {
+            text.pop();
+        }
+    }
+
+    /// Step a choice field forwards or backwards
+    pub fn cycle(&mut self, forward: bool) {
+        let step = |index: usize, len: usize| if forward { (index + 1) % len } else { (index + len - 1) % len };
+        match self.field() {
+            BuilderField::StatusCategory => self.status = step(self.status, STATUS_CHOICES.len()),
+            BuilderField::UpdatedWithin => self.updated = step(self.updated, UPDATED_CHOICES.len()),
+            _ => {}
+        }
+    }
+
+    /// The composed query, or None until at least one filter is set
+    pub fn to_jql(&self) -> Option<String> {
+        let mut clauses = Vec::new();
+
+        let projects = split_list(&self.project);
+        if !projects.is_empty() {
+            clauses.push(jql::in_clause("project", &projects));
+        }
+
+        match self.assignee.trim() {
+            "" => {}
+            "me" => clauses.push("assignee = currentUser()".to_string()),
+            "none" => clauses.push("assignee is EMPTY".to_string()),
+            name => clauses.push(format!("assignee = {}", jql::quote(name))),
+        }
+
+        let labels = split_list(&self.labels);
+        if !labels.is_empty() {
+            clauses.push(jql::in_clause("labels", &labels));
+        }
+
+        for clause in [STATUS_CHOICES[self.status].1, UPDATED_CHOICES[self.updated].1] {
+            if !clause.is_empty() {
+                clauses.push(clause.to_string());
+            }
+        }
+
+        if clauses.is_empty() {
+            None
+        } else {
+            Some(format!("{} ORDER BY updated DESC", clauses.join(" AND ")))
+        }
+    }
+}
+
+fn split_list(text: &str) -> Vec<&str> {
+    text.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()).collect()
+}
@@ -0,0 +1,117 @@
+// JIRA Cloud rate limiting: remember the budget reported in response
+// headers so polling can back off before requests start failing, and turn
+// 429s into errors that say when to try again
+use crate::audit;
+use crate::fixtures::Fixture;
+use crate::telemetry;
+use reqwest::blocking::{RequestBuilder, Response};
+use reqwest::StatusCode;
+use std::error::Error;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Default)]
+struct State {
+    used_percent: Option<u8>,
+    retry_at: Option<Instant>,
+}
+
+static STATE: Mutex<State> = Mutex::new(State { used_percent: None, retry_at: None });
+
+// Used when a 429 doesn't say how long to wait
+const DEFAULT_RETRY_SECONDS: u64 = 30;
+
+pub trait SendTracked {
+    /// `send()`, recording rate-limit headers and session statistics, and
+    /// failing on 429. Writes go in the audit log. Goes through the fixture
+    /// files with `--record` or `--replay`.
+    fn send_tracked(self) -> Result<Response, Box<dyn Error>>;
+}
+
+impl SendTracked for RequestBuilder {
+    fn send_tracked(self) -> Result<Response, Box<dyn Error>> {
+        let started = Instant::now();
+        let (client, request) = self.build_split();
+        let request = request?;
+        let fixture = Fixture::of(&request);
+        let response = match fixture {
+            Some(ref fixture) if fixture.replaying() => fixture.load()?,
+            _ => {
+                let call = audit::Call::of(&request);
+                let response = client.execute(request).inspect_err(|_| telemetry::record_unanswered());
+                if let Some(call) = call {
+                    call.record(&response.as_ref().map_or_else(|e| format!("failed: {}", e), |r| r.status().to_string()));
+                }
+                response?
+            }
+        };
+        record(&response);
+        let response = telemetry::measure(response, started)?;
+        let response = match fixture {
+            Some(ref fixture) => fixture.save(response)?,
+            None => response,
+        };
+
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            let wait = header_u64(&response, "Retry-After").unwrap_or(DEFAULT_RETRY_SECONDS);
+            if let Ok(mut state) = STATE.lock() {
+                state.retry_at = Some(Instant::now() + Duration::from_secs(wait));
+            }
+            return Err(format!("Rate limited by JIRA, retrying in {}s", wait).into());
+        }
+        Ok(response)
+    }
+}
+
+fn record(response: &Response) {
+    let limit = header_u64(response, "X-RateLimit-Limit");
+    let remaining = header_u64(response, "X-RateLimit-Remaining");
+    let near_limit = response.headers()
+        .get("X-RateLimit-NearLimit")
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.eq_ignore_ascii_case("true"));
+
+    let used = match (limit, remaining) {
+        (Some(limit), Some(remaining)) if limit > 0 => {
+            Some((100 - remaining.min(limit) * 100 / limit) as u8)
+        }
+        // Some responses only flag that the budget is nearly spent
+        _ if near_limit => Some(80),
+        _ => None,
+    };
+
+    if let Ok(mut state) = STATE.lock() {
+        if used.is_some() {
+            state.used_percent = used;
+        }
+        if response.status().is_success() {
+            state.retry_at = None;
+        }
+    }
+}
+
+fn header_u64(response: &Response, name: &str) -> Option<u64> {
+    response.headers().get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// Share of the API budget spent, as last reported by JIRA
+pub fn used_percent() -> Option<u8> {
+    STATE.lock().ok()?.used_percent
+}
+
+/// Time left before JIRA accepts requests again after a 429
+#[cfg(feature = "tui")]
+pub fn retry_after() -> Option<Duration> {
+    let retry_at = STATE.lock().ok()?.retry_at?;
+    Some(retry_at.saturating_duration_since(Instant::now()))
+}
+
+/// How much to stretch polling intervals as the budget runs low
+#[cfg(feature = "tui")]
+pub fn backoff_factor() -> u32 {
+    match used_percent() {
+        Some(used) if used >= 95 => 4,
+        Some(used) if used >= 80 => 2,
+        _ => 1,
+    }
+}
@@ -0,0 +1,117 @@
+// The board cut by fix version: each release with how much of the board's
+// work in it is done, and when it's due. Release dates come from the
+// projects' versions lists; progress counts the board's own tickets.
+use crate::config::Config;
+use crate::jira_api;
+use crate::jql;
+use crate::model::{StatusCategory, Ticket};
+use chrono::NaiveDate;
+use std::collections::BTreeSet;
+
+#[derive(Debug, Default)]
+pub struct Releases {
+    pub versions: Vec<Release>,
+    pub selected: usize,
+    pub error: Option<String>,  // Why a project's versions couldn't be loaded
+}
+
+#[derive(Debug, Clone)]
+pub struct Release {
+    pub name: Option<String>,  // None for tickets without a fix version
+    pub release_date: Option<NaiveDate>,
+    pub released: bool,
+    pub overdue: bool,
+    pub done: usize,
+    pub total: usize,
+}
+
+impl Release {
+    fn new(name: Option<String>) -> Self {
+        Release { name, release_date: None, released: false, overdue: false, done: 0, total: 0 }
+    }
+
+    /// (label, JQL clause) narrowing the board to this release
+    pub fn filter(&self) -> (String, String) {
+        match self.name {
+            Some(ref name) => (name.clone(), jql::in_clause("fixVersion", &[name])),
+            None => ("no fix version".to_string(), "fixVersion is EMPTY".to_string()),
+        }
+    }
+}
+
+impl Releases {
+    /// Unreleased versions of the projects on the board, plus any release
+    /// its tickets are in, soonest first
+    pub fn load<'a>(config: &Config, tickets: impl Iterator<Item = &'a Ticket> + Clone) -> Self {
+        let projects: BTreeSet<&str> = tickets.clone()
+            .filter_map(|t| t.key.rsplit_once('-').map(|(project, _)| project))
+            .collect();
+
+        let mut releases = Releases::default();
+        for project in projects {
+            match jira_api::fetch_versions(config, project) {
+                Ok(versions) => {
+                    for version in versions {
+                        if releases.versions.iter().any(|r| r.name.as_ref() == Some(&version.name)) {
+                            continue;
+                        }
+                        releases.versions.push(Release {
+                            release_date: version.release_date,
+                            released: version.released,
+                            overdue: version.overdue,
+                            ..Release::new(Some(version.name))
+                        });
+                    }
+                }
+                Err(e) => releases.error = Some(e.to_string().lines().next().unwrap_or_default().to_string()),
+            }
+        }
+
+        let mut unversioned = Release::new(None);
+        for ticket in tickets {
+            let done = ticket.status_category == Some(StatusCategory::Done);
+            let count = |release: &mut Release| {
+                release.total += 1;
+                release.done += usize::from(done);
+            };
+            if ticket.fix_versions.is_empty() {
+                count(&mut unversioned);
+            }
+            for name in &ticket.fix_versions {
+                match releases.versions.iter_mut().find(|r| r.name.as_ref() == Some(name)) {
+                    Some(release) => count(release),
+                    None => {
+                        // A version of a project that failed to load
+                        let mut release = Release::new(Some(name.clone()));
+                        count(&mut release);
+                        releases.versions.push(release);
+                    }
+                }
+            }
+        }
+
+        // Shipped releases only matter while the board still shows their work
+        releases.versions.retain(|r| !r.released || r.total > 0);
+        releases.versions.sort_by_key(|r| (r.released, r.release_date.is_none(), r.release_date));
+        if unversioned.total > 0 {
+            releases.versions.push(unversioned);
+        }
+        releases
+    }
+
+    pub fn selected_release(&self) -> Option<&Release> {
+        self.versions.get(self.selected)
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.versions.is_empty() {
+            self.selected = (self.selected + 1) % self.versions.len();
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        if !self.versions.is_empty() {
+            self.selected = (self.selected + self.versions.len() - 1) % self.versions.len();
+        }
+    }
+}
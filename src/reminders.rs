@@ -0,0 +1,107 @@
+// Follow-ups set on tickets ("ping me Thursday"), kept on this machine in
+// the data dir. Due ones show as a badge on the card and are announced when
+// kanbars starts.
+use crate::config::Config;
+use crate::i18n::{t, tf};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Reminder {
+    pub due: String,  // e.g. "2026-10-22"
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub note: String,
+}
+
+impl Reminder {
+    pub fn due_date(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(&self.due, "%Y-%m-%d").ok()
+    }
+
+    /// Due today or earlier
+    pub fn is_due(&self, today: NaiveDate) -> bool {
+        self.due_date().is_some_and(|due| due <= today)
+    }
+
+    /// "Thu 22 Oct", and the note if there is one
+    pub fn describe(&self) -> String {
+        let day = self.due_date().map_or_else(|| self.due.clone(), |due| due.format("%a %-d %b").to_string());
+        if self.note.is_empty() { day } else { format!("{}: {}", day, self.note) }
+    }
+}
+
+/// Reminders by ticket key
+#[derive(Debug, Default)]
+pub struct Reminders(BTreeMap<String, Reminder>);
+
+fn reminders_path() -> Option<PathBuf> {
+    Some(Config::data_dir()?.join("reminders.toml"))
+}
+
+impl Reminders {
+    pub fn load() -> Self {
+        Reminders(
+            reminders_path()
+                .and_then(|path| fs::read_to_string(path).ok())
+                .and_then(|contents| toml::from_str(&contents).ok())
+                .unwrap_or_default(),
+        )
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Reminder> {
+        self.0.get(key)
+    }
+
+    /// Set or clear the reminder on `key`, and save them all
+    pub fn set(&mut self, key: &str, reminder: Option<Reminder>) -> Result<(), String> {
+        match reminder {
+            Some(reminder) => self.0.insert(key.to_string(), reminder),
+            None => self.0.remove(key),
+        };
+        let path = reminders_path().ok_or(t("status.no_data_dir"))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        let contents = toml::to_string(&self.0).map_err(|e| e.to_string())?;
+        fs::write(&path, contents).map_err(|e| tf("status.save_failed", &[("path", &path.display()), ("error", &e)]))
+    }
+
+    /// Tickets with a reminder due by `today`
+    pub fn due(&self, today: NaiveDate) -> Vec<&str> {
+        self.0.iter().filter(|(_, r)| r.is_due(today)).map(|(key, _)| key.as_str()).collect()
+    }
+}
+
+/// Read a day and an optional note: "thu check the deploy", "tomorrow",
+/// "3d", "2w" or "2026-10-22". A weekday means the next one after today.
+pub fn parse(text: &str, today: NaiveDate) -> Result<Reminder, String> {
+    let text = text.trim();
+    let (when, note) = text.split_once(' ').unwrap_or((text, ""));
+    let lower = when.to_lowercase();
+    let due = match lower.as_str() {
+        "today" => Some(today),
+        "tomorrow" => today.succ_opt(),
+        _ => None,
+    }
+    .or_else(|| NaiveDate::parse_from_str(when, "%Y-%m-%d").ok())
+    .or_else(|| {
+        let day: Weekday = lower.parse().ok()?;
+        let ahead = (day.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+        Some(today + Duration::days(if ahead == 0 { 7 } else { ahead as i64 }))
+    })
+    .or_else(|| {
+        let unit = lower.chars().last()?;
+        let amount: i64 = lower[..lower.len() - unit.len_utf8()].parse().ok()?;
+        let ahead = match unit {
+            'd' => Duration::try_days(amount)?,
+            'w' => Duration::try_weeks(amount)?,
+            _ => return None,
+        };
+        today.checked_add_signed(ahead)
+    })
+    .ok_or_else(|| tf("status.bad_day", &[("text", &when)]))?;
+    Ok(Reminder { due: due.format("%Y-%m-%d").to_string(), note: note.trim().to_string() })
+}
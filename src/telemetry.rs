@@ -0,0 +1,106 @@
+// Session statistics about talking to JIRA: how many refreshes and
+// requests, how long they took, how much came back and how much failed.
+// Shown in the `M` panel, for tuning refresh intervals and spotting a slow
+// proxy.
+use crate::debug;
+use reqwest::blocking::Response;
+use std::error::Error;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Default)]
+pub struct Stats {
+    pub refreshes: u64,
+    pub failed_refreshes: u64,
+    pub refresh_time: Duration,  // Summed over all refreshes
+    pub requests: u64,
+    pub failed_requests: u64,  // Error statuses and requests that got no response
+    pub unanswered: u64,  // No response at all, so no latency either
+    pub request_time: Duration,  // Summed over requests that got a response
+    pub slowest_request: Duration,
+    pub bytes: u64,  // Response bodies, after decompression
+}
+
+impl Stats {
+    #[cfg(feature = "tui")]
+    pub fn average_refresh(&self) -> Option<Duration> {
+        self.refresh_time.checked_div(u32::try_from(self.refreshes).ok()?)
+    }
+
+    #[cfg(feature = "tui")]
+    pub fn average_request(&self) -> Option<Duration> {
+        let answered = self.requests - self.unanswered;
+        self.request_time.checked_div(u32::try_from(answered).ok()?)
+    }
+}
+
+static STATS: Mutex<Stats> = Mutex::new(Stats {
+    refreshes: 0,
+    failed_refreshes: 0,
+    refresh_time: Duration::ZERO,
+    requests: 0,
+    failed_requests: 0,
+    unanswered: 0,
+    request_time: Duration::ZERO,
+    slowest_request: Duration::ZERO,
+    bytes: 0,
+});
+static STARTED: OnceLock<Instant> = OnceLock::new();
+
+/// Start the session clock; later calls leave it alone
+pub fn start() {
+    STARTED.get_or_init(Instant::now);
+}
+
+/// How long the session has been running
+#[cfg(feature = "tui")]
+pub fn uptime() -> Duration {
+    STARTED.get().map_or(Duration::ZERO, Instant::elapsed)
+}
+
+pub fn stats() -> Stats {
+    STATS.lock().map(|stats| stats.clone()).unwrap_or_default()
+}
+
+/// Note a board refresh that took `took`
+#[cfg(feature = "tui")]
+pub fn record_refresh(took: Duration, ok: bool) {
+    if let Ok(mut stats) = STATS.lock() {
+        stats.refreshes += 1;
+        stats.failed_refreshes += u64::from(!ok);
+        stats.refresh_time += took;
+    }
+}
+
+/// Note a request that got no response at all
+pub fn record_unanswered() {
+    if let Ok(mut stats) = STATS.lock() {
+        stats.requests += 1;
+        stats.failed_requests += 1;
+        stats.unanswered += 1;
+    }
+}
+
+/// Read the whole body to time and size it, handing back an equivalent
+/// response to parse as usual
+pub fn measure(response: Response, started: Instant) -> Result<Response, Box<dyn Error>> {
+    let status = response.status();
+    let version = response.version();
+    let headers = response.headers().clone();
+    let url = response.url().to_string();
+    let body = response.bytes()?;
+    let took = started.elapsed();
+    debug::record(&url, status.as_u16(), &body);
+
+    if let Ok(mut stats) = STATS.lock() {
+        stats.requests += 1;
+        stats.failed_requests += u64::from(status.is_client_error() || status.is_server_error());
+        stats.request_time += took;
+        stats.slowest_request = stats.slowest_request.max(took);
+        stats.bytes += body.len() as u64;
+    }
+
+    let mut rebuilt = http::Response::builder().status(status).version(version).body(body)?;
+    *rebuilt.headers_mut() = headers;
+    Ok(Response::from(rebuilt))
+}
@@ -0,0 +1,223 @@
+// My logged work for one week, per ticket and day, so timesheets can be
+// checked and topped up without the Tempo web UI
+use crate::config::Config;
+use crate::i18n::{t, tf};
+use crate::jira_api::{self, Worklog};
+use chrono::{Datelike, Days, Local, NaiveDate, NaiveTime};
+
+// What JIRA counts as a day and a week of work, by default
+const DAY_SECONDS: u64 = 8 * 3600;
+const WEEK_SECONDS: u64 = 5 * DAY_SECONDS;
+// The most one entry logs: a whole calendar week
+const MAX_SECONDS: u64 = 7 * 24 * 3600;
+
+#[derive(Debug, Default)]
+pub struct Timesheet {
+    pub week_start: NaiveDate,  // Monday
+    pub rows: Vec<Row>,
+    pub selected: usize,
+    pub error: Option<String>,  // Why the worklogs couldn't be loaded
+    pub log_input: Option<String>,  // Time being typed to log on the selected ticket
+}
+
+/// A ticket's time for each day of the week, Monday first
+#[derive(Debug, Clone)]
+pub struct Row {
+    pub key: String,
+    pub summary: String,
+    pub days: [u64; 7],
+}
+
+impl Row {
+    pub fn total(&self) -> u64 {
+        self.days.iter().sum()
+    }
+}
+
+/// Monday of the week `date` falls in
+pub fn week_of(date: NaiveDate) -> NaiveDate {
+    date - Days::new(date.weekday().num_days_from_monday() as u64)
+}
+
+impl Timesheet {
+    /// Fetch the week starting `week_start`. `current` (key, summary) gets a
+    /// row even without time logged, so it's ready for a quick log.
+    pub fn load(config: &Config, week_start: NaiveDate, current: Option<(String, String)>) -> Self {
+        let week_end = week_start + Days::new(6);
+        let fetched = jira_api::fetch_myself(config)
+            .and_then(|me| jira_api::fetch_worklogs(config, &me, week_start, week_end));
+        let mut timesheet = match fetched {
+            Ok(worklogs) => Timesheet { week_start, rows: rows(week_start, &worklogs), ..Default::default() },
+            Err(e) => Timesheet {
+                week_start,
+                error: Some(e.to_string().lines().next().unwrap_or_default().to_string()),
+                ..Default::default()
+            },
+        };
+        if let Some((key, summary)) = current {
+            timesheet.selected = match timesheet.rows.iter().position(|row| row.key == key) {
+                Some(index) => index,
+                None => {
+                    timesheet.rows.push(Row { key, summary, days: [0; 7] });
+                    timesheet.rows.len() - 1
+                }
+            };
+        }
+        timesheet
+    }
+
+    /// The same week again, keeping the selection on the same ticket
+    pub fn reload(&mut self, config: &Config) {
+        let current = self.selected_row().map(|row| (row.key.clone(), row.summary.clone()));
+        *self = Timesheet::load(config, self.week_start, current);
+    }
+
+    /// Another week, `weeks` later (or earlier when negative)
+    pub fn shift(&mut self, config: &Config, weeks: i64) {
+        let week_start = self.week_start + chrono::Duration::weeks(weeks);
+        *self = Timesheet::load(config, week_start, None);
+    }
+
+    pub fn selected_row(&self) -> Option<&Row> {
+        self.rows.get(self.selected)
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.rows.is_empty() {
+            self.selected = (self.selected + 1) % self.rows.len();
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        if !self.rows.is_empty() {
+            self.selected = (self.selected + self.rows.len() - 1) % self.rows.len();
+        }
+    }
+
+    /// Totals per day, Monday first
+    pub fn day_totals(&self) -> [u64; 7] {
+        let mut totals = [0; 7];
+        for row in &self.rows {
+            for (total, seconds) in totals.iter_mut().zip(row.days) {
+                *total += seconds;
+            }
+        }
+        totals
+    }
+
+    pub fn total(&self) -> u64 {
+        self.rows.iter().map(Row::total).sum()
+    }
+
+    /// Log the typed time on the selected ticket: today when it's this week,
+    /// otherwise the Friday of the week shown
+    pub fn log(&mut self, config: &Config) -> Result<String, String> {
+        let input = self.log_input.take().unwrap_or_default();
+        let seconds = parse_duration(&input).ok_or_else(|| tf("status.bad_duration", &[("text", &input)]))?;
+        let row = self.selected_row().ok_or(t("status.no_ticket_selected"))?;
+        let key = row.key.clone();
+
+        let today = Local::now().date_naive();
+        let day = if week_of(today) == self.week_start { today } else { self.week_start + Days::new(4) };
+        // Start the entry so it ends now, or at the end of the working day
+        let started = if day == today {
+            Local::now() - chrono::Duration::seconds(seconds as i64)
+        } else {
+            day.and_time(NaiveTime::from_hms_opt(9, 0, 0).unwrap_or_default())
+                .and_local_timezone(Local)
+                .earliest()
+                .ok_or(t("status.no_local_time"))?
+        };
+        jira_api::add_worklog(config, &key, seconds, started)
+            .map_err(|e| e.to_string().lines().next().unwrap_or_default().to_string())?;
+        self.reload(config);
+        Ok(tf("status.logged", &[("time", &format_duration(seconds)), ("key", &key)]))
+    }
+}
+
+// Sum worklogs into one row per ticket, in the order first logged
+fn rows(week_start: NaiveDate, worklogs: &[Worklog]) -> Vec<Row> {
+    let mut rows: Vec<Row> = Vec::new();
+    for worklog in worklogs {
+        let day = (worklog.started.date_naive() - week_start).num_days();
+        let Ok(day) = usize::try_from(day) else { continue };
+        if day >= 7 {
+            continue;
+        }
+        let index = match rows.iter().position(|row| row.key == worklog.key) {
+            Some(index) => index,
+            None => {
+                rows.push(Row { key: worklog.key.clone(), summary: worklog.summary.clone(), days: [0; 7] });
+                rows.len() - 1
+            }
+        };
+        rows[index].days[day] += worklog.seconds;
+    }
+    rows
+}
+
+/// JIRA-style durations like "1h 30m", "2d" or "45m"; a bare number is
+/// hours. Nothing over a week is taken.
+pub fn parse_duration(text: &str) -> Option<u64> {
+    let text = text.trim();
+    if let Ok(hours) = text.parse::<f64>() {
+        return seconds_of(hours, 3600).filter(|&seconds| seconds > 0);
+    }
+    let mut seconds: u64 = 0;
+    for part in text.split_whitespace() {
+        let unit = part.chars().last()?;
+        let amount: f64 = part[..part.len() - unit.len_utf8()].parse().ok()?;
+        let scale = match unit.to_ascii_lowercase() {
+            'w' => WEEK_SECONDS,
+            'd' => DAY_SECONDS,
+            'h' => 3600,
+            'm' => 60,
+            _ => return None,
+        };
+        seconds = seconds.checked_add(seconds_of(amount, scale)?)?;
+    }
+    (seconds > 0 && seconds <= MAX_SECONDS).then_some(seconds)
+}
+
+// `amount` units of `scale` seconds, unless that's negative, not a number
+// or more than a week
+fn seconds_of(amount: f64, scale: u64) -> Option<u64> {
+    let seconds = (amount * scale as f64).round();
+    (seconds.is_finite() && (0.0..=MAX_SECONDS as f64).contains(&seconds)).then_some(seconds as u64)
+}
+
+/// Hours and minutes, e.g. "1h 30m"; days are left as hours since
+/// timesheets add up hours
+pub fn format_duration(seconds: u64) -> String {
+    let minutes = seconds.div_ceil(60);
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations_read_like_jira() {
+        assert_eq!(parse_duration("1h 30m"), Some(5400));
+        assert_eq!(parse_duration(" 45m "), Some(2700));
+        assert_eq!(parse_duration("2d"), Some(2 * DAY_SECONDS));
+        assert_eq!(parse_duration("1W"), Some(WEEK_SECONDS));
+        assert_eq!(parse_duration("0h 30m"), Some(1800));
+        assert_eq!(parse_duration("1.5"), Some(5400));
+        assert_eq!(parse_duration("168"), Some(MAX_SECONDS));
+    }
+
+    #[test]
+    fn nonsense_and_huge_durations_are_refused() {
+        for text in ["", "0", "0h", "-1", "-2h", "1x", "h", "inf", "-inf", "NaN", "infh", "1e13", "1e13m", "169", "5w", "1w 1w 1w 1w 1w"] {
+            assert_eq!(parse_duration(text), None, "{:?}", text);
+        }
+        let many = ["9999999999999999999w"; 3].join(" ");
+        assert_eq!(parse_duration(&many), None);
+    }
+}
@@ -0,0 +1,558 @@
+// Interactive terminal UI: boards, detail view and the event loop
+use crate::activity::{self, Kind};
+use crate::app::{self, Action, App};
+use crate::board::Board;
+use crate::catch_up::{self, CatchUp};
+use crate::cli::{Args, Focus};
+use crate::config::Config;
+use crate::desktop;
+use crate::doctor;
+use crate::i18n::{t, tf};
+use crate::ui::{draw_ui, AppState, UiMode, TICK};
+use crossterm::{
+    event::{self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::{Backend, CrosstermBackend},
+    Terminal,
+};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use chrono::Local;
+use std::{error::Error, io, path::PathBuf, sync::mpsc, time::{Duration, Instant}};
+
+// Least time between two boards' auto-refreshes
+const REFRESH_STAGGER: Duration = Duration::from_secs(5);
+
+// How often to check on boards loading in the background
+const LOADING_POLL: Duration = Duration::from_millis(100);
+
+// Pause in typing after which type-ahead digits are forgotten
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1500);
+
+// How long a status message stays in the footer
+const STATUS_TIMEOUT: Duration = Duration::from_secs(6);
+
+// Watches the config file's directory, since editors often replace the
+// file rather than write to it, and reports changes to the file itself
+struct ConfigWatcher {
+    _watcher: RecommendedWatcher,
+    events: mpsc::Receiver<notify::Result<notify::Event>>,
+    path: PathBuf,
+}
+
+impl ConfigWatcher {
+    fn new() -> Option<Self> {
+        let path = Config::config_path();
+        let dir = path.parent()?;
+        let (tx, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx).ok()?;
+        watcher.watch(dir, RecursiveMode::NonRecursive).ok()?;
+        Some(ConfigWatcher { _watcher: watcher, events, path })
+    }
+    
+    /// Whether the file changed since the last call; a burst of events from
+    /// one save counts once
+    fn changed(&self) -> bool {
+        self.events.try_iter()
+            .filter_map(Result::ok)
+            .filter(|event| !event.kind.is_access())
+            .any(|event| event.paths.iter().any(|p| p.file_name() == self.path.file_name()))
+    }
+}
+
+// Re-read the config and hand each board its new settings, adding or
+// dropping the split board as needed
+fn reload_config(args: &Args, boards: &mut Vec<Board>) -> Result<(), String> {
+    let configs = args.board_configs(Config::load()?)?;
+    boards.truncate(configs.len());
+    for (i, config) in configs.into_iter().enumerate() {
+        match boards.get_mut(i) {
+            Some(board) => board.reconfigure(config),
+            None => {
+                let mut board = Board::new(config);
+                board.start_loading();
+                boards.push(board);
+            }
+        }
+    }
+    Ok(())
+}
+
+// A wallboard's profiles, shown in turn in place of the main board. Each
+// keeps its own board, so showing it again doesn't start from scratch.
+struct Rotation {
+    parked: Vec<Board>,  // The profiles not on screen, next one first
+    period: Duration,
+    next_turn: Instant,
+}
+
+impl Rotation {
+    fn new(app: &mut App, period: Duration) -> Self {
+        app.state.wallboard = true;
+        // Wide lane titles, to read from across the room
+        app.state.zoom = 1;
+        let home = app.boards[0].config.clone();
+        let connected = app.state.setup_needed.is_empty();
+        let parked = home.profiles.keys()
+            .filter(|name| **name != home.query.name)
+            .filter_map(|name| home.with_profile(name))
+            .map(|config| {
+                let mut board = Board::new(config);
+                if connected {
+                    board.start_loading();
+                }
+                board
+            })
+            .collect();
+        let mut rotation = Rotation { parked, period, next_turn: Instant::now() + period };
+        for board in app.boards.iter_mut().chain(rotation.parked.iter_mut()) {
+            board.unattended = true;
+        }
+        rotation
+    }
+    
+    /// Keep the parked boards loading, and swap the next one in when its
+    /// turn comes, unless something is open over the board
+    fn turn(&mut self, app: &mut App) {
+        for board in self.parked.iter_mut() {
+            board.poll_loading();
+        }
+        if self.parked.is_empty() || Instant::now() < self.next_turn {
+            return;
+        }
+        self.next_turn = Instant::now() + self.period;
+        if app.state.mode() != UiMode::Board || app::typing(&app.state) {
+            return;
+        }
+        let mut board = self.parked.remove(0);
+        // Parked boards aren't refreshed, so catch up as it comes on screen
+        if board.loading.is_none() && board.next_refresh <= Instant::now() && app.state.setup_needed.is_empty() {
+            board.start_loading();
+        }
+        self.parked.push(std::mem::replace(&mut app.boards[0], board));
+        app.boards[0].selected_index = 0;
+    }
+}
+
+/// Run the UI until the user quits, loading each board in the background.
+/// Without credentials it opens on a setup screen instead, with the boards
+/// as last seen available read-only.
+pub fn run(args: &Args, configs: Vec<Config>) -> Result<(), Box<dyn Error>> {
+    let setup_needed = doctor::missing_credentials(&configs[0]);
+    let boards = configs.into_iter()
+        .map(|config| {
+            if !setup_needed.is_empty() {
+                return Board::from_cache(config);
+            }
+            let mut board = Board::new(config);
+            board.start_loading();
+            board
+        })
+        .collect();
+    
+    // Put the terminal back before a panic message prints, or it lands in
+    // the alternate screen (and on Windows, leaves the console unusable)
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), DisableBracketedPaste);
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
+        default_hook(info);
+    }));
+    
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    // Pastes arrive whole rather than as keys, where the console supports it
+    let _ = execute!(stdout, EnableBracketedPaste);
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let res = run_app(&mut terminal, args, boards, setup_needed);
+    if !args.demo {
+        catch_up::end_session();
+    }
+
+    disable_raw_mode()?;
+    let _ = execute!(terminal.backend_mut(), DisableBracketedPaste);
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    if let Err(err) = res {
+        println!("{:?}", err)
+    }
+
+    Ok(())
+}
+
+fn run_app<B: Backend>(
+    terminal: &mut Terminal<B>,
+    args: &Args,
+    boards: Vec<Board>,
+    setup_needed: Vec<&'static str>,
+) -> Result<(), Box<dyn Error>> {
+    // The demo board doesn't come from the config file, so edits can't apply
+    let mut config_watcher = if args.demo { None } else { ConfigWatcher::new() };
+    
+    let mut app = App::new(boards, setup_needed);
+    let mut rotation = args.wallboard.map(|seconds| Rotation::new(&mut app, Duration::from_secs(seconds)));
+    let mut next_tick = Instant::now() + TICK;
+    let mut status_shown: Option<(String, Instant)> = None;  // Message on screen and since when, to expire it
+    let mut last_input = Instant::now();
+    // Follow-ups that came due since the last start
+    let due = app.state.reminders.due(Local::now().date_naive());
+    if !due.is_empty() && rotation.is_none() {
+        let message = tf("status.follow_up", &[("keys", &due.join(", "))]);
+        activity::record(Kind::Change, &message);
+        if let Err(e) = desktop::notify("kanbars reminders", &message) {
+            activity::record(Kind::Error, format!("Desktop notification failed: {}", e));
+        }
+        app.state.status_message = Some(message);
+    }
+    
+    // These wait for the main board's first load
+    let live = app.state.setup_needed.is_empty() && !args.demo;
+    let mut learn_permissions = live;
+    let mut focus_mine = args.focus_mine.filter(|_| live);
+    let mut catch_up = catch_up::due()
+        .filter(|_| live && rotation.is_none() && app.boards[0].config.display.daily_summary);
+    
+    loop {
+        // Apply config edits without restarting; a broken edit keeps the
+        // running settings and says why
+        if config_watcher.as_ref().is_some_and(|w| w.changed()) {
+            let outcome = match reload_config(args, &mut app.boards) {
+                Ok(()) => {
+                    app.focused = app.focused.min(app.boards.len() - 1);
+                    app.state.display = app.boards[0].config.display.clone();
+                    let was_set_up = app.state.setup_needed.is_empty();
+                    app.state.setup_needed = doctor::missing_credentials(&app.boards[0].config);
+                    if !was_set_up && app.state.setup_needed.is_empty() {
+                        // Setup finished: swap the cached boards for live ones
+                        for board in app.boards.iter_mut() {
+                            board.start_loading();
+                        }
+                        app.state.pop_to_board();
+                    }
+                    Ok(t("status.config_reloaded").to_string())
+                }
+                Err(e) => Err(tf("status.config_not_reloaded", &[("error", &e.lines().collect::<Vec<_>>().join(" "))])),
+            };
+            app::report(&mut app.state, outcome);
+        }
+        
+        for board in app.boards.iter_mut() {
+            board.poll_loading();
+        }
+        app.state.permissions.poll();
+        if let Some(ref mut rotation) = rotation {
+            rotation.turn(&mut app);
+        }
+        if learn_permissions && app.boards[0].loading.is_none() {
+            learn_permissions = false;
+            app::learn_permissions(&mut app);
+        }
+        if let Some(focus) = focus_mine
+            && app.boards[0].loading.is_none()
+        {
+            focus_mine = None;
+            app::focus_mine(&mut app, focus == Focus::Open);
+        }
+        if let Some(since) = catch_up
+            && app.boards[0].loading.is_none()
+        {
+            catch_up = None;
+            let board = &app.boards[0];
+            let tickets: Vec<_> = board.columns.unique_tickets().into_iter().cloned().collect();
+            app.state.catch_up = Some(CatchUp::load(&board.config, &board.name, &tickets, since));
+            app.state.push(UiMode::CatchUp);
+        }
+        if app.state.type_ahead.as_ref().is_some_and(|(_, typed)| typed.elapsed() >= TYPE_AHEAD_TIMEOUT) {
+            app.state.type_ahead = None;
+        }
+        
+        // Nobody has touched the board in a while, e.g. it was left open
+        // overnight: stop polling JIRA until they're back. A wallboard has
+        // nobody to touch it.
+        let idle_after = Duration::from_secs(app.boards[0].config.refresh.idle_minutes * 60);
+        if !app.state.idle && !app.state.wallboard && !idle_after.is_zero() && last_input.elapsed() >= idle_after {
+            app.state.idle = true;
+            activity::record(Kind::Refresh, format!("Idle for {} min; auto-refresh paused", idle_after.as_secs() / 60));
+        }
+        
+        // Advance animations on a fixed beat, whether or not keys come in
+        let now = Instant::now();
+        if now >= next_tick {
+            app.state.tick += 1;
+            next_tick = (next_tick + TICK).max(now);
+        }
+        // Let status messages fade out; a new one starts its own clock
+        match (&app.state.status_message, &status_shown) {
+            (Some(message), Some((shown, since))) if message == shown => {
+                if since.elapsed() >= STATUS_TIMEOUT {
+                    app.state.status_message = None;
+                    status_shown = None;
+                }
+            }
+            (Some(message), _) => status_shown = Some((message.clone(), now)),
+            (None, _) => status_shown = None,
+        }
+        
+        // Draw UI with current state
+        terminal.draw(|f| draw_ui(f, &app.boards, app.focused, app.paused, &mut app.state))?;
+        
+        // Sleep until the next tick or the next board due, whichever is
+        // sooner, unless a key comes first
+        let timeout = next_tick.saturating_duration_since(Instant::now());
+        let timeout = if app.paused || app.state.idle {
+            timeout
+        } else {
+            // Each board keeps its own cycle
+            app.boards.iter()
+                .map(|board| board.next_refresh.saturating_duration_since(Instant::now()))
+                .fold(timeout, Duration::min)
+        };
+        let timeout = match rotation {
+            Some(ref rotation) => timeout.min(rotation.next_turn.saturating_duration_since(Instant::now())),
+            None => timeout,
+        };
+        // Redraw often while pages stream in
+        let loading = app.boards.iter().chain(rotation.iter().flat_map(|r| &r.parked)).any(|b| b.loading.is_some());
+        let timeout = if loading { timeout.min(LOADING_POLL) } else { timeout };
+        
+        if event::poll(timeout)? {
+            let event = event::read()?;
+            if matches!(event, Event::Key(_) | Event::Paste(_)) {
+                last_input = Instant::now();
+                // The key that wakes an idle board only wakes it, so a
+                // stray `q` doesn't quit; boards overdue refresh right away
+                if app.state.idle {
+                    app.state.idle = false;
+                    app.state.status_message = Some(t("status.awake").to_string());
+                    continue;
+                }
+            }
+            match event {
+                // Windows reports key releases too; only act on presses
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    let action = action_for(&app.state, key);
+                    let wrote_config = action == Action::WriteSampleConfig;
+                    app::reduce(&mut app, action);
+                    if app.quit {
+                        return Ok(());
+                    }
+                    // The config dir may not have existed to watch
+                    if wrote_config && config_watcher.is_none() && !args.demo {
+                        config_watcher = ConfigWatcher::new();
+                    }
+                }
+                Event::Paste(text) => app::paste(&mut app, &text),
+                _ => {}
+            }
+        } else if !app.paused && !app.state.idle && app.state.setup_needed.is_empty() {
+            // Auto-refresh the most overdue board, one at a time and spaced
+            // apart so split views never hit JIRA in the same instant
+            let now = Instant::now();
+            let due = (0..app.boards.len())
+                .filter(|&i| app.boards[i].loading.is_none() && app.boards[i].next_refresh <= now)
+                .min_by_key(|&i| app.boards[i].next_refresh);
+            if let Some(i) = due {
+                let recently_fetched = app.boards.iter().enumerate()
+                    .any(|(j, b)| j != i && now.duration_since(b.last_refresh) < REFRESH_STAGGER);
+                if recently_fetched {
+                    app.boards[i].next_refresh = now + REFRESH_STAGGER;
+                } else {
+                    let _ = app.boards[i].refresh_due();
+                }
+            }
+        }
+    }
+}
+
+// What `key` does on whatever is on top of the screen stack
+fn action_for(state: &AppState, key: KeyEvent) -> Action {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    // A field being typed in takes every character
+    if app::typing(state) {
+        return match key.code {
+            KeyCode::Esc => Action::Back,
+            KeyCode::Enter => Action::Confirm,
+            KeyCode::Backspace => Action::DeleteBack,
+            KeyCode::Char(c) => Action::Input(c),
+            _ => Action::Other,
+        };
+    }
+    
+    match (state.mode(), key.code) {
+        // Overlays close with Esc, q or the key that opened them
+        (UiMode::Help, KeyCode::Char('?'))
+        | (UiMode::Legend, KeyCode::Char('K'))
+        | (UiMode::Stats, KeyCode::Char('M'))
+        | (UiMode::Hygiene, KeyCode::Char('H'))
+        | (UiMode::Activity, KeyCode::Char('L'))
+        | (UiMode::BoardDiff, KeyCode::Char('D'))
+        | (UiMode::Blockers, KeyCode::Char('B'))
+        | (UiMode::Aging, KeyCode::Char('A'))
+        | (UiMode::RawPayload, KeyCode::Char('J')) => Action::Back,
+        (UiMode::RawPayload, code) => match code {
+            KeyCode::Char('q') | KeyCode::Esc => Action::Back,
+            KeyCode::Down | KeyCode::Char('j') => Action::ScrollDown(1),
+            KeyCode::Up | KeyCode::Char('k') => Action::ScrollUp(1),
+            KeyCode::PageDown | KeyCode::Char(' ') => Action::PageDown,
+            KeyCode::PageUp => Action::PageUp,
+            KeyCode::Home | KeyCode::Char('g') => Action::ScrollTop,
+            KeyCode::End | KeyCode::Char('G') => Action::ScrollBottom,
+            _ => Action::Other,
+        },
+        (mode, KeyCode::Char('q') | KeyCode::Esc) if mode.is_overlay() && mode != UiMode::NewTicket => Action::Back,
+        (UiMode::Setup, code) => match code {
+            KeyCode::Char('q') => Action::Quit,
+            KeyCode::Char('w') => Action::WriteSampleConfig,
+            KeyCode::Enter | KeyCode::Char('c') => Action::Confirm,
+            KeyCode::Esc => Action::Back,
+            _ => Action::Other,
+        },
+        (UiMode::Board, code) => match code {
+            KeyCode::Char(c) if c.is_ascii_digit() => Action::TypeAhead(c),
+            KeyCode::Backspace => Action::DeleteBack,
+            KeyCode::Esc => Action::Back,
+            KeyCode::Char('q') => Action::Quit,
+            KeyCode::Char('s') => Action::Open(UiMode::Setup),
+            KeyCode::Char('r') if ctrl => Action::ReloadMetadata,
+            KeyCode::Char('r') => Action::Refresh,
+            KeyCode::Char('R') => Action::RefreshCard,
+            KeyCode::Char('l') => Action::RefreshLane,
+            KeyCode::Char('w') if ctrl => Action::SwitchBoard,
+            KeyCode::Char('p') => Action::TogglePause,
+            KeyCode::Char('v') => Action::CycleDensity,
+            KeyCode::Char('+' | '=') => Action::Zoom(1),
+            KeyCode::Char('-') => Action::Zoom(-1),
+            KeyCode::Char('?') => Action::Open(UiMode::Help),
+            KeyCode::Char('M') => Action::Open(UiMode::Stats),
+            KeyCode::Char('H') => Action::Open(UiMode::Hygiene),
+            KeyCode::Char('J') => Action::ShowRawPayload,
+            KeyCode::Char('K') => Action::Open(UiMode::Legend),
+            KeyCode::Char('L') => Action::Open(UiMode::Activity),
+            KeyCode::Char('I') => Action::OpenInbox,
+            KeyCode::Char('V') => Action::OpenReleases,
+            KeyCode::Char('T') => Action::OpenTimesheet,
+            KeyCode::Char('D') => Action::ShowDiff,
+            KeyCode::Char('B') => Action::ShowBlockers,
+            KeyCode::Char('A') => Action::ShowAging,
+            KeyCode::Char('a') => Action::CycleAssignee,
+            KeyCode::Char('Y') => Action::CopySnippet,
+            KeyCode::Char('F') => Action::SetReminder,
+            KeyCode::Char('b') => Action::Open(UiMode::QueryBuilder),
+            KeyCode::Char('i') => Action::EditSummary,
+            KeyCode::Char('N') => Action::NewTicket,
+            KeyCode::Char('f') => Action::ToggleFlag,
+            KeyCode::Up | KeyCode::Char('k') => Action::SelectPrev,
+            KeyCode::Down | KeyCode::Char('j') => Action::SelectNext,
+            KeyCode::Enter => Action::Confirm,
+            _ => Action::Other,
+        },
+        (UiMode::QueryBuilder, code) => match code {
+            KeyCode::Esc => Action::Back,
+            KeyCode::Up | KeyCode::BackTab => Action::PrevField,
+            KeyCode::Down | KeyCode::Tab => Action::NextField,
+            KeyCode::Left => Action::CycleValue(false),
+            KeyCode::Right => Action::CycleValue(true),
+            KeyCode::Backspace => Action::DeleteBack,
+            KeyCode::Char('s') if ctrl => Action::SaveProfile,
+            KeyCode::Char(c) => Action::Input(c),
+            KeyCode::Enter => Action::Confirm,
+            _ => Action::Other,
+        },
+        (UiMode::NewTicket, code) => match code {
+            KeyCode::Esc | KeyCode::Char('q') => Action::Back,
+            KeyCode::Up | KeyCode::Char('k') => Action::SelectPrev,
+            KeyCode::Down | KeyCode::Char('j') => Action::SelectNext,
+            KeyCode::Enter => Action::Confirm,
+            KeyCode::Char(c @ '1'..='9') => Action::PickTemplate(c as usize - '1' as usize),
+            _ => Action::Other,
+        },
+        (UiMode::Inbox, code) => match code {
+            KeyCode::Char('q') | KeyCode::Esc => Action::Back,
+            KeyCode::Up | KeyCode::Char('k') => Action::SelectPrev,
+            KeyCode::Down | KeyCode::Char('j') => Action::SelectNext,
+            KeyCode::Char('m') => Action::ToggleRead,
+            KeyCode::Char('a') => Action::MarkAllRead,
+            KeyCode::Char('r') => Action::Reload,
+            KeyCode::Enter => Action::Confirm,
+            _ => Action::Other,
+        },
+        (UiMode::Releases, code) => match code {
+            KeyCode::Char('q') | KeyCode::Esc => Action::Back,
+            KeyCode::Up | KeyCode::Char('k') => Action::SelectPrev,
+            KeyCode::Down | KeyCode::Char('j') => Action::SelectNext,
+            KeyCode::Char('r') => Action::Reload,
+            KeyCode::Enter => Action::Confirm,
+            _ => Action::Other,
+        },
+        (UiMode::Timesheet, code) => match code {
+            KeyCode::Char('q') | KeyCode::Esc => Action::Back,
+            KeyCode::Up | KeyCode::Char('k') => Action::SelectPrev,
+            KeyCode::Down | KeyCode::Char('j') => Action::SelectNext,
+            KeyCode::Left | KeyCode::Char('[') => Action::ShiftWeek(-1),
+            KeyCode::Right | KeyCode::Char(']') => Action::ShiftWeek(1),
+            KeyCode::Char('r') => Action::Reload,
+            KeyCode::Char('l') => Action::LogWork,
+            KeyCode::Enter => Action::Confirm,
+            _ => Action::Other,
+        },
+        // The comment composer takes Enter as a new line
+        (UiMode::Detail, code) if state.comment_draft.is_some() => match code {
+            KeyCode::Esc => Action::Back,
+            KeyCode::Char('s') if ctrl => Action::Confirm,
+            KeyCode::Char('t') if ctrl => Action::ToggleInternal,
+            KeyCode::Enter => Action::Input('\n'),
+            KeyCode::Tab => Action::Input('\t'),
+            KeyCode::Backspace => Action::DeleteBack,
+            KeyCode::Char(c) => Action::Input(c),
+            _ => Action::Other,
+        },
+        (UiMode::Detail, code) => {
+            let pending = state.pending_key;
+            match code {
+                KeyCode::Char('q') | KeyCode::Esc => Action::Back,
+                KeyCode::Up | KeyCode::Char('k') => Action::ScrollUp(1),
+                KeyCode::Down | KeyCode::Char('j') => Action::ScrollDown(1),
+                KeyCode::PageUp => Action::PageUp,
+                KeyCode::PageDown => Action::PageDown,
+                KeyCode::Char('d') if ctrl => Action::HalfPage(true),
+                KeyCode::Char('u') if ctrl => Action::HalfPage(false),
+                KeyCode::Home => Action::ScrollTop,
+                KeyCode::End | KeyCode::Char('G') => Action::ScrollBottom,
+                KeyCode::Char(']') if pending == Some(']') => Action::NextSection,
+                KeyCode::Char('[') if pending == Some('[') => Action::PrevSection,
+                KeyCode::Char(c @ (']' | '[')) => Action::Prefix(c),
+                KeyCode::Char('c') => Action::JumpToComments,
+                KeyCode::Char('/') => Action::StartSearch,
+                KeyCode::Char('n') => Action::NextMatch,
+                KeyCode::Char('N') => Action::PrevMatch,
+                KeyCode::Char('g') => Action::GoTop,
+                KeyCode::Char('x') => Action::OpenVisibleLink,
+                KeyCode::Char('J') => Action::ShowRawPayload,
+                KeyCode::Char('B') => Action::ShowBlockers,
+                KeyCode::Char('Y') => Action::CopySnippet,
+                KeyCode::Char('F') => Action::SetReminder,
+                KeyCode::Char('W') => Action::ToggleWatchers,
+                KeyCode::Tab => Action::PickCheckItem(true),
+                KeyCode::BackTab => Action::PickCheckItem(false),
+                KeyCode::Char(' ') => Action::ToggleCheckItem,
+                KeyCode::Char('C') => Action::StartComment,
+                KeyCode::Char('r') => Action::Reply,
+                KeyCode::Char('+') => Action::ThumbsUp,
+                KeyCode::Char(c @ '1'..='9') => Action::OpenLink(c as usize - '1' as usize),
+                _ => Action::Other,
+            }
+        }
+        _ => Action::Other,
+    }
+}
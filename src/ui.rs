@@ -1,89 +1,1814 @@
-use crate::model::{StatusGroups, Ticket, get_status_color};
+use crate::activity;
+use crate::aging::{self, Aging};
+use crate::board::{Board, HIGHLIGHT};
+use crate::blockers::{Chain, Node};
+use crate::catch_up::CatchUp;
+use crate::permissions::{Permission, Permissions};
+use crate::reminders::Reminders;
+use crate::history::{BoardDiff, Change};
+use crate::hygiene;
+use crate::i18n::{t, tf};
+use crate::inbox::Inbox;
+use crate::config::{AssigneeStyle, Config, Density, DisplayConfig, GroupBy, HygieneRule, TicketTemplate};
+use crate::model::{NO_COMPONENT, NO_SPRINT, Sla, StatusGroups, Ticket, TicketType, a11y, comment_threads, count_tickets, get_status_color, plain_symbols, symbol, total_points};
+use crate::query_builder::{BuilderField, QueryBuilder};
+use crate::jira_api::ApiError;
+use crate::keymap::{self, Context};
+use crate::markdown::{self, Inline};
+use crate::rate_limit;
+use crate::telemetry;
+use crate::releases::Releases;
+use crate::timesheet::{self, Timesheet};
+use crate::wiki::{looks_like_wiki_markup, quoted, render_wiki_markup};
+use chrono::{DateTime, Local, NaiveDate, Utc};
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    symbols,
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{Axis, Block, Borders, Chart, Clear, Dataset, GraphType, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap},
     Frame,
 };
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::{Duration, Instant};
 
-#[derive(Debug, Clone)]
+/// Time between frames when nothing else happens: spinners, countdowns,
+/// pulses and fades move on at this pace without waiting for input
+pub const TICK: Duration = Duration::from_millis(250);
+
+/// A screen or overlay. Open ones form a stack with the board at the
+/// bottom: the top one gets the keys, and Esc closes it to show what's
+/// below.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum UiMode {
     Board,
     Detail,
+    QueryBuilder,
+    NewTicket,  // Template picker over the board
+    Inbox,
+    Timesheet,
+    Releases,
+    Setup,  // Credentials are missing
+    Help,
+    Legend,
+    Stats,
+    Hygiene,
+    Activity,
+    BoardDiff,
+    RawPayload,
+    Blockers,
+    CatchUp,
+    Aging,
+}
+
+impl UiMode {
+    /// Drawn over the screen below it rather than in its place
+    pub fn is_overlay(self) -> bool {
+        matches!(
+            self,
+            UiMode::NewTicket | UiMode::Help | UiMode::Legend | UiMode::Stats | UiMode::Activity | UiMode::BoardDiff | UiMode::RawPayload
+                | UiMode::Blockers | UiMode::CatchUp | UiMode::Hygiene | UiMode::Aging
+        )
+    }
+}
+
+/// Anchors within the detail view, in display order
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DetailSection {
+    Fields,
+    Checklist,
+    Description,
+    Comments,
+    Links,
+}
+
+impl DetailSection {
+    pub fn label(&self) -> &str {
+        match self {
+            DetailSection::Fields => "Fields",
+            DetailSection::Checklist => "Checklist",
+            DetailSection::Description => "Description",
+            DetailSection::Comments => "Comments",
+            DetailSection::Links => "Links",
+        }
+    }
+}
+
+/// Board zoom levels, stepped through with `+` and `-`. Zooming in widens
+/// the lane labels and strips cards down to what reads from across a room;
+/// zooming out narrows the labels to leave more room for cards.
+pub const ZOOM: std::ops::RangeInclusive<i8> = -1..=2;
+
+// Lane label width at each zoom level, from the most zoomed out
+const LABEL_WIDTHS: [u16; 4] = [8, 12, 16, 22];
+
+/// A ticket's raw JSON, as last fetched
+#[derive(Debug)]
+pub struct RawPayload {
+    pub key: String,
+    pub json: String,  // Pretty-printed
+    pub scroll: usize,
+    pub height: usize,  // Lines that fit on screen, filled in on draw
 }
 
 #[derive(Debug)]
 pub struct AppState {
-    pub mode: UiMode,
-    pub selected_index: usize,  // Global index across all tickets
+    modes: Vec<UiMode>,  // Open screens and overlays, the board first
     pub detail_ticket: Option<Ticket>,
     pub detail_scroll: usize,
+    pub detail_sections: Vec<(DetailSection, usize)>,  // Section start lines, filled in on draw
+    pub pending_key: Option<char>,  // First key of a two-key sequence like `]]`
+    pub search_input: Option<String>,  // Query being typed after `/`
+    pub search_query: Option<String>,  // Confirmed query being highlighted
+    pub search_matches: Vec<usize>,  // Lines containing a match, filled in on draw
+    pub search_jump: bool,  // Jump to the first match on the next draw
+    pub detail_link_lines: Vec<(usize, usize)>,  // (line, url index) for `[n]` markers, filled in on draw
+    pub detail_comment_lines: Vec<(usize, usize)>,  // (header line, comment index) in display order, filled in on draw
+    pub detail_height: usize,  // Content lines that fit on screen, filled in on draw
+    pub detail_length: usize,  // Content lines in all, filled in on draw
+    pub scroll_before_g: Option<usize>,  // Where `g` jumped from, restored if it turns out to be `gx`
+    pub status_message: Option<String>,  // Transient feedback shown in the footer
+    pub display: DisplayConfig,
+    pub show_watchers: bool,
+    pub checklist_selected: Option<usize>,  // Checklist item Space ticks, picked with Tab
+    pub raw_payload: Option<RawPayload>,  // Shown in an overlay after `J`, with --debug
+    pub query_builder: QueryBuilder,
+    pub template_selected: usize,
+    pub template_summary: Option<String>,  // Summary being typed once a template is picked
+    pub summary_edit: Option<(String, String)>,  // (ticket key, new summary) while editing a card inline
+    pub reminders: Reminders,
+    pub permissions: Permissions,  // What JIRA lets you do, by project, learned as projects show up
+    pub reminder_input: Option<(String, String)>,  // (ticket key, day and note) while setting a reminder
+    pub board_diff: Option<Result<BoardDiff, String>>,  // Shown in an overlay after `D`
+    pub catch_up: Option<CatchUp>,  // What changed overnight, shown on the first start of a day
+    pub blockers: Option<Result<Chain, String>>,  // Blocking chain of a ticket, shown in an overlay after `B`
+    pub aging: Option<Aging>,  // Days in status of the work in progress, charted after `A`
+    pub inbox: Option<Inbox>,  // Loaded when the inbox opens, kept while a ticket from it is open
+    pub timesheet: Option<Timesheet>,  // Loaded when the worklog summary opens
+    pub releases: Option<Releases>,  // Loaded when the release view opens
+    pub setup_needed: Vec<&'static str>,  // Settings missing before JIRA can be reached
+    pub type_ahead: Option<(String, Instant)>,  // Digits typed to jump to a ticket, and when the last one came
+    pub comment_draft: Option<String>,  // Comment being written on the open ticket
+    pub comment_internal: bool,  // The draft is an internal note, on Service Management requests
+    pub tick: u64,  // Ticks since start, counted by the event loop, for animations
+    pub zoom: i8,  // Within `ZOOM`; 0 is the usual board
+    pub wallboard: bool,  // Unattended on a TV: no selection or key hints
+    pub idle: bool,  // Auto-refresh paused for want of keypresses
+}
+
+impl AppState {
+    /// Nothing open yet: the board, or the setup screen while settings are
+    /// missing
+    pub fn new(display: DisplayConfig, setup_needed: Vec<&'static str>) -> Self {
+        AppState {
+            modes: if setup_needed.is_empty() { vec![UiMode::Board] } else { vec![UiMode::Board, UiMode::Setup] },
+            setup_needed,
+            type_ahead: None,
+            comment_draft: None,
+            comment_internal: true,
+            tick: 0,
+            zoom: 0,
+            wallboard: false,
+            idle: false,
+            detail_ticket: None,
+            detail_scroll: 0,
+            detail_sections: Vec::new(),
+            pending_key: None,
+            search_input: None,
+            search_query: None,
+            search_matches: Vec::new(),
+            search_jump: false,
+            detail_link_lines: Vec::new(),
+            detail_comment_lines: Vec::new(),
+            detail_height: 0,
+            detail_length: 0,
+            scroll_before_g: None,
+            status_message: None,
+            display,
+            show_watchers: false,
+            checklist_selected: None,
+            raw_payload: None,
+            query_builder: QueryBuilder::new(),
+            template_selected: 0,
+            template_summary: None,
+            summary_edit: None,
+            reminders: Reminders::default(),
+            permissions: Permissions::default(),
+            reminder_input: None,
+            board_diff: None,
+            blockers: None,
+            aging: None,
+            catch_up: None,
+            inbox: None,
+            timesheet: None,
+            releases: None,
+        }
+    }
+    
+    /// The screen or overlay on top, which gets the keys
+    pub fn mode(&self) -> UiMode {
+        self.modes.last().copied().unwrap_or(UiMode::Board)
+    }
+    
+    pub fn push(&mut self, mode: UiMode) {
+        self.modes.push(mode);
+    }
+    
+    /// Close the top screen or overlay, dropping what it was showing. The
+    /// board at the bottom can't be closed.
+    pub fn pop(&mut self) {
+        if self.modes.len() <= 1 {
+            return;
+        }
+        match self.modes.pop() {
+            Some(UiMode::Detail) => {
+                self.detail_ticket = None;
+                self.comment_draft = None;
+                self.checklist_selected = None;
+                self.clear_search();
+            }
+            Some(UiMode::Inbox) => self.inbox = None,
+            Some(UiMode::Timesheet) => self.timesheet = None,
+            Some(UiMode::Releases) => self.releases = None,
+            Some(UiMode::NewTicket) => self.template_summary = None,
+            Some(UiMode::BoardDiff) => self.board_diff = None,
+            Some(UiMode::Blockers) => self.blockers = None,
+            Some(UiMode::Aging) => self.aging = None,
+            Some(UiMode::CatchUp) => self.catch_up = None,
+            Some(UiMode::RawPayload) => self.raw_payload = None,
+            _ => {}
+        }
+    }
+    
+    /// Close everything down to the board
+    pub fn pop_to_board(&mut self) {
+        while self.modes.len() > 1 {
+            self.pop();
+        }
+    }
+    
+    /// Section containing the current scroll position
+    pub fn current_section(&self) -> Option<(usize, DetailSection)> {
+        self.detail_sections.iter()
+            .enumerate()
+            .rev()
+            .find(|(_, (_, start))| *start <= self.detail_scroll)
+            .map(|(i, (section, _))| (i, *section))
+    }
+    
+    pub fn next_section(&mut self) {
+        if let Some(&(_, start)) = self.detail_sections.iter()
+            .find(|(_, start)| *start > self.detail_scroll)
+        {
+            self.detail_scroll = start;
+        }
+    }
+    
+    pub fn prev_section(&mut self) {
+        if let Some(&(_, start)) = self.detail_sections.iter()
+            .rev()
+            .find(|(_, start)| *start < self.detail_scroll)
+        {
+            self.detail_scroll = start;
+        }
+    }
+    
+    pub fn next_match(&mut self) {
+        let next = self.search_matches.iter()
+            .find(|&&line| line > self.detail_scroll)
+            .or_else(|| self.search_matches.first());
+        if let Some(&line) = next {
+            self.detail_scroll = line;
+        }
+    }
+    
+    pub fn prev_match(&mut self) {
+        let prev = self.search_matches.iter()
+            .rev()
+            .find(|&&line| line < self.detail_scroll)
+            .or_else(|| self.search_matches.last());
+        if let Some(&line) = prev {
+            self.detail_scroll = line;
+        }
+    }
+    
+    pub fn clear_search(&mut self) {
+        self.search_input = None;
+        self.search_query = None;
+        self.search_matches.clear();
+        self.search_jump = false;
+    }
+    
+    /// First link marker at or below the top of the detail viewport
+    pub fn first_visible_link(&self) -> Option<usize> {
+        self.detail_link_lines.iter()
+            .find(|(line, _)| *line >= self.detail_scroll)
+            .map(|(_, index)| *index)
+    }
+    
+    /// Comment a reply would quote: the one the top of the screen is in,
+    /// else the first one that starts on screen
+    pub fn current_comment(&self) -> Option<usize> {
+        let bottom = self.detail_scroll + self.detail_height.max(1);
+        self.detail_comment_lines.iter()
+            .rev()
+            .find(|(line, _)| *line <= self.detail_scroll)
+            .filter(|_| self.current_section().is_some_and(|(_, section)| section == DetailSection::Comments))
+            .or_else(|| self.detail_comment_lines.iter().find(|(line, _)| *line >= self.detail_scroll && *line < bottom))
+            .map(|(_, index)| *index)
+    }
+    
+    /// Scroll the detail view by half a screen, like a pager's Ctrl+D/U
+    pub fn scroll_half_page(&mut self, down: bool) {
+        let step = (self.detail_height / 2).max(1);
+        self.detail_scroll = if down {
+            (self.detail_scroll + step).min(self.last_scroll())
+        } else {
+            self.detail_scroll.saturating_sub(step)
+        };
+    }
+    
+    /// Scroll so the end of the ticket sits at the bottom of the screen
+    pub fn scroll_to_bottom(&mut self) {
+        self.detail_scroll = self.last_scroll();
+    }
+    
+    fn last_scroll(&self) -> usize {
+        self.detail_length.saturating_sub(self.detail_height)
+    }
+    
+    pub fn jump_to_section(&mut self, section: DetailSection) {
+        if let Some(&(_, start)) = self.detail_sections.iter().find(|(s, _)| *s == section) {
+            self.detail_scroll = start;
+        }
+    }
 }
 
 pub fn draw_ui(
     frame: &mut Frame, 
-    columns: &StatusGroups,
-    last_update: Option<&chrono::DateTime<chrono::Local>>,
+    boards: &[Board],
+    focused: usize,
     paused: bool,
-    refresh_seconds: u64,
     app_state: &mut AppState,
 ) {
     let size = frame.area();
     
-    match app_state.mode {
+    // The topmost screen fills the frame; overlays opened above it are drawn
+    // over it in order
+    let base = app_state.modes.iter().rposition(|mode| !mode.is_overlay()).unwrap_or(0);
+    let screen = app_state.modes.get(base).copied().unwrap_or(UiMode::Board);
+    match screen {
+        // Until the first results arrive, a splash screen shows kanbars is
+        // working rather than hung on a slow connection
+        UiMode::Board if boards.iter().all(|b| b.is_starting()) => draw_splash(frame, size, &boards[0]),
         UiMode::Board => {
+            // The linear a11y board carries its own hints
+            let show_footer = app_state.display.footer && !app_state.wallboard && !a11y();
+            let size = if show_footer {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(0), Constraint::Length(1)])
+                    .split(size);
+                draw_board_footer(frame, chunks[1], &boards[focused], boards.len() > 1, app_state);
+                chunks[0]
+            } else {
+                size
+            };
+            
+            // Split views stack boards top to bottom in equal shares
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
-                .constraints([Constraint::Min(0)])
+                .constraints(boards.iter().map(|_| Constraint::Ratio(1, boards.len() as u32)))
                 .split(size);
-            draw_kanban_board(frame, chunks[0], columns, last_update, paused, refresh_seconds, app_state);
+            for (i, board) in boards.iter().enumerate() {
+                let view = BoardView {
+                    board,
+                    focused: i == focused,
+                    split: boards.len() > 1,
+                };
+                draw_kanban_board(frame, chunks[i], &view, paused, app_state);
+            }
         }
         UiMode::Detail => {
             if app_state.detail_ticket.is_some() {
                 draw_ticket_detail(frame, size, app_state);
             }
+            if let (Some(draft), Some(ticket)) = (&app_state.comment_draft, &app_state.detail_ticket) {
+                let internal = ticket.service_desk.then_some(app_state.comment_internal);
+                draw_comment_composer(frame, size, &ticket.key, draft, internal);
+            }
+        }
+        UiMode::QueryBuilder => draw_query_builder(frame, size, app_state),
+        UiMode::Inbox => draw_inbox(frame, size, app_state),
+        UiMode::Timesheet => draw_timesheet(frame, size, app_state),
+        UiMode::Releases => draw_releases(frame, size, app_state),
+        UiMode::Setup => draw_setup(frame, size, app_state),
+        _ => {}
+    }
+    
+    let overlays = app_state.modes[base + 1..].to_vec();
+    for overlay in overlays {
+        match overlay {
+            UiMode::NewTicket => draw_template_picker(frame, size, &boards[focused].config.templates, app_state),
+            UiMode::Help => draw_help_overlay(frame, size),
+            UiMode::Legend => draw_legend_overlay(frame, size, &boards[focused].columns, &app_state.display, &app_state.reminders, &boards[focused].config.hygiene.rules),
+            UiMode::Activity => draw_activity_overlay(frame, size),
+            UiMode::Stats => draw_stats_overlay(frame, size, boards),
+            UiMode::Hygiene => draw_hygiene_overlay(frame, size, &boards[focused]),
+            UiMode::BoardDiff => {
+                if let Some(ref diff) = app_state.board_diff {
+                    draw_diff_overlay(frame, size, diff);
+                }
+            }
+            UiMode::RawPayload => {
+                if let Some(ref mut payload) = app_state.raw_payload {
+                    draw_raw_overlay(frame, size, payload);
+                }
+            }
+            UiMode::Blockers => {
+                if let Some(ref chain) = app_state.blockers {
+                    draw_blockers_overlay(frame, size, chain);
+                }
+            }
+            UiMode::CatchUp => {
+                if let Some(ref catch_up) = app_state.catch_up {
+                    draw_catch_up_overlay(frame, size, catch_up);
+                }
+            }
+            UiMode::Aging => {
+                if let Some(ref aging) = app_state.aging {
+                    draw_aging_overlay(frame, size, aging);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// Feedback from the last action, or the keys that do something right now
+fn draw_board_footer(frame: &mut Frame, area: Rect, board: &Board, split: bool, app_state: &AppState) {
+    let text = if app_state.summary_edit.is_some() {
+        t("board.editing").to_string()
+    } else if let Some((ref key, ref input)) = app_state.reminder_input {
+        tf("board.remind", &[("key", key), ("input", input)])
+    } else if let Some((ref digits, _)) = app_state.type_ahead {
+        match board.columns.find_key(digits) {
+            Some(_) => tf("board.jump", &[("digits", digits)]),
+            None => tf("board.jump_none", &[("digits", digits)]),
+        }
+    } else if let Some(ref message) = app_state.status_message {
+        message.clone()
+    } else {
+        // Keys JIRA wouldn't allow on the selected card stay out of it
+        let connected = app_state.setup_needed.is_empty();
+        let selected = board.columns.get_ticket_by_index(board.selected_index);
+        let state = keymap::State {
+            selected: board.columns.total_tickets() > 0,
+            connected,
+            split,
+            searching: false,
+            filtered: board.filter.is_some() || board.assignee.is_some(),
+            editable: connected && selected.is_some_and(|t| app_state.permissions.allows(&t.key, Permission::Edit)),
+            commentable: false,
+        };
+        keymap::footer(Context::Board, &state)
+    };
+    frame.render_widget(Paragraph::new(format!(" {}", text)).style(Style::default().fg(Color::DarkGray)), area);
+}
+
+// What's missing before kanbars can reach JIRA, and how to provide it
+fn draw_setup(frame: &mut Frame, area: Rect, app_state: &AppState) {
+    let key_style = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+    let muted = Style::default().fg(Color::DarkGray);
+    let path = Config::config_path();
+    
+    let mut lines = vec![
+        Line::from(Span::styled(t("setup.title"), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
+        Line::from(""),
+        Line::from(t("setup.missing")),
+    ];
+    for missing in &app_state.setup_needed {
+        lines.push(Line::from(vec![Span::raw("  • "), Span::styled(*missing, key_style)]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(t("setup.env_or_file")));
+    lines.push(Line::from(Span::styled(format!("  {}", path.display()), Style::default().fg(Color::Cyan))));
+    lines.push(Line::from(t("setup.loads_when_saved")));
+    lines.push(Line::from(Span::styled(t("setup.token_hint"), muted)));
+    lines.push(Line::from(""));
+    
+    let mut keys = Vec::new();
+    if !path.exists() {
+        keys.push(("w", t("setup.write_sample")));
+    }
+    keys.push(("Enter", t("setup.browse_cache")));
+    keys.push(("q", t("setup.quit")));
+    for (key, action) in keys {
+        lines.push(Line::from(vec![Span::styled(format!("  {:<7}", key), key_style), Span::raw(action)]));
+    }
+    if let Some(ref message) = app_state.status_message {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(message.clone(), Style::default().fg(Color::Green))));
+    }
+    
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(borders(Borders::ALL)).title(format!(" {} KANBARS ", symbol("🦀", "")))),
+        area,
+    );
+}
+
+fn draw_api_error(frame: &mut Frame, area: Rect, board: &Board, api_error: &ApiError, title: Span) {
+    let key_style = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+    let muted = Style::default().fg(Color::DarkGray);
+    let path = Config::config_path();
+    
+    let mut lines = Vec::new();
+    match api_error {
+        ApiError::Auth(status) => {
+            lines.push(Line::from(Span::styled(
+                tf("error.auth", &[("status", status)]),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(""));
+            if *status == 403 {
+                lines.push(Line::from(t("error.forbidden")));
+            }
+            lines.push(Line::from(t("error.check_settings")));
+            for setting in ["jira.url or JIRA_URL", "jira.email or JIRA_EMAIL", "jira.api_token or JIRA_API_TOKEN"] {
+                lines.push(Line::from(vec![Span::raw("  • "), Span::styled(setting, key_style)]));
+            }
+            lines.push(Line::from(Span::styled(format!("  {}", path.display()), Style::default().fg(Color::Cyan))));
+            lines.push(Line::from(Span::styled(t("error.tokens_expire"), muted)));
+        }
+        ApiError::Query(message) => {
+            lines.push(Line::from(Span::styled(
+                t("error.query"),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(message.clone(), Style::default().fg(Color::Red))));
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![Span::styled("JQL: ", muted), Span::styled(board.config.query.jql.clone(), Style::default().fg(Color::Cyan))]));
+            lines.push(Line::from(""));
+            lines.push(Line::from(tf("error.fix_jql", &[("path", &path.display())])));
         }
     }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![Span::styled("  r      ", key_style), Span::raw(t("error.retry"))]));
+    lines.push(Line::from(vec![Span::styled("  q      ", key_style), Span::raw(t("error.quit"))]));
+    
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(borders(Borders::ALL)).title(title)),
+        area,
+    );
+}
+
+// One frame of a spinner; step it on to animate
+fn spinner(step: usize) -> &'static str {
+    let frames: &[&str] = if plain_symbols() {
+        &["|", "/", "-", "\\"]
+    } else {
+        &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]
+    };
+    frames[step % frames.len()]
+}
+
+fn draw_splash(frame: &mut Frame, area: Rect, board: &Board) {
+    let elapsed = board.loading.as_ref().map(|l| l.started.elapsed()).unwrap_or_default();
+    let spinner = spinner((elapsed.as_millis() / 100) as usize);
+    let target = match board.config.jira.url {
+        Some(ref url) if board.config.jira.api_token.is_some() => url.trim_start_matches("https://").trim_end_matches('/').to_string(),
+        _ => t("splash.via_acli").to_string(),
+    };
+    
+    let muted = Style::default().fg(Color::DarkGray);
+    let mut lines = vec![
+        Line::from(Span::styled(format!("{} KANBARS", symbol("🦀", "")).trim_start().to_string(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(format!("{} ", spinner), Style::default().fg(Color::Yellow)),
+            Span::raw(tf("splash.loading", &[("board", &board.name), ("target", &target)])),
+        ]),
+        Line::from(Span::styled(format!("{}s", elapsed.as_secs()), muted)),
+    ];
+    // Point at the usual suspect once it's taking a while
+    if elapsed.as_secs() >= 10 {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(t("splash.slow"), muted)));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(t("splash.quit"), muted)));
+    
+    let height = lines.len() as u16;
+    let top = area.y + area.height.saturating_sub(height) / 2;
+    frame.render_widget(
+        Paragraph::new(lines).alignment(Alignment::Center),
+        Rect { x: area.x, y: top, width: area.width, height: height.min(area.height) },
+    );
+}
+
+// Filter form with a live preview of the JQL it composes
+fn draw_query_builder(frame: &mut Frame, area: Rect, app_state: &AppState) {
+    let builder = &app_state.query_builder;
+    let label_style = Style::default().fg(Color::Gray);
+    let mut lines = vec![Line::from("")];
+    
+    for (i, field) in BuilderField::ALL.iter().enumerate() {
+        let is_selected = i == builder.selected;
+        let value = builder.value(*field);
+        let mut spans = vec![
+            Span::styled(
+                if is_selected { "▶ " } else { "  " },
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("{:<16}", field.label()), label_style),
+        ];
+        if value.is_empty() {
+            spans.push(Span::styled(field.hint().to_string(), Style::default().fg(Color::DarkGray)));
+        } else {
+            let style = if is_selected {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            spans.push(Span::styled(value.to_string(), style));
+        }
+        if is_selected && builder.profile_name.is_none() {
+            let text_field = !matches!(field, BuilderField::StatusCategory | BuilderField::UpdatedWithin);
+            spans.push(Span::raw(if text_field { "▏" } else { "  ◀▶" }));
+        }
+        lines.push(Line::from(spans));
+    }
+    
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("JQL", label_style.add_modifier(Modifier::BOLD))));
+    lines.push(match builder.to_jql() {
+        Some(jql) => Line::from(Span::styled(jql, Style::default().fg(Color::Cyan))),
+        None => Line::from(Span::styled("(set at least one filter)", Style::default().fg(Color::DarkGray))),
+    });
+    
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(borders(Borders::ALL)).title(" 🔧 Query builder ")),
+        chunks[0],
+    );
+    
+    let footer = if let Some(ref name) = builder.profile_name {
+        format!(" Save as profile: {}▏  Enter: save  Esc: cancel", name)
+    } else if let Some(ref message) = app_state.status_message {
+        format!(" {}", message)
+    } else {
+        " ↑↓/Tab: field  ←→: change  Enter: try on board  Ctrl+S: save as profile  Esc: back".to_string()
+    };
+    frame.render_widget(
+        Paragraph::new(footer).style(Style::default().fg(Color::DarkGray)),
+        chunks[1],
+    );
+}
+
+// Popup listing ticket templates, then asking for the picked one's summary
+fn draw_template_picker(
+    frame: &mut Frame,
+    area: Rect,
+    templates: &BTreeMap<String, TicketTemplate>,
+    app_state: &AppState,
+) {
+    let key_style = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+    let mut lines = Vec::new();
+    
+    for (i, (name, template)) in templates.iter().enumerate() {
+        let is_selected = i == app_state.template_selected;
+        let marker = if is_selected { "▶" } else { " " };
+        let mut spans = vec![
+            Span::styled(format!("{} {} ", marker, i + 1), key_style),
+            Span::styled(
+                format!("{:<16}", name),
+                if is_selected { Style::default().fg(Color::Yellow) } else { Style::default() },
+            ),
+            Span::styled(
+                format!("{} in {}", template.issue_type, template.project),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ];
+        if !template.labels.is_empty() {
+            spans.push(Span::styled(
+                format!(" [{}]", template.labels.join(", ")),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    
+    lines.push(Line::from(""));
+    match app_state.template_summary {
+        Some(ref summary) => {
+            lines.push(Line::from(vec![
+                Span::styled(t("new_ticket.summary"), Style::default().fg(Color::Gray)),
+                Span::raw(format!("{}▏", summary)),
+            ]));
+            lines.push(Line::from(Span::styled(
+                t("new_ticket.summary_hints"),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        None => lines.push(Line::from(Span::styled(
+            t("new_ticket.pick_hints"),
+            Style::default().fg(Color::DarkGray),
+        ))),
+    }
+    
+    let width = 70.min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .borders(borders(Borders::ALL))
+                .title(t("new_ticket.title"))
+                .border_style(Style::default().fg(Color::Cyan)),
+        ),
+        popup,
+    );
+}
+
+// Editor for a new comment, above a preview of how JIRA will show it.
+// `internal` is set on Service Management requests, where who will see the
+// comment is spelled out in the border so a customer reply isn't sent by
+// mistake.
+fn draw_comment_composer(frame: &mut Frame, area: Rect, key: &str, draft: &str, internal: Option<bool>) {
+    let muted = Style::default().fg(Color::DarkGray);
+    let width = 90.min(area.width);
+    let height = 24.min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Min(0), Constraint::Length(1)])
+        .split(popup);
+    frame.render_widget(Clear, popup);
+    
+    // Keep the end of the draft, where typing happens, in view
+    let mut editor: Vec<Line> = draft.split('\n').map(|line| Line::from(line.to_string())).collect();
+    if let Some(last) = editor.last_mut() {
+        last.spans.push(Span::raw("▏"));
+    }
+    let rows = chunks[0].height.saturating_sub(2) as usize;
+    let skip = editor.len().saturating_sub(rows);
+    let mut block = Block::default()
+        .borders(borders(Borders::ALL))
+        .title(tf("comment.title", &[("key", &key)]))
+        .border_style(Style::default().fg(Color::Cyan));
+    if let Some(internal) = internal {
+        let (label, color) = if internal {
+            (t("comment.internal"), Color::Yellow)
+        } else {
+            (t("comment.public"), Color::Red)
+        };
+        block = block
+            .title_top(Line::from(Span::styled(label, Style::default().fg(Color::Black).bg(color).add_modifier(Modifier::BOLD))).right_aligned())
+            .border_style(Style::default().fg(color));
+    }
+    frame.render_widget(Paragraph::new(editor.split_off(skip)).block(block), chunks[0]);
+    
+    let blocks = markdown::parse(draft);
+    let preview = if blocks.is_empty() {
+        vec![Line::from(Span::styled(t("comment.syntax"), muted))]
+    } else {
+        comment_preview(&blocks)
+    };
+    frame.render_widget(
+        Paragraph::new(preview)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(borders(Borders::ALL)).title(t("comment.preview")).border_style(muted)),
+        chunks[1],
+    );
+    let hints = match internal {
+        Some(_) => format!(" {}  {}", t("comment.hints"), t("comment.switch_visibility")),
+        None => format!(" {}", t("comment.hints")),
+    };
+    frame.render_widget(Paragraph::new(hints).style(muted), chunks[2]);
+}
+
+fn comment_preview(blocks: &[markdown::Block]) -> Vec<Line<'static>> {
+    let inline_spans = |line: &[Inline]| -> Vec<Span<'static>> {
+        line.iter()
+            .map(|inline| match inline {
+                Inline::Text(text) => Span::raw(text.clone()),
+                Inline::Bold(text) => Span::styled(text.clone(), Style::default().add_modifier(Modifier::BOLD)),
+                Inline::Code(text) => Span::styled(text.clone(), Style::default().fg(Color::Yellow)),
+                Inline::Mention(name) => Span::styled(format!("@{}", name), Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
+            })
+            .collect()
+    };
+    
+    let mut lines = Vec::new();
+    for (i, block) in blocks.iter().enumerate() {
+        if i > 0 {
+            lines.push(Line::from(""));
+        }
+        match block {
+            markdown::Block::Paragraph(paragraph) => lines.extend(paragraph.iter().map(|line| Line::from(inline_spans(line)))),
+            markdown::Block::Bullets(items) => lines.extend(items.iter().map(|item| {
+                let mut spans = vec![Span::raw(format!("  {} ", symbol("•", "*")))];
+                spans.extend(inline_spans(item));
+                Line::from(spans)
+            })),
+            markdown::Block::Numbered(items) => lines.extend(items.iter().enumerate().map(|(n, item)| {
+                let mut spans = vec![Span::raw(format!("  {}. ", n + 1))];
+                spans.extend(inline_spans(item));
+                Line::from(spans)
+            })),
+            markdown::Block::Quote(quote) => lines.extend(quote.iter().map(|line| quoted(inline_spans(line)))),
+            markdown::Block::Code(code) => lines.extend(code.lines().map(|line| {
+                Line::from(Span::styled(format!("    {}", line), Style::default().fg(Color::Cyan)))
+            })),
+        }
+    }
+    lines
+}
+
+// Comments on watched tickets, newest first, with unread ones marked
+fn draw_inbox(frame: &mut Frame, area: Rect, app_state: &AppState) {
+    let Some(ref inbox) = app_state.inbox else { return };
+    let muted = Style::default().fg(Color::DarkGray);
+    let mut lines = Vec::new();
+    
+    if let Some(ref error) = inbox.error {
+        lines.push(Line::from(Span::styled(tf("inbox.failed", &[("error", error)]), Style::default().fg(Color::Red))));
+    } else if inbox.items.is_empty() {
+        lines.push(Line::from(Span::styled(t("inbox.empty"), muted)));
+    }
+    
+    for (i, item) in inbox.items.iter().enumerate() {
+        let is_selected = i == inbox.selected;
+        let unread = inbox.is_unread(item);
+        let text_style = if unread { Style::default().add_modifier(Modifier::BOLD) } else { muted };
+        let mut spans = vec![
+            Span::styled(
+                if is_selected { "▶ " } else { "  " },
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(if unread { "● " } else { "  " }, Style::default().fg(Color::Cyan)),
+            Span::styled(format!("{:<12}", item.key), Style::default().fg(Color::Cyan)),
+            Span::styled(format!("{:<17}", item.created.get(..16).unwrap_or(&item.created).replace('T', " ")), muted),
+        ];
+        if item.mentions_me {
+            spans.push(Span::styled("@you ", Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)));
+        }
+        spans.push(Span::styled(format!("{}: ", item.author), Style::default().fg(Color::Blue)));
+        spans.push(Span::styled(item.summary.clone(), text_style));
+        lines.push(Line::from(spans));
+        
+        let excerpt = item.body.split_whitespace().collect::<Vec<_>>().join(" ");
+        lines.push(Line::from(vec![
+            Span::raw("      "),
+            Span::styled(abbreviate(&excerpt, (area.width as usize).saturating_sub(10)), muted),
+        ]));
+    }
+    
+    // Keep the selection on screen
+    let visible = area.height.saturating_sub(3) as usize;
+    let scroll = (inbox.selected * 2 + 2).saturating_sub(visible);
+    
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .scroll((scroll as u16, 0))
+            .block(Block::default().borders(borders(Borders::ALL)).title(tf("inbox.title", &[("unread", &inbox.unread_count())]))),
+        chunks[0],
+    );
+    
+    let footer = match app_state.status_message {
+        Some(ref message) => format!(" {}", message),
+        None => format!(" {}", keymap::footer(Context::Inbox, &keymap::State::default())),
+    };
+    frame.render_widget(Paragraph::new(footer).style(muted), chunks[1]);
+}
+
+// One row per ticket and a column per weekday, with totals along the
+// bottom and right
+fn draw_timesheet(frame: &mut Frame, area: Rect, app_state: &AppState) {
+    let Some(ref timesheet) = app_state.timesheet else { return };
+    let muted = Style::default().fg(Color::DarkGray);
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let today = Local::now().date_naive();
+    let days: Vec<_> = (0..7).map(|i| timesheet.week_start + chrono::Days::new(i)).collect();
+    const CELL: usize = 8;
+    let summary_width = (area.width as usize).saturating_sub(2 + 12 + CELL * 8 + 2).max(10);
+    
+    let cell = |seconds: u64| if seconds == 0 { format!("{:>w$}", "·", w = CELL) } else { format!("{:>w$}", timesheet::format_duration(seconds), w = CELL) };
+    let day_style = |day: &chrono::NaiveDate| if *day == today { Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD) } else { Style::default() };
+    
+    let mut header = vec![Span::styled(format!("  {:<12}{:<w$}", t("timesheet.ticket"), "", w = summary_width), muted)];
+    for day in &days {
+        header.push(Span::styled(format!("{:>w$}", day.format("%a %d").to_string(), w = CELL), day_style(day).patch(muted)));
+    }
+    header.push(Span::styled(format!("{:>w$}", t("timesheet.total"), w = CELL), muted));
+    let mut lines = vec![Line::from(header)];
+    
+    if let Some(ref error) = timesheet.error {
+        lines.push(Line::from(Span::styled(tf("timesheet.failed", &[("error", error)]), Style::default().fg(Color::Red))));
+    } else if timesheet.rows.is_empty() {
+        lines.push(Line::from(Span::styled(t("timesheet.empty"), muted)));
+    }
+    
+    for (i, row) in timesheet.rows.iter().enumerate() {
+        let is_selected = i == timesheet.selected;
+        let mut spans = vec![
+            Span::styled(
+                if is_selected { "▶ " } else { "  " },
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("{:<12}", row.key), Style::default().fg(Color::Cyan)),
+            Span::raw(format!("{:<w$}", abbreviate(&row.summary, summary_width.saturating_sub(1)), w = summary_width)),
+        ];
+        for (day, seconds) in days.iter().zip(row.days) {
+            spans.push(Span::styled(cell(seconds), if seconds == 0 { muted } else { day_style(day) }));
+        }
+        spans.push(Span::styled(cell(row.total()), bold));
+        lines.push(Line::from(spans));
+    }
+    
+    let mut totals = vec![Span::styled(format!("  {:<w$}", t("timesheet.total"), w = 12 + summary_width), bold)];
+    for (day, seconds) in days.iter().zip(timesheet.day_totals()) {
+        totals.push(Span::styled(cell(seconds), day_style(day).patch(bold)));
+    }
+    totals.push(Span::styled(cell(timesheet.total()), bold.fg(Color::Green)));
+    lines.push(Line::from(""));
+    lines.push(Line::from(totals));
+    
+    // Keep the selection on screen
+    let visible = area.height.saturating_sub(6) as usize;
+    let scroll = (timesheet.selected + 2).saturating_sub(visible);
+    
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+    let title = tf("timesheet.title", &[("week", &timesheet.week_start.format("%Y-%m-%d")), ("total", &timesheet::format_duration(timesheet.total()))]);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .scroll((scroll as u16, 0))
+            .block(Block::default().borders(borders(Borders::ALL)).title(title)),
+        chunks[0],
+    );
+    
+    let footer = match (&timesheet.log_input, &app_state.status_message) {
+        (Some(input), _) => Line::from(vec![
+            Span::styled(
+                format!(" {} ", tf("timesheet.log_prompt", &[("key", &timesheet.selected_row().map_or("", |row| row.key.as_str()))])),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::raw(format!("{}█", input)),
+            Span::styled(format!("  {}", t("timesheet.log_hints")), muted),
+        ]),
+        (None, Some(message)) => Line::from(Span::styled(format!(" {}", message), muted)),
+        (None, None) => Line::from(Span::styled(format!(" {}", keymap::footer(Context::Timesheet, &keymap::State::default())), muted)),
+    };
+    frame.render_widget(Paragraph::new(footer), chunks[1]);
+}
+
+// Each release with a progress bar of the board's tickets in it and its
+// due date
+fn draw_releases(frame: &mut Frame, area: Rect, app_state: &AppState) {
+    let Some(ref releases) = app_state.releases else { return };
+    let muted = Style::default().fg(Color::DarkGray);
+    let mut lines = Vec::new();
+    
+    if let Some(ref error) = releases.error {
+        lines.push(Line::from(Span::styled(tf("releases.failed", &[("error", error)]), Style::default().fg(Color::Red))));
+    }
+    if releases.versions.is_empty() {
+        lines.push(Line::from(Span::styled(t("releases.empty"), muted)));
+    }
+    
+    const BAR: usize = 20;
+    let name_width = releases.versions.iter()
+        .map(|r| r.name.as_deref().map_or(t("releases.no_version").chars().count(), |n| n.chars().count()))
+        .max()
+        .unwrap_or(0)
+        .min(40);
+    for (i, release) in releases.versions.iter().enumerate() {
+        let is_selected = i == releases.selected;
+        let name = release.name.as_deref().unwrap_or(t("releases.no_version"));
+        let filled = (release.done * BAR).checked_div(release.total).unwrap_or(0);
+        let bar_color = if release.total > 0 && release.done == release.total { Color::Green } else { Color::Cyan };
+        let (date, date_style) = match release.release_date {
+            Some(date) if release.released => (tf("releases.released", &[("date", &date.format("%Y-%m-%d"))]), muted),
+            Some(date) if release.overdue => (tf("releases.overdue", &[("date", &date.format("%Y-%m-%d"))]), Style::default().fg(Color::Red)),
+            Some(date) => (date.format("%Y-%m-%d").to_string(), Style::default()),
+            None if release.name.is_some() => (t("releases.no_date").to_string(), muted),
+            None => (String::new(), muted),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                if is_selected { "▶ " } else { "  " },
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("{:<w$}  ", abbreviate(name, name_width), w = name_width),
+                if release.released { muted } else { Style::default().fg(Color::LightMagenta).add_modifier(Modifier::BOLD) },
+            ),
+            Span::styled(symbol("█", "#").repeat(filled), Style::default().fg(bar_color)),
+            Span::styled(symbol("░", "-").repeat(BAR - filled), muted),
+            Span::raw(format!(" {:>3}/{:<3}  ", release.done, release.total)),
+            Span::styled(date, date_style),
+        ]));
+    }
+    
+    // Keep the selection on screen
+    let visible = area.height.saturating_sub(3) as usize;
+    let scroll = (releases.selected + 1).saturating_sub(visible);
+    
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .scroll((scroll as u16, 0))
+            .block(Block::default().borders(borders(Borders::ALL)).title(t("releases.title"))),
+        chunks[0],
+    );
+    
+    let footer = match app_state.status_message {
+        Some(ref message) => format!(" {}", message),
+        None => format!(" {}", keymap::footer(Context::Releases, &keymap::State::default())),
+    };
+    frame.render_widget(Paragraph::new(footer).style(muted), chunks[1]);
+}
+
+// A board as drawn in the current layout
+struct BoardView<'a> {
+    board: &'a Board,
+    focused: bool,
+    split: bool,
+}
+
+// Centered popup listing key bindings and a legend of who's on the board
+fn draw_help_overlay(frame: &mut Frame, area: Rect) {
+    let key_style = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+    let heading_style = Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD);
+    let mut lines = vec![Line::from(Span::styled(t("help.board"), heading_style))];
+    
+    for binding in keymap::bindings(Context::Board) {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:<8}", binding.keys), key_style),
+            Span::raw(t(binding.help)),
+        ]));
+    }
+    
+    draw_popup(frame, area, lines, 50, t("help.title"));
+}
+
+// Centered box over whatever is on screen
+fn draw_popup(frame: &mut Frame, area: Rect, lines: Vec<Line>, width: u16, title: &str) {
+    let width = width.min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .borders(borders(Borders::ALL))
+                .title(title.to_string())
+                .border_style(Style::default().fg(Color::Cyan)),
+        ),
+        popup,
+    );
+}
+
+// What the symbols and colors on the board mean, built from the tickets
+// and settings on screen so it only explains what's in use
+fn draw_legend_overlay(frame: &mut Frame, area: Rect, columns: &StatusGroups, display: &DisplayConfig, reminders: &Reminders, hygiene_rules: &[HygieneRule]) {
+    let heading_style = Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD);
+    let tickets = columns.unique_tickets();
+    let mut lines = Vec::new();
+    let section = |lines: &mut Vec<Line>, heading: &str, entries: Vec<(Vec<Span<'static>>, String)>| {
+        if entries.is_empty() {
+            return;
+        }
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(Span::styled(heading.to_string(), heading_style)));
+        for (sample, meaning) in entries {
+            let width: usize = sample.iter().map(|s| s.width()).sum();
+            let mut spans = vec![Span::raw("  ")];
+            spans.extend(sample);
+            spans.push(Span::raw(format!("{:w$}{}", "", meaning, w = 10usize.saturating_sub(width))));
+            lines.push(Line::from(spans));
+        }
+    };
+    
+    // Issue types on the board
+    let mut types: Vec<TicketType> = Vec::new();
+    for ticket in &tickets {
+        if !types.iter().any(|kind| kind.name() == ticket.ticket_type.name()) {
+            types.push(ticket.ticket_type.clone());
+        }
+    }
+    section(&mut lines, t("legend.types"), types.iter()
+        .map(|kind| {
+            let meaning = match kind {
+                TicketType::Bug => t("legend.bug"),
+                TicketType::Story => t("legend.story"),
+                TicketType::Task => t("legend.task"),
+                TicketType::Epic => t("legend.epic_type"),
+            };
+            (vec![Span::raw(kind.icon(display.icons))], meaning.to_string())
+        })
+        .collect());
+    
+    // Lane colors
+    let lanes = columns.groups.iter()
+        .filter(|(_, tickets)| !tickets.is_empty())
+        .map(|(lane, lane_tickets)| {
+            let name = match lane.as_str() {
+                NO_SPRINT => t("board.backlog"),
+                NO_COMPONENT => t("board.no_component"),
+                lane => lane,
+            };
+            (vec![Span::styled("██", Style::default().fg(columns.lane_color(lane, lane_tickets)))], name.to_string())
+        })
+        .collect();
+    let lane_meaning = match columns.group_by {
+        GroupBy::Status => t("legend.lanes_status"),
+        GroupBy::Sprint => t("legend.lanes_sprint"),
+        GroupBy::Component => t("legend.lanes_component"),
+    };
+    section(&mut lines, lane_meaning, lanes);
+    
+    // Card markers, each only when some card shows it
+    let mut markers = vec![(
+        vec![Span::styled("▶ ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))],
+        t("legend.selected").to_string(),
+    )];
+    if tickets.iter().any(|t| t.incident) {
+        markers.push((
+            vec![Span::styled(symbol("🚨", "!!"), Style::default().fg(Color::White).bg(Color::Red).add_modifier(Modifier::BOLD))],
+            t("legend.incident").to_string(),
+        ));
+    }
+    if tickets.iter().any(|t| t.flagged) {
+        markers.push((
+            vec![Span::styled(symbol("🚩", "!"), Style::default().fg(Color::LightRed).bg(Color::Rgb(60, 0, 0)))],
+            t("legend.flagged").to_string(),
+        ));
+    }
+    if tickets.iter().any(|t| !hygiene::problems(t, hygiene_rules).is_empty()) {
+        markers.push((
+            vec![Span::styled(symbol("⚠", "?"), Style::default().fg(Color::Yellow))],
+            t("legend.hygiene").to_string(),
+        ));
+    }
+    let today = Local::now().date_naive();
+    if tickets.iter().any(|t| reminders.get(&t.key).is_some_and(|r| r.is_due(today))) {
+        markers.push((
+            vec![Span::styled(symbol("⏰", "@!"), Style::default().fg(Color::LightYellow))],
+            t("legend.reminder").to_string(),
+        ));
+    }
+    if columns.group_by == GroupBy::Component && tickets.iter().any(|t| t.components.len() > 1) {
+        markers.push((
+            vec![Span::styled(format!("{}2", symbol("⧉", "x")), Style::default().fg(Color::LightBlue))],
+            t("legend.shared").to_string(),
+        ));
+    }
+    if display.show_epic && tickets.iter().any(|t| t.parent.is_some()) {
+        markers.push((vec![Span::styled("[…]", Style::default().fg(Color::Magenta))], t("legend.epic").to_string()));
+    }
+    if columns.group_by != GroupBy::Sprint && columns.spans_multiple_sprints() {
+        markers.push((vec![Span::styled("‹…›", Style::default().fg(Color::Green))], t("legend.sprint").to_string()));
+    }
+    if tickets.iter().any(|t| t.time_tracking.as_ref().and_then(|tt| tt.badge()).is_some()) {
+        markers.push((
+            vec![Span::styled(format!("{}2h/1d", symbol("⏱", "~")), Style::default().fg(Color::DarkGray))],
+            t("legend.time").to_string(),
+        ));
+    }
+    if tickets.iter().any(|t| t.slas.iter().any(|s| !s.done)) {
+        let sla = |icon: &'static str, style: Style, meaning: &'static str| (vec![Span::styled(icon, style)], t(meaning).to_string());
+        markers.push(sla(symbol("⏳", "@"), Style::default().fg(Color::Green), "legend.sla_running"));
+        markers.push(sla(symbol("⏳", "@"), Style::default().fg(Color::Yellow), "legend.sla_last_hour"));
+        markers.push(sla(symbol("⏳", "@"), Style::default().fg(Color::White).bg(Color::Red), "legend.sla_breached"));
+        markers.push(sla(symbol("⏸", "="), Style::default().fg(Color::DarkGray), "legend.sla_paused"));
+    }
+    section(&mut lines, t("legend.markers"), markers);
+    
+    // Assignee legend: initials badge → full name
+    let mut assignees: Vec<&str> = tickets.iter()
+        .map(|t| t.assignee.as_str())
+        .filter(|a| !a.is_empty() && *a != "unassigned")
+        .collect();
+    assignees.sort_unstable();
+    assignees.dedup();
+    if display.assignee_style == AssigneeStyle::Name {
+        assignees.clear();
+    }
+    section(&mut lines, t("help.assignees"), assignees.into_iter()
+        .map(|assignee| (vec![initials_badge(assignee)], assignee.to_string()))
+        .collect());
+    
+    // Query badge legend when several queries feed the board
+    let mut queries: Vec<&str> = tickets.iter()
+        .flat_map(|t| t.queries.iter().map(|q| q.as_str()))
+        .collect();
+    queries.sort_unstable();
+    queries.dedup();
+    section(&mut lines, t("help.queries"), queries.into_iter()
+        .map(|query| (vec![query_badge(query)], query.to_string()))
+        .collect());
+    
+    draw_popup(frame, area, lines, 60, t("legend.title"));
+}
+
+// Tickets breaking the team's rules, under each rule
+fn draw_hygiene_overlay(frame: &mut Frame, area: Rect, board: &Board) {
+    let heading_style = Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD);
+    let tickets = board.columns.unique_tickets();
+    let mut lines = Vec::new();
+    for &rule in &board.config.hygiene.rules {
+        let breaking: Vec<_> = tickets.iter().filter(|t| rule.broken_by(t)).collect();
+        if breaking.is_empty() {
+            continue;
+        }
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(Span::styled(format!("{} ({})", t(rule.label()), breaking.len()), heading_style)));
+        for ticket in breaking {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<12}", ticket.key), Style::default().fg(Color::Cyan)),
+                Span::raw(ticket.summary.clone()),
+            ]));
+        }
+    }
+    if lines.is_empty() {
+        let text = if board.config.hygiene.rules.is_empty() { t("hygiene.no_rules") } else { t("hygiene.clean") };
+        lines.push(Line::from(Span::styled(text, Style::default().fg(Color::DarkGray))));
+    }
+    
+    draw_popup(frame, area, lines, 90, t("hygiene.title"));
+}
+
+// This session's traffic with JIRA, for tuning refresh intervals
+fn draw_stats_overlay(frame: &mut Frame, area: Rect, boards: &[Board]) {
+    let heading_style = Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD);
+    let stats = telemetry::stats();
+    let millis = |d: Option<std::time::Duration>| d.map_or("–".to_string(), |d| format!("{} ms", d.as_millis()));
+    let row = |label: &'static str, value: String| Line::from(vec![
+        Span::styled(format!("  {:<22}", t(label)), Style::default().fg(Color::Yellow)),
+        Span::raw(value),
+    ]);
+    let uptime = telemetry::uptime().as_secs();
+    
+    let mut lines = vec![
+        row("stats.uptime", format!("{}h {:02}m {:02}s", uptime / 3600, uptime / 60 % 60, uptime % 60)),
+        Line::from(""),
+        Line::from(Span::styled(t("stats.refreshes_heading"), heading_style)),
+        row("stats.refreshes", tf("stats.refreshes_value", &[("count", &stats.refreshes), ("failed", &stats.failed_refreshes)])),
+        row("stats.average_refresh", millis(stats.average_refresh())),
+    ];
+    for board in boards {
+        let interval = board.refresh_interval * rate_limit::backoff_factor();
+        lines.push(row("stats.interval", format!("{}s ({})", interval.as_secs(), board.name)));
+    }
+    lines.extend([
+        Line::from(""),
+        Line::from(Span::styled(t("stats.requests_heading"), heading_style)),
+        row("stats.requests", stats.requests.to_string()),
+        row("stats.errors", tf("stats.errors_value", &[("count", &stats.failed_requests), ("unanswered", &stats.unanswered)])),
+        row("stats.average_request", millis(stats.average_request())),
+        row("stats.slowest_request", millis((stats.requests > 0).then_some(stats.slowest_request))),
+        row("stats.downloaded", format_bytes(stats.bytes)),
+        row("stats.api_budget", rate_limit::used_percent().map_or("–".to_string(), |used| tf("stats.api_budget_value", &[("used", &used)]))),
+    ]);
+    
+    draw_popup(frame, area, lines, 60, t("stats.title"));
+}
+
+// A ticket's raw JSON with its keys picked out, scrolled by the caller
+fn draw_raw_overlay(frame: &mut Frame, area: Rect, payload: &mut RawPayload) {
+    let width = 100.min(area.width);
+    let height = area.height.saturating_sub(4).max(3).min(area.height);
+    let visible = height.saturating_sub(2) as usize;
+    let total = payload.json.lines().count();
+    payload.height = visible;
+    payload.scroll = payload.scroll.min(total.saturating_sub(visible));
+    
+    let key_style = Style::default().fg(Color::Cyan);
+    let lines: Vec<Line> = payload.json.lines()
+        .skip(payload.scroll)
+        .take(visible)
+        .map(|line| {
+            let indent = line.len() - line.trim_start().len();
+            // `"name": value`, with the key ending at the first `": `
+            match line.trim_start().starts_with('"').then(|| line.find("\": ")).flatten() {
+                Some(end) => Line::from(vec![
+                    Span::raw(line[..indent].to_string()),
+                    Span::styled(line[indent..end + 1].to_string(), key_style),
+                    Span::raw(line[end + 1..].to_string()),
+                ]),
+                None => Line::from(line.to_string()),
+            }
+        })
+        .collect();
+    
+    let title = tf("raw.title", &[
+        ("key", &payload.key),
+        ("from", &(payload.scroll + 1).min(total)),
+        ("to", &(payload.scroll + visible).min(total)),
+        ("total", &total),
+    ]);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .borders(borders(Borders::ALL))
+                .title(title)
+                .border_style(Style::default().fg(Color::Cyan)),
+        ),
+        popup,
+    );
+}
+
+// "512 B", "3.4 KB", "12.0 MB"
+fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..1024 => format!("{} B", bytes),
+        1024..1_048_576 => format!("{:.1} KB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+// The session's activity log, newest at the bottom
+fn draw_activity_overlay(frame: &mut Frame, area: Rect) {
+    let width = 100.min(area.width);
+    let height = area.height.saturating_sub(4).max(3).min(area.height);
+    let visible = height.saturating_sub(2) as usize;
+    
+    let entries = activity::entries();
+    let mut lines: Vec<Line> = entries[entries.len().saturating_sub(visible)..].iter()
+        .map(|entry| {
+            let color = match entry.kind {
+                activity::Kind::Refresh => Color::DarkGray,
+                activity::Kind::Change => Color::Green,
+                activity::Kind::Error => Color::Red,
+            };
+            Line::from(vec![
+                Span::styled(entry.time.format("%H:%M:%S ").to_string(), Style::default().fg(Color::Gray)),
+                Span::styled(format!("{:<8}", entry.kind.label()), Style::default().fg(color)),
+                Span::raw(entry.message.clone()),
+            ])
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled("Nothing yet", Style::default().fg(Color::DarkGray))));
+    }
+    
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .borders(borders(Borders::ALL))
+                .title(" Activity (L to close) ")
+                .border_style(Style::default().fg(Color::Cyan)),
+        ),
+        popup,
+    );
+}
+
+// Tickets that entered, left or moved columns since a point in time
+fn draw_diff_overlay(frame: &mut Frame, area: Rect, diff: &Result<BoardDiff, String>) {
+    let lines = diff_lines(diff);
+    let title = match diff {
+        Ok(diff) => format!(" Since {} (D to close) ", diff.since.format("%a %H:%M")),
+        Err(_) => " Changes (D to close) ".to_string(),
+    };
+    draw_popup(frame, area, lines, 100, &title);
+}
+
+// Tickets that entered, left or moved lanes, one per line
+fn diff_lines(diff: &Result<BoardDiff, String>) -> Vec<Line<'static>> {
+    let muted = Style::default().fg(Color::DarkGray);
+    let mut lines = Vec::new();
+    match diff {
+        Err(e) => lines.push(Line::from(Span::styled(e.clone(), muted))),
+        Ok(diff) => {
+            if diff.baseline > diff.since {
+                lines.push(Line::from(Span::styled(
+                    format!("History starts at {}", diff.baseline.format("%a %H:%M")),
+                    muted,
+                )));
+            }
+            if diff.changes.is_empty() {
+                lines.push(Line::from(Span::styled("No changes", muted)));
+            }
+            for change in &diff.changes {
+                let (marker, color, ticket, status) = match change {
+                    Change::Entered(t) => ("+", Color::Green, t, t.status.clone()),
+                    Change::Left(t) => ("-", Color::Red, t, t.status.clone()),
+                    Change::Moved { ticket, from } => ("~", Color::Yellow, ticket, format!("{} → {}", from, ticket.status)),
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{} ", marker), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                    Span::styled(format!("{:<12}", ticket.key), Style::default().fg(Color::Cyan)),
+                    Span::styled(format!("{:<26} ", status), Style::default().fg(color)),
+                    Span::raw(ticket.summary.clone()),
+                ]));
+            }
+        }
+    }
+    lines
+}
+
+// A summary on the first start of the day, until dismissed
+fn draw_catch_up_overlay(frame: &mut Frame, area: Rect, catch_up: &CatchUp) {
+    let muted = Style::default().fg(Color::DarkGray);
+    let heading = Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD);
+    let mut lines = vec![Line::from(Span::styled(t("catch_up.board"), heading))];
+    lines.extend(diff_lines(&catch_up.board));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(t("catch_up.comments"), heading)));
+    match &catch_up.comments {
+        Err(e) => lines.push(Line::from(Span::styled(e.clone(), muted))),
+        Ok(comments) if comments.is_empty() => lines.push(Line::from(Span::styled(t("catch_up.none"), muted))),
+        Ok(comments) => {
+            for comment in comments {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{:<12}", comment.key), Style::default().fg(Color::Cyan)),
+                    Span::styled(format!("{}: ", comment.author), Style::default().fg(Color::Blue)),
+                    Span::raw(comment.body.lines().next().unwrap_or_default().to_string()),
+                ]));
+            }
+        }
+    }
+    let title = tf("catch_up.title", &[("since", &catch_up.since.format("%a %H:%M"))]);
+    draw_popup(frame, area, lines, 100, &title);
+}
+
+// The blocking chain as two trees: what holds the ticket up, then what it
+// holds up. Done tickets are dimmed, since they no longer block anything.
+fn draw_blockers_overlay(frame: &mut Frame, area: Rect, chain: &Result<Chain, String>) {
+    fn tree(lines: &mut Vec<Line>, nodes: &[Node], indent: &str) {
+        let muted = Style::default().fg(Color::DarkGray);
+        for (i, node) in nodes.iter().enumerate() {
+            let last = i + 1 == nodes.len();
+            let done = get_status_color(&node.status, None) == Color::Green;
+            let mut spans = vec![
+                Span::styled(format!("{}{} ", indent, if last { "└─" } else { "├─" }), muted),
+                Span::styled(node.key.clone(), if done { muted } else { Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD) }),
+                Span::styled(format!(" [{}] ", node.status), Style::default().fg(get_status_color(&node.status, None))),
+                Span::styled(node.summary.clone(), if done { muted } else { Style::default() }),
+            ];
+            if let Some(cut) = node.cut {
+                spans.push(Span::styled(format!(" ({})", cut), muted));
+            }
+            lines.push(Line::from(spans));
+            tree(lines, &node.children, &format!("{}{}", indent, if last { "   " } else { "│  " }));
+        }
+    }
+    
+    let muted = Style::default().fg(Color::DarkGray);
+    let heading = Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD);
+    let mut lines = Vec::new();
+    match chain {
+        Err(e) => lines.push(Line::from(Span::styled(e.clone(), muted))),
+        Ok(chain) => {
+            for (label, nodes) in [("blockers.blocked_by", &chain.blocked_by), ("blockers.blocks", &chain.blocks)] {
+                lines.push(Line::from(Span::styled(t(label), heading)));
+                if nodes.is_empty() {
+                    lines.push(Line::from(Span::styled(format!("  {}", t("blockers.nothing")), muted)));
+                }
+                tree(&mut lines, nodes, "  ");
+            }
+        }
+    }
+    
+    let title = match chain {
+        Ok(chain) => tf("blockers.title", &[("key", &chain.key)]),
+        Err(_) => t("blockers.title_failed").to_string(),
+    };
+    draw_popup(frame, area, lines, 100, &title);
+}
+
+// The aging WIP chart: a column per status and a dot per ticket, as high as
+// its days in that status and colored by how stale it is, with the oldest
+// listed underneath
+fn draw_aging_overlay(frame: &mut Frame, area: Rect, aging: &Aging) {
+    let muted = Style::default().fg(Color::DarkGray);
+    let title = t("aging.title");
+    if let Some(ref e) = aging.error {
+        draw_popup(frame, area, vec![Line::from(Span::styled(e.clone(), muted))], 80, title);
+        return;
+    }
+    if aging.columns.is_empty() {
+        draw_popup(frame, area, vec![Line::from(Span::styled(t("aging.empty"), muted))], 80, title);
+        return;
+    }
+    
+    let width = area.width.saturating_sub(8).clamp(40.min(area.width), 120);
+    let height = area.height.saturating_sub(4).clamp(16.min(area.height), 36);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    let block = Block::default()
+        .borders(borders(Borders::ALL))
+        .title(title)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(popup);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(block, popup);
+    
+    let oldest = aging.oldest();
+    let listed = oldest.len().min(5);
+    let [chart_area, list_area] = Layout::vertical([Constraint::Min(6), Constraint::Length(listed as u16 + 1)]).areas(inner);
+    
+    // Dots by color; a column's tickets spread a little sideways so ones
+    // of the same age don't cover each other
+    let mut points: [Vec<(f64, f64)>; 3] = Default::default();
+    for (i, column) in aging.columns.iter().enumerate() {
+        let count = column.tickets.len();
+        for (j, ticket) in column.tickets.iter().enumerate() {
+            let spread = if count > 1 { (j as f64 / (count - 1) as f64 - 0.5) * 0.5 } else { 0.0 };
+            points[aging_level(ticket.days)].push((i as f64 + 1.0 + spread, ticket.days));
+        }
+    }
+    let datasets = points.iter()
+        .zip([Color::Green, Color::Yellow, Color::Red])
+        .map(|(points, color)| Dataset::default()
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Scatter)
+            .style(Style::default().fg(color))
+            .data(points))
+        .collect();
+    
+    // Labels spread evenly over the axis, so one per column lands on its
+    // dots, with blanks at both ends for margin
+    let columns = aging.columns.len();
+    let label_width = (chart_area.width as usize / (columns + 1)).saturating_sub(1).max(3);
+    let mut x_labels = vec![Line::from("")];
+    x_labels.extend(aging.columns.iter().map(|c| Line::from(c.status.chars().take(label_width).collect::<String>())));
+    x_labels.push(Line::from(""));
+    let top = oldest.first().map_or(0.0, |(_, t)| t.days).max(aging::WATCH_DAYS).ceil();
+    let chart = Chart::new(datasets)
+        .x_axis(Axis::default().bounds([0.0, columns as f64 + 1.0]).labels(x_labels).style(muted))
+        .y_axis(Axis::default()
+            .title(t("aging.days"))
+            .bounds([0.0, top])
+            .labels([0.0, top / 2.0, top].map(|d| Line::from(format!("{:.0}", d))))
+            .style(muted));
+    frame.render_widget(chart, chart_area);
+    
+    let mut lines = vec![Line::from(Span::styled(t("aging.oldest"), Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD)))];
+    for (status, ticket) in oldest.into_iter().take(listed) {
+        let color = [Color::Green, Color::Yellow, Color::Red][aging_level(ticket.days)];
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<12}", ticket.key), Style::default().fg(Color::Cyan)),
+            Span::styled(format!("{:>5.1}d ", ticket.days), Style::default().fg(color)),
+            Span::styled(format!("{:<16} ", status), muted),
+            Span::raw(ticket.summary.clone()),
+        ]));
+    }
+    frame.render_widget(Paragraph::new(lines), list_area);
+}
+
+// 0 while fresh, 1 when worth a look, 2 when stale
+fn aging_level(days: f64) -> usize {
+    if days < aging::FRESH_DAYS {
+        0
+    } else if days < aging::WATCH_DAYS {
+        1
+    } else {
+        2
+    }
 }
 
 fn draw_kanban_board(
     frame: &mut Frame, 
     area: Rect, 
-    columns: &StatusGroups,
-    last_update: Option<&chrono::DateTime<chrono::Local>>,
+    view: &BoardView,
     paused: bool,
-    refresh_seconds: u64,
     app_state: &AppState,
 ) {
+    if a11y() {
+        draw_linear_board(frame, area, view, paused, app_state);
+        return;
+    }
     // Always use horizontal lanes for better space utilization
-    draw_horizontal_lanes(frame, area, columns, last_update, paused, refresh_seconds, app_state);
+    draw_horizontal_lanes(frame, area, view, paused, app_state);
+}
+
+// Box drawing reads as noise to screen readers, so `--a11y` drops it
+fn borders(borders: Borders) -> Borders {
+    if a11y() { Borders::NONE } else { borders }
+}
+
+// The board for screen readers: a status sentence, then each lane as a
+// numbered section with one line per ticket. The selection is marked with
+// ">" rather than color alone, and the terminal cursor sits on it so
+// readers that follow the cursor announce it.
+fn draw_linear_board(
+    frame: &mut Frame,
+    area: Rect,
+    view: &BoardView,
+    paused: bool,
+    app_state: &AppState,
+) {
+    let board = view.board;
+    let columns = &board.columns;
+    
+    let mut status = String::from("KANBARS");
+    if view.split {
+        status.push_str(&format!(", board {}", board.name));
+    }
+    status.push_str(&format!(". Updated {}. {}.", board.last_update.format("%H:%M"), count_tickets(columns.total_tickets())));
+    if let Some(total) = board.total_matches {
+        status.push_str(&format!(" Showing {} of {}.", columns.total_tickets(), total));
+    }
+    if let Some(ref loading) = board.loading {
+        match loading.pages_total {
+            Some(total) => status.push_str(&format!(" Loading, page {} of {}.", loading.pages_done, total)),
+            None => status.push_str(" Loading."),
+        }
+    }
+    if app_state.idle {
+        status.push_str(" Idle, paused until a key is pressed.");
+    } else if paused {
+        status.push_str(" Paused.");
+    }
+    if let Some((ref label, _)) = board.filter {
+        status.push_str(&format!(" Filtered to {}, Escape clears.", label));
+    }
+    if let Some(ref assignee) = board.assignee {
+        status.push_str(&format!(" {}", tf("board.assignee_status", &[("assignee", assignee)])));
+    }
+    if let Some(ref error) = board.last_error {
+        status.push_str(&format!(" Refresh failed: {}.", error));
+    }
+    if !app_state.setup_needed.is_empty() {
+        status.push_str(" Read-only, JIRA is not set up. Press s for setup.");
+    }
+    if view.focused {
+        match app_state.status_message {
+            Some(ref message) => status.push_str(&format!(" {}", message)),
+            None => status.push_str(" Press question mark for help."),
+        }
+    }
+    
+    if let Some(ref api_error) = board.api_error {
+        draw_api_error(frame, area, board, api_error, Span::raw(status));
+        return;
+    }
+    
+    let mut lines = vec![Line::from(status), Line::from("")];
+    let mut selected_line = None;
+    let lanes: Vec<_> = columns.groups.iter().filter(|(_, tickets)| !tickets.is_empty()).collect();
+    if lanes.is_empty() {
+        lines.push(Line::from(match board.config.display.empty_message {
+            _ if board.loading.is_some() => "Loading.".to_string(),
+            Some(ref message) => message.clone(),
+            None => "No tickets found.".to_string(),
+        }));
+    }
+    let mut index = 0;
+    for (i, (lane, tickets)) in lanes.iter().enumerate() {
+        let stale = if tickets.iter().any(|t| board.stale.contains(&t.key)) { " Some may be out of date." } else { "" };
+        let points = total_points(tickets).map(|p| format!(", {} points", p)).unwrap_or_default();
+        lines.push(Line::from(Span::styled(
+            format!("Section {} of {}: {}, {}{}.{}", i + 1, lanes.len(), lane, count_tickets(tickets.len()), points, stale),
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+        for ticket in tickets.iter() {
+            if index == board.selected_index {
+                selected_line = Some(lines.len());
+                lines.push(Line::from(Span::styled(
+                    format!("> {}", ticket.describe()),
+                    Style::default().add_modifier(Modifier::REVERSED),
+                )));
+            } else {
+                lines.push(Line::from(format!("  {}", ticket.describe())));
+            }
+            index += 1;
+        }
+        lines.push(Line::from(""));
+    }
+    
+    // Keep the selection in view, roughly centred once the list scrolls
+    let height = area.height as usize;
+    let scroll = match selected_line {
+        Some(line) if line >= height => line.saturating_sub(height / 2),
+        _ => 0,
+    };
+    if view.focused
+        && let Some(line) = selected_line
+    {
+        frame.set_cursor_position((area.x, area.y + (line - scroll) as u16));
+    }
+    frame.render_widget(Paragraph::new(lines).scroll((scroll as u16, 0)), area);
 }
 
 fn draw_horizontal_lanes(
     frame: &mut Frame, 
     area: Rect, 
-    columns: &StatusGroups,
-    last_update: Option<&chrono::DateTime<chrono::Local>>,
+    view: &BoardView,
     paused: bool,
-    refresh_seconds: u64,
     app_state: &AppState,
 ) {
+    let board = view.board;
+    let columns = &board.columns;
+    
+    // A configured title replaces the name, update time, counts and key
+    // hints; warnings and feedback are still added after it
+    let template = board.config.display.title.as_deref();
+    let mut title_str = match template {
+        Some(template) => fill_title(template, board),
+        None => String::from("🦀 KANBARS"),
+    };
+    
+    // Name each board when several share the screen, or take turns on a
+    // wallboard
+    if (view.split || app_state.wallboard) && template.is_none() {
+        title_str.push_str(&format!(" [{}]", board.name));
+    }
+    let title_style = if view.split && view.focused {
+        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+    
     // Build active lanes from dynamic status groups
     let mut active_lanes = Vec::new();
     for (status, tickets) in &columns.groups {
         if !tickets.is_empty() {
-            let color = get_status_color(status);
+            let color = columns.lane_color(status, tickets);
             active_lanes.push((status.as_str(), tickets, color));
         }
     }
     
+    // Errors a retry on the timer won't fix get a screen of their own
+    if let Some(ref api_error) = board.api_error {
+        draw_api_error(frame, area, board, api_error, Span::styled(title_str, title_style));
+        return;
+    }
+    
     // If no tickets at all, show a message
     if active_lanes.is_empty() {
-        let message = Paragraph::new("No tickets found! 🎉")
+        let message = match board.config.display.empty_message {
+            _ if board.loading.is_some() => t("board.loading_board"),
+            Some(ref message) => message.as_str(),
+            None => t("board.empty"),
+        };
+        let message = Paragraph::new(message)
             .block(Block::default()
-                .borders(Borders::ALL)
-                .title("🦀 KANBARS"))
+                .borders(borders(Borders::ALL))
+                .title(Span::styled(title_str, title_style)))
             .style(Style::default().fg(Color::DarkGray));
         frame.render_widget(message, area);
         return;
@@ -109,77 +1834,210 @@ fn draw_horizontal_lanes(
         .constraints(lane_constraints)
         .split(main_chunks[1]);
     
-    // Title with status information
-    let mut title_str = String::from("🦀 KANBARS");
+    if template.is_none() {
+        // Title with status information
+        title_str.push_str(&format!(" | {}", tf("board.updated", &[("time", &board.last_update.format("%H:%M:%S"))])));
+        
+        // Warn when the board is a partial view of the query
+        if let Some(total) = board.total_matches {
+            title_str.push_str(&format!(" | {}", tf("board.showing", &[("shown", &columns.total_tickets()), ("total", &total)])));
+        }
+        
+        // Note which backend the data came from
+        title_str.push_str(&format!(" | {}", tf("board.via", &[("source", &board.data_source)])));
+    }
+    
+    // Only part of the query is showing
+    if let Some((ref label, _)) = board.filter {
+        title_str.push_str(&format!(" | {}", tf("board.filtered", &[("filter", label)])));
+    }
+    if let Some(ref assignee) = board.assignee {
+        title_str.push_str(&format!(" | {}", tf("board.assignee", &[("assignee", assignee)])));
+    }
     
-    // Add last update time
-    if let Some(update_time) = last_update {
-        title_str.push_str(&format!(" | Updated: {}", update_time.format("%H:%M:%S")));
+    // API budget, once JIRA reports it's getting used up
+    if let Some(used) = rate_limit::used_percent().filter(|used| *used >= 50) {
+        title_str.push_str(&format!(" | {}", tf("board.api_budget", &[("used", &used)])));
     }
     
-    // Add refresh status
-    if paused {
-        title_str.push_str(" | ⏸ PAUSED");
-    } else {
-        title_str.push_str(&format!(" | ↻ {}s", refresh_seconds));
+    // A failed refresh keeps the old tickets on screen, so say so
+    if let Some(ref error) = board.last_error {
+        title_str.push_str(&format!(" | ⚠ {}", error));
+    }
+    
+    // Pages still streaming in
+    if let Some(ref loading) = board.loading {
+        let spinner = spinner(app_state.tick as usize);
+        match loading.pages_total {
+            Some(total) => title_str.push_str(&format!(" | {} {}", spinner, tf("board.loading_pages", &[("done", &loading.pages_done), ("total", &total)]))),
+            None => title_str.push_str(&format!(" | {} {}", spinner, t("board.loading"))),
+        }
+    }
+    
+    // Add refresh status, counting down to the next one
+    if app_state.idle {
+        title_str.push_str(&format!(" | {}", t("board.idle")));
+    } else if paused {
+        title_str.push_str(&format!(" | {}", t("board.paused")));
+    } else if template.is_none() && board.loading.is_none() {
+        let due = board.next_refresh.saturating_duration_since(Instant::now());
+        if board.off_hours() {
+            title_str.push_str(&format!(" | {}", t("board.off_hours")));
+        }
+        title_str.push_str(&format!(" | ↻ {}", countdown(due)));
+    }
+    
+    // A cached board can't be changed or refreshed
+    if !app_state.setup_needed.is_empty() {
+        title_str.push_str(&format!(" | {}", t("board.read_only")));
     }
     
-    // Add controls hint
-    title_str.push_str(" | q:quit r:refresh p:pause ↑↓/jk:navigate Enter:detail");
+    if app_state.zoom != 0 {
+        title_str.push_str(&format!(" | {}{:+}", symbol("🔍", "zoom "), app_state.zoom));
+    }
     
     let title = Block::default()
-        .borders(Borders::BOTTOM)
-        .title(title_str);
+        .borders(borders(Borders::BOTTOM))
+        .title(Span::styled(title_str, title_style));
     frame.render_widget(title, main_chunks[0]);
     
+    // Sprint tags only help when there's more than one sprint to tell apart
+    let card_options = CardOptions {
+        display: &app_state.display,
+        show_sprint: columns.group_by != GroupBy::Sprint && columns.spans_multiple_sprints(),
+        mark_shared: columns.group_by == GroupBy::Component,
+        stale: &board.stale,
+        changed: &board.changed,
+        tick: app_state.tick,
+        density: board.density,
+        zoom: app_state.zoom,
+        editing: app_state.summary_edit.as_ref()
+            .filter(|_| view.focused)
+            .map(|(key, text)| (key.as_str(), text.as_str())),
+        reminders: &app_state.reminders,
+        hygiene: &board.config.hygiene.rules,
+    };
+    
     // Render only non-empty lanes with proper selection tracking
     let mut global_ticket_index = 0;
     for (i, (title, tickets, color)) in active_lanes.iter().enumerate() {
         // Calculate which ticket in this lane is selected (if any)
-        let selected_ticket = if app_state.selected_index >= global_ticket_index && 
-                                 app_state.selected_index < global_ticket_index + tickets.len() {
-            Some(app_state.selected_index - global_ticket_index)
+        let selected_ticket = if view.focused && !app_state.wallboard && board.selected_index >= global_ticket_index && 
+                                 board.selected_index < global_ticket_index + tickets.len() {
+            Some(board.selected_index - global_ticket_index)
         } else {
             None
         };
         
-        draw_lane(frame, lane_chunks[i], tickets, title, *color, selected_ticket);
+        draw_lane(frame, lane_chunks[i], tickets, title, *color, selected_ticket, &card_options);
         global_ticket_index += tickets.len();
     }
 }
 
-fn draw_lane(frame: &mut Frame, area: Rect, tickets: &[Ticket], title: &str, color: Color, selected_ticket: Option<usize>) {
+// `display.title` with its placeholders filled in
+fn fill_title(template: &str, board: &Board) -> String {
+    let count = board.columns.total_tickets();
+    let values = [
+        ("{app}", "🦀 KANBARS".to_string()),
+        ("{board}", board.name.clone()),
+        ("{query}", board.config.query.jql.clone()),
+        ("{updated}", board.last_update.format("%H:%M:%S").to_string()),
+        ("{count}", count.to_string()),
+        ("{total}", board.total_matches.unwrap_or(count).to_string()),
+        ("{source}", board.data_source.clone()),
+    ];
+    values.iter().fold(template.to_string(), |title, (placeholder, value)| title.replace(placeholder, value))
+}
+
+// Board-wide settings that affect how each card is drawn
+struct CardOptions<'a> {
+    display: &'a DisplayConfig,
+    show_sprint: bool,
+    mark_shared: bool,  // Note tickets that also sit in other component lanes
+    stale: &'a HashSet<String>,  // Tickets kept from before a partly failed refresh
+    changed: &'a HashMap<String, Instant>,  // Tickets that changed in a recent refresh, and when
+    tick: u64,
+    density: Density,
+    zoom: i8,
+    editing: Option<(&'a str, &'a str)>,  // Card whose summary is being edited, and the new text
+    reminders: &'a Reminders,
+    hygiene: &'a [HygieneRule],  // Rules a card is marked for breaking
+}
+
+fn draw_lane(
+    frame: &mut Frame,
+    area: Rect,
+    tickets: &[Ticket],
+    title: &str,
+    color: Color,
+    selected_ticket: Option<usize>,
+    options: &CardOptions,
+) {
+    let display = options.display;
+    // Zoomed in, cards drop their secondary badges, then assignee names
+    let badges = options.zoom < 1;
+    let show_sprint = options.show_sprint && badges;
+    let now = Utc::now();
+    let today = Local::now().date_naive();
+    // Two ticks on, two off
+    let pulse = (options.tick / 2).is_multiple_of(2);
     // Split lane into label and content
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Length(12),  // Lane label
+            Constraint::Length(LABEL_WIDTHS[(options.zoom - ZOOM.start()) as usize]),  // Lane label
             Constraint::Min(0),      // Tickets
         ])
         .split(area);
     
+    // The sprintless and componentless lanes are named by kanbars, so
+    // they're the lane titles that get translated
+    let title = match title {
+        NO_SPRINT => t("board.backlog"),
+        NO_COMPONENT => t("board.no_component"),
+        _ => title,
+    };
+    // Warn when the lane holds tickets a failed query couldn't update
+    let title = if tickets.iter().any(|t| options.stale.contains(&t.key)) {
+        format!("⚠ {}", title)
+    } else {
+        title.to_string()
+    };
+    
     // Lane label with colored border
     let label = Block::default()
-        .borders(Borders::RIGHT)
+        .borders(borders(Borders::RIGHT))
         .border_style(Style::default().fg(color))
         .title(title)
         .title_style(Style::default().fg(color).add_modifier(Modifier::BOLD));
+    // Under the title, what the lane holds, e.g. "(7 | 21pts)"
+    let tally = match total_points(tickets) {
+        Some(points) => format!("({} | {})", tickets.len(), tf("board.points", &[("points", &points)])),
+        None => format!("({})", tickets.len()),
+    };
+    let tally_area = label.inner(chunks[0]);
     frame.render_widget(label, chunks[0]);
+    frame.render_widget(
+        Paragraph::new(tally).style(Style::default().fg(Color::DarkGray)).wrap(Wrap { trim: true }),
+        tally_area,
+    );
     
-    // Build ticket lines
+    // Build ticket lines, leaving the last column for a scrollbar
     let mut lines: Vec<Line> = Vec::new();
-    let content_width = chunks[1].width as usize;
+    let mut starts = Vec::new();  // First line of each ticket
+    let content_width = (chunks[1].width as usize).saturating_sub(1);
     
     for (i, ticket) in tickets.iter().enumerate() {
         let is_selected = selected_ticket == Some(i);
         
-        if i > 0 && lines.len() < area.height as usize - 1 {
+        if i > 0 && options.density != Density::Compact {
             // Add subtle separator between tickets
             lines.push(Line::from(""));
         }
+        starts.push(lines.len());
         
         // Format ticket on 1-2 lines
-        let emoji = ticket.ticket_type.emoji();
+        let emoji = ticket.ticket_type.icon(display.icons);
         let key = &ticket.key;
         let summary = &ticket.summary;
         
@@ -190,15 +2048,20 @@ fn draw_lane(frame: &mut Frame, area: Rect, tickets: &[Ticket], title: &str, col
             .unwrap_or(&ticket.assignee)
             .trim();
         
-        // First line: emoji + key + assignee + as much summary as fits
-        let prefix = if !assignee.is_empty() && assignee != "unassigned" {
-            format!("{} {} @{} ", emoji, key, assignee)
-        } else {
-            format!("{} {} ", emoji, key)
-        };
-        let prefix_len = prefix.len() + 3; // +3 for " • "
+        // Short parent/epic tag, e.g. "[Checkout rev…]"
+        let epic_tag = ticket.parent.as_ref()
+            .filter(|_| display.show_epic && badges)
+            .map(|p| format!("[{}]", abbreviate(if p.summary.is_empty() { &p.key } else { &p.summary }, 14)));
+        
+        let has_assignee = !assignee.is_empty() && assignee != "unassigned";
+        let show_initials = has_assignee && display.assignee_style != AssigneeStyle::Name;
+        let show_name = has_assignee && display.assignee_style != AssigneeStyle::Initials && options.zoom < 2;
         
-        let available_for_summary = content_width.saturating_sub(prefix_len);
+        // First line: emoji + key + assignee + epic + as much summary as fits
+        let sprint_tag = ticket.sprint.as_ref()
+            .filter(|_| show_sprint)
+            .map(|s| (format!("‹{}›", abbreviate(&s.name, 10)), s.state.color()));
+        let time_badge = ticket.time_tracking.as_ref().filter(|_| badges).and_then(|t| t.badge());
         
         // Build the main ticket line
         let key_style = if is_selected {
@@ -206,6 +2069,14 @@ fn draw_lane(frame: &mut Frame, area: Rect, tickets: &[Ticket], title: &str, col
         } else {
             Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
         };
+        // A ticket that just changed lights up, fading back over a few seconds
+        let key_style = match options.changed.get(key).map(Instant::elapsed) {
+            Some(age) if age < HIGHLIGHT => {
+                let left = 1.0 - age.as_secs_f32() / HIGHLIGHT.as_secs_f32();
+                key_style.bg(Color::Rgb((120.0 * left) as u8, (100.0 * left) as u8, (20.0 * left) as u8))
+            }
+            _ => key_style,
+        };
         
         let mut main_line_spans = vec![];
         
@@ -221,20 +2092,116 @@ fn draw_lane(frame: &mut Frame, area: Rect, tickets: &[Ticket], title: &str, col
             Span::styled(key.clone(), key_style),
         ]);
         
-        // Add assignee if present
-        if !assignee.is_empty() && assignee != "unassigned" {
+        // Incidents get a marker that pulses between reds
+        if ticket.incident {
+            let bg = if pulse { Color::Red } else { Color::Rgb(110, 0, 0) };
+            main_line_spans.push(Span::raw(" "));
+            main_line_spans.push(Span::styled(
+                symbol("🚨", "!!"),
+                Style::default().fg(Color::White).bg(bg).add_modifier(Modifier::BOLD),
+            ));
+        }
+        
+        // Breaks one of the team's rules, listed with `H`
+        if badges && !hygiene::problems(ticket, options.hygiene).is_empty() {
+            main_line_spans.push(Span::styled(format!(" {}", symbol("⚠", "?")), Style::default().fg(Color::Yellow)));
+        }
+        
+        // A follow-up you set is due
+        if options.reminders.get(&ticket.key).is_some_and(|r| r.is_due(today)) {
+            main_line_spans.push(Span::styled(format!(" {}", symbol("⏰", "@!")), Style::default().fg(Color::LightYellow)));
+        }
+        
+        // Flagged (impediment) tickets get a flag and a red-tinted summary
+        let (summary_style, continuation_style) = if ticket.flagged {
+            main_line_spans.push(Span::raw(format!(" {}", symbol("🚩", "!"))));
+            let tint = Style::default().fg(Color::LightRed).bg(Color::Rgb(60, 0, 0));
+            (tint, tint)
+        } else {
+            (Style::default(), Style::default().fg(Color::Gray))
+        };
+        
+        // Add assignee if present, as an initials badge and/or name
+        if show_initials {
+            main_line_spans.push(Span::raw(" "));
+            main_line_spans.push(initials_badge(&ticket.assignee));
+        }
+        if show_name {
             main_line_spans.push(Span::styled(
                 format!(" @{}", assignee),
                 Style::default().fg(Color::Blue),
             ));
         }
         
+        if let (Some(tag), Some(parent)) = (epic_tag, ticket.parent.as_ref()) {
+            main_line_spans.push(Span::styled(
+                format!(" {}", tag),
+                Style::default().fg(hashed_color(&parent.key)),
+            ));
+        }
+        
+        // One colored letter per matching query, when several are configured
+        for query in ticket.queries.iter().filter(|_| badges) {
+            main_line_spans.push(Span::raw(" "));
+            main_line_spans.push(query_badge(query));
+        }
+        
+        // The same card is in this many component lanes
+        if options.mark_shared && badges && ticket.components.len() > 1 {
+            main_line_spans.push(Span::styled(
+                format!(" {}{}", symbol("⧉", "x"), ticket.components.len()),
+                Style::default().fg(Color::LightBlue),
+            ));
+        }
+        
+        if let Some((tag, color)) = sprint_tag {
+            main_line_spans.push(Span::styled(format!(" {}", tag), Style::default().fg(color)));
+        }
+        
+        if let Some(badge) = time_badge {
+            main_line_spans.push(Span::styled(
+                format!(" {}{}", symbol("⏱", "~"), badge),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        
+        // Service Management clocks still running
+        for sla in ticket.slas.iter().filter(|s| !s.done) {
+            main_line_spans.push(Span::raw(" "));
+            main_line_spans.push(sla_badge(sla, now));
+        }
+        
         main_line_spans.push(Span::styled(" • ", Style::default().fg(Color::DarkGray)));
         
+        // Measure in terminal cells rather than bytes so emoji and accented
+        // text don't throw off the wrap point
+        let prefix_width: usize = main_line_spans.iter().map(|s| s.width()).sum();
+        let available_for_summary = content_width.saturating_sub(prefix_width);
+        
         // Add summary text and handle wrapping
-        if summary.len() <= available_for_summary {
+        if let Some((_, text)) = options.editing.filter(|(key, _)| *key == ticket.key) {
+            // Inline edit: one line, scrolled so the cursor stays visible
+            let mut visible: Vec<char> = Vec::new();
+            let mut width = 1;  // The cursor
+            for c in text.chars().rev() {
+                width += text_width(c.encode_utf8(&mut [0; 4]));
+                if width > available_for_summary {
+                    break;
+                }
+                visible.push(c);
+            }
+            let visible: String = visible.into_iter().rev().collect();
+            main_line_spans.push(Span::styled(
+                format!("{}▏", visible),
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::UNDERLINED),
+            ));
+            lines.push(Line::from(main_line_spans));
+        } else if text_width(summary) <= available_for_summary {
             // Simple case: everything fits on one line
-            main_line_spans.push(Span::raw(summary.clone()));
+            main_line_spans.push(Span::styled(summary.clone(), summary_style));
+            lines.push(Line::from(main_line_spans));
+        } else if options.density == Density::Compact {
+            main_line_spans.push(Span::styled(cut_to_width(summary, available_for_summary), summary_style));
             lines.push(Line::from(main_line_spans));
         } else {
             // Need to wrap to second line
@@ -244,14 +2211,15 @@ fn draw_lane(frame: &mut Frame, area: Rect, tickets: &[Ticket], title: &str, col
             let mut current_len = 0;
             
             for word in &words {
-                if current_len + word.len() + 1 <= available_for_summary {
+                let word_width = text_width(word);
+                if current_len + word_width < available_for_summary {
                     if !first_line.is_empty() {
                         first_line.push(' ');
                         current_len += 1;
                     }
                     first_line.push_str(word);
-                    current_len += word.len();
-                } else if second_line.is_empty() || second_line.len() + word.len() + 1 <= content_width - 4 {
+                    current_len += word_width;
+                } else if second_line.is_empty() || text_width(&second_line) + word_width < content_width.saturating_sub(4) {
                     if !second_line.is_empty() {
                         second_line.push(' ');
                     }
@@ -259,38 +2227,56 @@ fn draw_lane(frame: &mut Frame, area: Rect, tickets: &[Ticket], title: &str, col
                 }
             }
             
-            main_line_spans.push(Span::raw(first_line));
+            main_line_spans.push(Span::styled(first_line, summary_style));
             lines.push(Line::from(main_line_spans));
             
             // Add continuation line if we have more text
             if !second_line.is_empty() {
                 lines.push(Line::from(vec![
                     Span::raw("    "), // Indent
-                    Span::styled(second_line, Style::default().fg(Color::Gray)),
+                    Span::styled(second_line, continuation_style),
                 ]));
             }
         }
         
-        // Stop if we're running out of vertical space
-        if lines.len() >= area.height as usize - 1 {
-            break;
+        if options.density == Density::Detailed
+            && let Some(line) = details_line(ticket)
+        {
+            lines.push(line);
         }
     }
     
-    // Add overflow indicator if needed
-    if tickets.len() > tickets.iter().take_while(|_| lines.len() < area.height as usize - 1).count() {
-        let remaining = tickets.len() - tickets.iter().take_while(|_| lines.len() < area.height as usize - 1).count();
-        lines.push(Line::from(Span::styled(
-            format!("  ...and {} more", remaining),
-            Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
-        )));
-    }
+    // Scroll just far enough to keep the selected card in view
+    let height = chunks[1].height as usize;
+    let total_lines = lines.len();
+    let scroll = match selected_ticket {
+        Some(i) => starts.get(i + 1).map_or(total_lines, |next| next - 1).saturating_sub(height),
+        None => 0,
+    };
     
     let content = Paragraph::new(lines)
-        .block(Block::default().borders(Borders::NONE))
-        .style(Style::default());
+        .block(Block::default().borders(borders(Borders::NONE)))
+        .style(Style::default())
+        .scroll((scroll as u16, 0));
     
     frame.render_widget(content, chunks[1]);
+    draw_scrollbar(frame, chunks[1], total_lines, scroll, height);
+}
+
+// A scrollbar on the right edge of `area`, when there's more to see
+fn draw_scrollbar(frame: &mut Frame, area: Rect, total_lines: usize, scroll: usize, visible_lines: usize) {
+    if total_lines <= visible_lines || a11y() {
+        return;
+    }
+    let max_scroll = total_lines - visible_lines;
+    let mut state = ScrollbarState::new(max_scroll)
+        .position(scroll.min(max_scroll))
+        .viewport_content_length(visible_lines);
+    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None)
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_stateful_widget(scrollbar, area, &mut state);
 }
 
 fn draw_ticket_detail(frame: &mut Frame, area: Rect, app_state: &mut AppState) {
@@ -309,22 +2295,70 @@ fn draw_ticket_detail(frame: &mut Frame, area: Rect, app_state: &mut AppState) {
     
     // Header with ticket key and type
     let header = Block::default()
-        .borders(Borders::BOTTOM)
+        .borders(borders(Borders::BOTTOM))
         .title(format!("{} {} - {}", 
-            ticket.ticket_type.emoji(),
+            ticket.ticket_type.icon(app_state.display.icons),
             ticket.key,
             ticket.summary
         ))
         .title_style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+    
+    // Watcher and vote counts on the right of the header
+    let mut counts = Vec::new();
+    if let Some(watches) = ticket.watch_count {
+        counts.push(format!("👁 {}", watches));
+    }
+    if let Some(votes) = ticket.vote_count {
+        counts.push(format!("👍 {}", votes));
+    }
+    let header = if counts.is_empty() {
+        header
+    } else {
+        header.title(Line::styled(format!(" {} ", counts.join("  ")), Style::default().fg(Color::Gray)).right_aligned())
+    };
     frame.render_widget(header, chunks[0]);
     
-    // Build content lines
+    // Once the fields scroll away, keep the essentials pinned under the
+    // title so a long description doesn't lose its context
+    if app_state.detail_scroll > 0 && chunks[0].height >= 3 {
+        let mut pinned = Vec::new();
+        if ticket.flagged {
+            pinned.push(Span::styled(format!("{} ", symbol("🚩", "!")), Style::default().fg(Color::LightRed)));
+        }
+        pinned.extend([
+            Span::styled("Status: ", Style::default().fg(Color::Gray)),
+            Span::styled(ticket.status.as_str(), Style::default().fg(Color::Yellow)),
+            Span::styled("  Assignee: ", Style::default().fg(Color::Gray)),
+            Span::styled(ticket.assignee.as_str(), Style::default().fg(Color::Blue)),
+        ]);
+        if let Some(ref priority) = ticket.priority {
+            pinned.push(Span::styled("  Priority: ", Style::default().fg(Color::Gray)));
+            pinned.push(Span::styled(priority.as_str(), Style::default().fg(Color::Magenta)));
+        }
+        let pinned_area = Rect { y: chunks[0].y + 1, height: 1, ..chunks[0] };
+        frame.render_widget(Paragraph::new(Line::from(pinned)), pinned_area);
+    }
+    
+    // Build content lines, remembering where each section starts
     let mut lines = Vec::new();
+    let mut sections = vec![(DetailSection::Fields, 0)];
+    let mut comment_lines = Vec::new();
+    
+    if ticket.flagged {
+        lines.push(Line::from(Span::styled(
+            "🚩 Flagged as impediment",
+            Style::default().fg(Color::LightRed).add_modifier(Modifier::BOLD),
+        )));
+    }
     
     // Status and assignee
     lines.push(Line::from(vec![
         Span::styled("Status: ", Style::default().fg(Color::Gray)),
         Span::styled(&ticket.status, Style::default().fg(Color::Yellow)),
+        Span::styled(
+            ticket.resolution.as_ref().map(|r| format!(" ({})", r)).unwrap_or_default(),
+            Style::default().fg(Color::Green),
+        ),
         Span::raw("  "),
         Span::styled("Assignee: ", Style::default().fg(Color::Gray)),
         Span::styled(&ticket.assignee, Style::default().fg(Color::Blue)),
@@ -364,31 +2398,176 @@ fn draw_ticket_detail(frame: &mut Frame, area: Rect, app_state: &mut AppState) {
         lines.push(Line::from(date_spans));
     }
     
+    if let Some(ref due) = ticket.due_date {
+        lines.push(Line::from(vec![
+            Span::styled("Due: ", Style::default().fg(Color::Gray)),
+            Span::styled(due, Style::default().fg(if ticket.is_overdue() { Color::Red } else { Color::Yellow })),
+        ]));
+    }
+    
+    // Your own follow-up, set with `F`
+    if let Some(reminder) = app_state.reminders.get(&ticket.key) {
+        let due = reminder.is_due(Local::now().date_naive());
+        lines.push(Line::from(vec![
+            Span::styled("Reminder: ", Style::default().fg(Color::Gray)),
+            Span::styled(reminder.describe(), Style::default().fg(if due { Color::LightYellow } else { Color::DarkGray })),
+        ]));
+    }
+    
     // Labels if available
-    if let Some(ref labels) = ticket.labels {
-        if !labels.is_empty() {
-            let mut label_spans = vec![
-                Span::styled("Labels: ", Style::default().fg(Color::Gray)),
-            ];
-            for (i, label) in labels.iter().enumerate() {
-                if i > 0 {
-                    label_spans.push(Span::raw(", "));
-                }
-                label_spans.push(Span::styled(label, Style::default().fg(Color::Cyan)));
+    if let Some(ref labels) = ticket.labels
+        && !labels.is_empty()
+    {
+        let mut label_spans = vec![
+            Span::styled("Labels: ", Style::default().fg(Color::Gray)),
+        ];
+        for (i, label) in labels.iter().enumerate() {
+            if i > 0 {
+                label_spans.push(Span::raw(", "));
             }
-            lines.push(Line::from(label_spans));
+            label_spans.push(Span::styled(label, Style::default().fg(Color::Cyan)));
+        }
+        lines.push(Line::from(label_spans));
+    }
+    
+    // Watchers list, toggled with `W`
+    if app_state.show_watchers {
+        let watchers = match ticket.watchers {
+            Some(ref names) if names.is_empty() => "(none)".to_string(),
+            Some(ref names) => names.join(", "),
+            None => "(not loaded)".to_string(),
+        };
+        lines.push(Line::from(vec![
+            Span::styled("Watchers: ", Style::default().fg(Color::Gray)),
+            Span::styled(watchers, Style::default().fg(Color::Blue)),
+        ]));
+    }
+    
+    if let Some(ref sprint) = ticket.sprint {
+        lines.push(Line::from(vec![
+            Span::styled("Sprint: ", Style::default().fg(Color::Gray)),
+            Span::styled(&sprint.name, Style::default().fg(sprint.state.color())),
+            Span::styled(format!(" ({:?})", sprint.state).to_lowercase(), Style::default().fg(Color::DarkGray)),
+        ]));
+    }
+    
+    // Components and fix versions
+    for (label, values, color) in [
+        ("Components: ", &ticket.components, Color::Green),
+        ("Fix versions: ", &ticket.fix_versions, Color::LightMagenta),
+    ] {
+        if !values.is_empty() {
+            lines.push(Line::from(vec![
+                Span::styled(label, Style::default().fg(Color::Gray)),
+                Span::styled(values.join(", "), Style::default().fg(color)),
+            ]));
+        }
+    }
+    
+    // Service Management request type and SLA clocks
+    if let Some(ref request_type) = ticket.request_type {
+        lines.push(Line::from(vec![
+            Span::styled("Request type: ", Style::default().fg(Color::Gray)),
+            Span::raw(request_type),
+        ]));
+    }
+    let now = Utc::now();
+    for sla in &ticket.slas {
+        let (time, breached) = sla.countdown(now);
+        let state = match (sla.done, sla.paused, breached) {
+            (true, _, false) => "met".to_string(),
+            (true, _, true) => "breached".to_string(),
+            (false, true, _) => format!("paused, {} left", time),
+            (false, false, true) => format!("breached, {}", time),
+            (false, false, false) => format!("{} left", time),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}: ", sla.name), Style::default().fg(Color::Gray)),
+            Span::styled(state, sla_style(sla, now)),
+        ]));
+    }
+    
+    // Time tracking breakdown with a progress bar of logged vs remaining
+    if let Some(ref tracking) = ticket.time_tracking {
+        let value = |v: &Option<String>| v.clone().unwrap_or_else(|| "-".to_string());
+        lines.push(Line::from(vec![
+            Span::styled("Time: ", Style::default().fg(Color::Gray)),
+            Span::styled("estimate ", Style::default().fg(Color::DarkGray)),
+            Span::raw(value(&tracking.original_estimate)),
+            Span::styled("  spent ", Style::default().fg(Color::DarkGray)),
+            Span::raw(value(&tracking.time_spent)),
+            Span::styled("  remaining ", Style::default().fg(Color::DarkGray)),
+            Span::raw(value(&tracking.remaining_estimate)),
+        ]));
+        if let Some(progress) = tracking.progress() {
+            const BAR_WIDTH: usize = 30;
+            let filled = ((progress * BAR_WIDTH as f64).round() as usize).min(BAR_WIDTH);
+            let over_estimate = tracking.original_estimate_seconds
+                .zip(tracking.time_spent_seconds)
+                .is_some_and(|(estimate, spent)| spent > estimate);
+            lines.push(Line::from(vec![
+                Span::raw("      "),
+                Span::styled("█".repeat(filled), Style::default().fg(if over_estimate { Color::Red } else { Color::Green })),
+                Span::styled("░".repeat(BAR_WIDTH - filled), Style::default().fg(Color::DarkGray)),
+                Span::raw(format!(" {:.0}%", progress * 100.0)),
+            ]));
         }
     }
     
+    // Parent epic with its browse link marker
+    if let Some(ref parent) = ticket.parent {
+        let mut parent_spans = vec![
+            Span::styled(if parent.is_epic { "Epic: " } else { "Parent: " }, Style::default().fg(Color::Gray)),
+            Span::styled(&parent.key, Style::default().fg(hashed_color(&parent.key)).add_modifier(Modifier::BOLD)),
+            Span::raw(format!(" {}", parent.summary)),
+        ];
+        if let Some(index) = parent.url_index {
+            parent_spans.push(Span::styled(format!(" [{}]", index + 1), Style::default().fg(Color::Yellow)));
+        }
+        lines.push(Line::from(parent_spans));
+    }
+    
     lines.push(Line::from(""));
     
+    // Checklist, ticked in place: Tab picks an item, Space ticks it
+    if !ticket.checklist.is_empty() {
+        sections.push((DetailSection::Checklist, lines.len()));
+        let done = ticket.checklist.iter().filter(|item| item.done).count();
+        lines.push(Line::from(Span::styled(
+            format!("Checklist ({}/{})", done, ticket.checklist.len()),
+            Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD),
+        )));
+        for (index, item) in ticket.checklist.iter().enumerate() {
+            let mut style = if item.done { Style::default().fg(Color::DarkGray) } else { Style::default() };
+            if app_state.checklist_selected == Some(index) {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            let (box_symbol, box_color) = if item.done { (symbol("☑", "[x]"), Color::Green) } else { (symbol("☐", "[ ]"), Color::Yellow) };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {} ", box_symbol), Style::default().fg(box_color)),
+                Span::styled(item.text.as_str(), style),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+    
     // Description
+    sections.push((DetailSection::Description, lines.len()));
     lines.push(Line::from(Span::styled("Description:", Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD))));
     
+    let query = app_state.search_query.as_deref().unwrap_or("");
+    let mut matches = Vec::new();
+    
     if let Some(ref desc) = ticket.description {
-        // Split description into lines
-        for line in desc.lines() {
-            lines.push(Line::from(line.to_string()));
+        // Server instances send wiki markup; everything else is plain text
+        if looks_like_wiki_markup(desc) {
+            for line in render_wiki_markup(desc) {
+                push_searchable_line(&mut lines, &mut matches, line, query);
+            }
+        } else {
+            for line in desc.lines() {
+                push_searchable_line(&mut lines, &mut matches, Line::from(inline_spans(line)), query);
+            }
         }
     } else {
         lines.push(Line::from(Span::styled("(No description available)", Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC))));
@@ -399,31 +2578,161 @@ fn draw_ticket_detail(frame: &mut Frame, area: Rect, app_state: &mut AppState) {
     // Comments
     if let Some(ref comments) = ticket.comments {
         lines.push(Line::from(""));
+        sections.push((DetailSection::Comments, lines.len()));
         lines.push(Line::from(Span::styled(format!("Comments ({})", comments.len()), Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD))));
-        for comment in comments {
+        // Replies sit under the comment they quote, behind a guide per level
+        for (index, depth) in comment_threads(comments) {
+            let comment = &comments[index];
+            let guide = || Span::styled(symbol("┊ ", "| ").repeat(depth.min(4)), Style::default().fg(Color::DarkGray));
             lines.push(Line::from(""));
-            lines.push(Line::from(vec![
+            comment_lines.push((lines.len(), index));
+            let mut header = vec![
+                guide(),
+                Span::raw(if depth > 0 { symbol("↳ ", "-> ") } else { "" }),
                 Span::styled(&comment.author, Style::default().fg(Color::Blue)),
                 Span::raw(" - "),
                 Span::styled(&comment.created, Style::default().fg(Color::DarkGray)),
+            ];
+            // Internal notes stand out from what the customer can see
+            match comment.public {
+                Some(false) => header.push(Span::styled(" Internal ", Style::default().fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD))),
+                Some(true) => header.push(Span::styled(" Customer-visible ", Style::default().fg(Color::Cyan))),
+                None => {}
+            }
+            lines.push(Line::from(header));
+            let body = if looks_like_wiki_markup(&comment.body) {
+                render_wiki_markup(&comment.body)
+            } else {
+                comment.body.lines()
+                    .map(|line| match line.strip_prefix('>') {
+                        Some(quote) => quoted(inline_spans(quote.strip_prefix(' ').unwrap_or(quote))),
+                        None => Line::from(inline_spans(line)),
+                    })
+                    .collect()
+            };
+            for mut line in body {
+                if depth > 0 {
+                    line.spans.insert(0, guide());
+                }
+                push_searchable_line(&mut lines, &mut matches, line, query);
+            }
+            // Your own reactions are highlighted, as in the browser
+            if !comment.reactions.is_empty() {
+                let mut spans = vec![guide()];
+                for reaction in &comment.reactions {
+                    let style = if reaction.mine {
+                        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(Color::DarkGray)
+                    };
+                    spans.push(Span::styled(format!("{} {}", reaction.emoji, reaction.count), style));
+                    spans.push(Span::raw("  "));
+                }
+                lines.push(Line::from(spans));
+            }
+        }
+    }
+    
+    // Linked issues and link targets referenced by `[n]` markers
+    let has_links = ticket.links.as_ref().is_some_and(|l| !l.is_empty());
+    let remote_links = ticket.remote_links.as_deref().unwrap_or(&[]);
+    if has_links || !ticket.urls.is_empty() {
+        lines.push(Line::from(""));
+        sections.push((DetailSection::Links, lines.len()));
+        let link_count = ticket.links.as_ref().map_or(0, |l| l.len()) + ticket.urls.len();
+        lines.push(Line::from(Span::styled(format!("Links ({})", link_count), Style::default().fg(Color::Gray).add_modifier(Modifier::BOLD))));
+        for link in ticket.links.iter().flatten() {
+            lines.push(Line::from(vec![
+                Span::styled(format!("{} ", link.relation), Style::default().fg(Color::DarkGray)),
+                Span::styled(&link.key, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::raw(" "),
+                Span::styled(format!("[{}]", link.status), Style::default().fg(get_status_color(&link.status, None))),
+                Span::raw(format!(" {}", link.summary)),
+            ]));
+        }
+        for remote in remote_links {
+            let icon = match remote.source.as_str() {
+                "Confluence" => "📄",
+                "Slack" => "💬",
+                _ => "🔗",
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("[{}] ", remote.url_index + 1), Style::default().fg(Color::Yellow)),
+                Span::raw(format!("{} ", icon)),
+                Span::styled(format!("{}: ", remote.source), Style::default().fg(Color::DarkGray)),
+                Span::styled(&remote.title, Style::default().fg(Color::Blue).add_modifier(Modifier::UNDERLINED)),
+            ]));
+        }
+        for (i, url) in ticket.urls.iter().enumerate() {
+            // Remote links were listed above with their titles
+            if remote_links.iter().any(|r| r.url_index == i) {
+                continue;
+            }
+            lines.push(Line::from(vec![
+                Span::styled(format!("[{}] ", i + 1), Style::default().fg(Color::Yellow)),
+                Span::styled(url, Style::default().fg(Color::Blue).add_modifier(Modifier::UNDERLINED)),
             ]));
-            lines.push(Line::from(&comment.body[..]));
         }
     }
     
+    // Remember which lines reference which link for `gx`
+    app_state.detail_link_lines = lines.iter()
+        .enumerate()
+        .flat_map(|(i, line)| {
+            let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+            link_markers(&text, ticket.urls.len()).into_iter().map(move |index| (i, index))
+        })
+        .collect();
+    app_state.detail_sections = sections;
+    app_state.detail_comment_lines = comment_lines;
+    app_state.search_matches = matches;
+    if app_state.search_jump {
+        app_state.search_jump = false;
+        // Field-level access here since `lines` still borrows the ticket
+        let scroll = app_state.detail_scroll;
+        if let Some(&line) = app_state.search_matches.iter()
+            .find(|&&line| line >= scroll)
+            .or_else(|| app_state.search_matches.first())
+        {
+            app_state.detail_scroll = line;
+        }
+    }
+    
+    // Mark the comment `r` would reply to
+    if let Some(index) = app_state.current_comment()
+        && let Some(&(line, _)) = app_state.detail_comment_lines.iter().find(|(_, i)| *i == index)
+    {
+        lines[line].spans.insert(0, Span::styled("▶ ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
+    }
+    
     // Apply scroll offset
     let visible_lines = chunks[1].height as usize;
     let total_lines = lines.len();
+    app_state.detail_height = visible_lines;
+    app_state.detail_length = total_lines;
+    
+    // A readable column, centered when the terminal is wider
+    let reading_width = match app_state.display.reading_width {
+        0 => chunks[1].width,
+        limit => limit.min(chunks[1].width),
+    };
+    let column = Rect {
+        x: chunks[1].x + (chunks[1].width - reading_width) / 2,
+        width: reading_width,
+        ..chunks[1]
+    };
     
+    // Scrolling counts source lines, so wrap only the ones on screen
     let visible_content: Vec<Line> = lines.into_iter()
         .skip(app_state.detail_scroll)
+        .flat_map(|line| wrap_line(line, column.width.saturating_sub(1) as usize))
         .take(visible_lines)
         .collect();
     
     let content = Paragraph::new(visible_content)
-        .block(Block::default().borders(Borders::NONE))
-        .wrap(Wrap { trim: true });
-    frame.render_widget(content, chunks[1]);
+        .block(Block::default().borders(borders(Borders::NONE)));
+    frame.render_widget(content, column);
+    draw_scrollbar(frame, chunks[1], total_lines, app_state.detail_scroll, visible_lines);
     
     // Footer with controls and scroll position
     let scroll_info = if total_lines > visible_lines {
@@ -436,9 +2745,394 @@ fn draw_ticket_detail(frame: &mut Frame, area: Rect, app_state: &mut AppState) {
         String::new()
     };
     
-    let footer_text = format!("ESC/q: Back  ↑↓/jk: Scroll  PgUp/PgDn: Page{}", scroll_info);
+    // Section position indicator, e.g. "§ Comments (3/4)"
+    let section_info = match app_state.current_section() {
+        Some((i, section)) => format!(" § {} ({}/{})", section.label(), i + 1, app_state.detail_sections.len()),
+        None => String::new(),
+    };
+    
+    // Keys JIRA wouldn't allow on this ticket stay out of the footer
+    let key = app_state.detail_ticket.as_ref().map_or("", |t| t.key.as_str());
+    let connected = app_state.setup_needed.is_empty();
+    let allowed = |permission| connected && app_state.permissions.allows(key, permission);
+    let keys = keymap::State {
+        connected,
+        editable: allowed(Permission::Edit),
+        commentable: allowed(Permission::Comment),
+        ..Default::default()
+    };
+    
+    // While typing a search, the footer becomes the input line
+    let footer_text = if let Some(ref input) = app_state.search_input {
+        format!("/{}▏", input)
+    } else if let Some((ref key, ref input)) = app_state.reminder_input {
+        tf("board.remind", &[("key", key), ("input", input)])
+    } else if let Some(ref message) = app_state.status_message {
+        message.clone()
+    } else if let Some(ref query) = app_state.search_query {
+        let position = app_state.search_matches.iter()
+            .position(|&line| line == app_state.detail_scroll)
+            .map(|i| format!("{}/", i + 1))
+            .unwrap_or_default();
+        let state = keymap::State { searching: true, ..keys };
+        format!("/{}  [{}{} matches]  {}{}{}",
+            query, position, app_state.search_matches.len(), keymap::footer(Context::Detail, &state), section_info, scroll_info)
+    } else {
+        format!("{}{}{}", keymap::footer(Context::Detail, &keys), section_info, scroll_info)
+    };
     let footer = Paragraph::new(footer_text)
         .style(Style::default().fg(Color::DarkGray))
-        .block(Block::default().borders(Borders::TOP));
+        .block(Block::default().borders(borders(Borders::TOP)));
     frame.render_widget(footer, chunks[2]);
-}
\ No newline at end of file
+}
+
+// Red once breached, yellow in the last hour, gray while paused or stopped
+fn sla_style(sla: &Sla, now: DateTime<Utc>) -> Style {
+    let (_, breached) = sla.countdown(now);
+    let last_hour = sla.breach_at.is_some_and(|at| at - now < chrono::Duration::hours(1));
+    if breached {
+        Style::default().fg(Color::White).bg(Color::Red).add_modifier(Modifier::BOLD)
+    } else if sla.paused || sla.done {
+        Style::default().fg(Color::DarkGray)
+    } else if last_hour {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::Green)
+    }
+}
+
+// Countdown for a card, e.g. "⏳TFR 1h 20m"
+fn sla_badge(sla: &Sla, now: DateTime<Utc>) -> Span<'static> {
+    let (time, _) = sla.countdown(now);
+    let icon = if sla.paused { symbol("⏸", "=") } else { symbol("⏳", "@") };
+    Span::styled(format!("{}{} {}", icon, sla.short_name(), time), sla_style(sla, now))
+}
+
+// Truncate to at most `max` characters, marking the cut with an ellipsis
+// Time to the next refresh, rounded up: "45s", "12m", "9h 30m"
+fn countdown(due: Duration) -> String {
+    let seconds = due.as_secs() + u64::from(due.subsec_nanos() > 0);
+    match seconds {
+        0..120 => format!("{}s", seconds),
+        120..7200 => format!("{}m", seconds.div_ceil(60)),
+        _ => format!("{}h {}m", seconds / 3600, seconds % 3600 / 60),
+    }
+}
+
+// `text` cut to `width` terminal cells, ending in "…" when cut
+fn cut_to_width(text: &str, width: usize) -> String {
+    if text_width(text) <= width {
+        return text.to_string();
+    }
+    let mut cut = String::new();
+    let mut used = 1;  // The ellipsis
+    for c in text.chars() {
+        used += text_width(c.encode_utf8(&mut [0; 4]));
+        if used > width {
+            break;
+        }
+        cut.push(c);
+    }
+    format!("{}…", cut.trim_end())
+}
+
+// The detailed density's extra card line: priority, labels and due date,
+// or None when the ticket has none of them
+fn details_line(ticket: &Ticket) -> Option<Line<'static>> {
+    let mut spans = Vec::new();
+    let mut add = |text: String, style: Style| {
+        spans.push(Span::raw(if spans.is_empty() { "    " } else { "  " }));
+        spans.push(Span::styled(text, style));
+    };
+    if let Some(ref priority) = ticket.priority {
+        add(priority.clone(), Style::default().fg(Color::Magenta));
+    }
+    for label in ticket.labels.iter().flatten() {
+        add(format!("#{}", label), Style::default().fg(Color::Cyan));
+    }
+    if let Some(ref due) = ticket.due_date {
+        let date = NaiveDate::parse_from_str(due, "%Y-%m-%d").map_or(due.clone(), |d| d.format("%b %-d").to_string());
+        let color = if ticket.is_overdue() { Color::Red } else { Color::DarkGray };
+        add(format!("due {}", date), Style::default().fg(color));
+    }
+    (!spans.is_empty()).then(|| Line::from(spans))
+}
+
+fn abbreviate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        text.to_string()
+    } else {
+        let cut: String = text.chars().take(max.saturating_sub(1)).collect();
+        format!("{}…", cut.trim_end())
+    }
+}
+
+// Stable color for an arbitrary string (epic keys, people), so the same
+// value always gets the same color across refreshes
+fn hashed_color(value: &str) -> Color {
+    const PALETTE: [Color; 10] = [
+        Color::Red, Color::Green, Color::Yellow, Color::Blue, Color::Magenta,
+        Color::Cyan, Color::LightRed, Color::LightGreen, Color::LightMagenta, Color::LightBlue,
+    ];
+    // FNV-1a keeps this independent of std's randomized hasher
+    let hash = value.bytes().fold(0xcbf29ce484222325u64, |h, b| (h ^ b as u64).wrapping_mul(0x100000001b3));
+    PALETTE[(hash % PALETTE.len() as u64) as usize]
+}
+
+// Two-letter initials for a display name or email: "Jake Swanson" → "JS",
+// "jake.swanson@example.com" → "JS", "jake" → "JA"
+fn initials(name: &str) -> String {
+    let local = name.split('@').next().unwrap_or(name);
+    let words: Vec<&str> = local
+        .split(|c: char| c.is_whitespace() || c == '.' || c == '_' || c == '-')
+        .filter(|w| !w.is_empty())
+        .collect();
+    let letters: String = match words.as_slice() {
+        [] => "?".to_string(),
+        [only] => only.chars().take(2).collect(),
+        [first, .., last] => first.chars().take(1).chain(last.chars().take(1)).collect(),
+    };
+    letters.to_uppercase()
+}
+
+// First letter of a query name, colored per query
+fn query_badge(name: &str) -> Span<'static> {
+    let letter = name.chars().next().unwrap_or('?').to_uppercase().to_string();
+    Span::styled(letter, Style::default().fg(hashed_color(name)).add_modifier(Modifier::BOLD))
+}
+
+// Initials on a background color that stays the same for each person
+fn initials_badge(name: &str) -> Span<'static> {
+    Span::styled(
+        format!(" {} ", initials(name)),
+        Style::default().fg(Color::Black).bg(hashed_color(name)).add_modifier(Modifier::BOLD),
+    )
+}
+
+// Split plain text into spans, coloring `@mentions` and `[n]` link markers.
+// A mention runs from the `@` through up to two following capitalized words
+// ("@Jane Doe").
+fn inline_spans(text: &str) -> Vec<Span<'static>> {
+    let mention_style = Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD);
+    let marker_style = Style::default().fg(Color::Yellow);
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut words = text.split(' ').peekable();
+    let mut first = true;
+    
+    while let Some(word) = words.next() {
+        if !first {
+            plain.push(' ');
+        }
+        first = false;
+        
+        if word.len() > 1 && word.starts_with('@') {
+            if !plain.is_empty() {
+                spans.push(Span::raw(std::mem::take(&mut plain)));
+            }
+            let mut mention = word.to_string();
+            let mut extra_words = 0;
+            while let Some(next) = words.peek() {
+                if extra_words < 2 && next.chars().next().is_some_and(|c| c.is_uppercase()) && !mention.ends_with(|c: char| c.is_ascii_punctuation()) {
+                    mention.push(' ');
+                    mention.push_str(next);
+                    words.next();
+                    extra_words += 1;
+                } else {
+                    break;
+                }
+            }
+            spans.push(Span::styled(mention, mention_style));
+        } else if word.len() > 2 && word.starts_with('[') && word.ends_with(']') && word[1..word.len() - 1].parse::<usize>().is_ok() {
+            if !plain.is_empty() {
+                spans.push(Span::raw(std::mem::take(&mut plain)));
+            }
+            spans.push(Span::styled(word.to_string(), marker_style));
+        } else {
+            plain.push_str(word);
+        }
+    }
+    
+    if !plain.is_empty() || spans.is_empty() {
+        spans.push(Span::raw(plain));
+    }
+    spans
+}
+
+// Link indexes (zero-based) referenced by `[n]` markers in a line of text
+fn link_markers(text: &str, url_count: usize) -> Vec<usize> {
+    let mut found = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('[') {
+        rest = &rest[start + 1..];
+        let Some(end) = rest.find(']') else { break };
+        if let Ok(n) = rest[..end].parse::<usize>()
+            && n >= 1
+            && n <= url_count
+        {
+            found.push(n - 1);
+        }
+    }
+    found
+}
+
+// Push a line of free text, highlighting case-insensitive matches of `query`
+// within each span and recording the line index when it contains one
+fn push_searchable_line<'a>(
+    lines: &mut Vec<Line<'a>>,
+    matches: &mut Vec<usize>,
+    line: Line<'a>,
+    query: &str,
+) {
+    if query.is_empty() {
+        lines.push(line);
+        return;
+    }
+    
+    let highlight = Style::default().fg(Color::Black).bg(Color::Yellow);
+    let needle = query.to_ascii_lowercase();
+    let mut spans = Vec::new();
+    let mut found_any = false;
+    
+    for span in line.spans {
+        let text = span.content.as_ref();
+        // ASCII lowercasing keeps byte offsets aligned with the original text
+        let haystack = text.to_ascii_lowercase();
+        let mut pos = 0;
+        while let Some(found) = haystack[pos..].find(&needle) {
+            let start = pos + found;
+            let end = start + needle.len();
+            if start > pos {
+                spans.push(Span::styled(text[pos..start].to_string(), span.style));
+            }
+            spans.push(Span::styled(text[start..end].to_string(), highlight));
+            pos = end;
+        }
+        if pos == 0 {
+            spans.push(span);
+        } else {
+            found_any = true;
+            if pos < text.len() {
+                spans.push(Span::styled(text[pos..].to_string(), span.style));
+            }
+        }
+    }
+    
+    if found_any {
+        matches.push(lines.len());
+    }
+    lines.push(Line::from(spans));
+}
+
+// Width of text in terminal cells
+// Code (indented four spaces) and tables keep their layout; wrapping
+// them would scramble it, so they run off the edge instead
+fn is_preformatted(line: &Line) -> bool {
+    let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+    let trimmed = text.trim();
+    text.starts_with("    ")
+        || text.starts_with('\t')
+        || (trimmed.starts_with('│') && trimmed.ends_with('│'))
+        || trimmed.starts_with(['├', '┌', '└'])
+}
+
+// Break a line into rows of at most `width` cells between words, keeping
+// each span's style. Words longer than a row are split.
+fn wrap_line(line: Line<'_>, width: usize) -> Vec<Line<'_>> {
+    if width == 0 || line.width() <= width || is_preformatted(&line) {
+        return vec![line];
+    }
+    
+    let mut rows = Vec::new();
+    let mut row: Vec<Span> = Vec::new();
+    let mut row_width = 0;
+    for span in line.spans {
+        for word in span.content.split_inclusive(' ') {
+            let word_width = text_width(word.trim_end());
+            if row_width > 0 && row_width + word_width > width {
+                rows.push(Line::from(std::mem::take(&mut row)));
+                row_width = 0;
+            }
+            // Wrapped rows don't start with the space that split them
+            let word = if row_width == 0 && !rows.is_empty() { word.trim_start() } else { word };
+            let mut rest = word;
+            while text_width(rest.trim_end()) > width - row_width {
+                let split = rest.char_indices()
+                    .scan(row_width, |used, (i, c)| {
+                        *used += text_width(c.encode_utf8(&mut [0; 4]));
+                        Some((i, *used))
+                    })
+                    .find(|(_, used)| *used > width)
+                    .map_or(rest.len(), |(i, _)| i)
+                    .max(rest.chars().next().map_or(0, char::len_utf8));
+                row.push(Span::styled(rest[..split].to_string(), span.style));
+                rows.push(Line::from(std::mem::take(&mut row)));
+                row_width = 0;
+                rest = &rest[split..];
+            }
+            if !rest.is_empty() {
+                row_width += text_width(rest);
+                row.push(Span::styled(rest.to_string(), span.style));
+            }
+        }
+    }
+    if !row.is_empty() {
+        rows.push(Line::from(row));
+    }
+    rows
+}
+
+fn text_width(text: &str) -> usize {
+    Span::raw(text).width()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Words of narrow, wide and accented characters, runs of spaces, and
+    // the odd word longer than any row
+    const TEXT: &str = "([a-zé]{1,6}|[世界日本]{1,3}|x{10,30}| {1,3}){0,30}";
+
+    fn line(parts: &[String]) -> Line<'static> {
+        let styles = [Style::default(), Style::default().fg(Color::Red), Style::default().add_modifier(Modifier::BOLD)];
+        Line::from(parts.iter().enumerate().map(|(i, part)| Span::styled(part.clone(), styles[i % styles.len()])).collect::<Vec<_>>())
+    }
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    proptest! {
+        #[test]
+        fn any_text_wraps_without_panicking(parts in prop::collection::vec("\\PC{0,40}", 0..4), width in 0usize..40) {
+            wrap_line(line(&parts), width);
+        }
+
+        #[test]
+        fn rows_fit_the_width(parts in prop::collection::vec(TEXT, 1..4), width in 2usize..40) {
+            let line = line(&parts);
+            prop_assume!(!is_preformatted(&line));
+            for row in wrap_line(line, width) {
+                prop_assert!(text_width(text(&row).trim_end()) <= width, "{:?} is wider than {}", text(&row), width);
+            }
+        }
+
+        #[test]
+        fn wrapping_only_drops_spaces(parts in prop::collection::vec(TEXT, 1..4), width in 1usize..40) {
+            let line = line(&parts);
+            let before: String = text(&line).split_whitespace().collect();
+            let after: String = wrap_line(line, width).iter().flat_map(|row| text(row).split_whitespace().map(str::to_string).collect::<Vec<_>>()).collect();
+            prop_assert_eq!(before, after);
+        }
+
+        #[test]
+        fn short_lines_are_left_alone(parts in prop::collection::vec(TEXT, 1..4), extra in 0usize..10) {
+            let line = line(&parts);
+            let width = line.width() + extra;
+            let rows = wrap_line(line.clone(), width);
+            prop_assert_eq!(rows, vec![line]);
+        }
+    }
+}
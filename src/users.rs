@@ -0,0 +1,83 @@
+// User directory: display names keyed by account id or email, filled in
+// lazily from API responses and persisted to the data dir so names
+// survive restarts without repeated lookups. New names are written out by
+// `save`, once per fetch rather than once per name.
+use crate::config::Config;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+#[derive(Default)]
+struct Directory {
+    names: HashMap<String, String>,
+    dirty: bool,  // Names learned since the file was last written
+}
+
+fn directory() -> &'static Mutex<Directory> {
+    static DIRECTORY: OnceLock<Mutex<Directory>> = OnceLock::new();
+    DIRECTORY.get_or_init(|| {
+        let names = cache_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+        Mutex::new(Directory { names, dirty: false })
+    })
+}
+
+pub fn cache_path() -> Option<PathBuf> {
+    Some(Config::data_dir()?.join("users.json"))
+}
+
+/// Cached display name for an account id or email
+pub fn display_name(key: &str) -> Option<String> {
+    directory().lock().ok()?.names.get(key).cloned()
+}
+
+/// The account id of the one cached user with display name `name`, e.g. to
+/// assign someone seen before without searching for them. None when nobody
+/// or several people have it.
+pub fn account_id(name: &str) -> Option<String> {
+    let directory = directory().lock().ok()?;
+    // Emails are keys too, but JIRA wants account ids
+    let mut matching = directory.names.iter()
+        .filter(|(key, known)| !key.contains('@') && known.eq_ignore_ascii_case(name))
+        .map(|(key, _)| key);
+    match (matching.next(), matching.next()) {
+        (Some(id), None) => Some(id.clone()),
+        _ => None,
+    }
+}
+
+/// Record a display name, to be written out by the next `save`
+pub fn remember(key: &str, name: &str) {
+    if key.is_empty() || name.is_empty() {
+        return;
+    }
+
+    let Ok(mut directory) = directory().lock() else { return };
+    if directory.names.get(key).map(|n| n.as_str()) == Some(name) {
+        return;
+    }
+    directory.names.insert(key.to_string(), name.to_string());
+    directory.dirty = true;
+}
+
+/// Write the cache file if names were learned since it was last written
+pub fn save() {
+    let Ok(mut directory) = directory().lock() else { return };
+    if !directory.dirty {
+        return;
+    }
+    directory.dirty = false;
+
+    // Best effort: a read-only data dir just means lookups repeat next run
+    if let Some(path) = cache_path() {
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        if let Ok(json) = serde_json::to_string_pretty(&directory.names) {
+            let _ = fs::write(path, json);
+        }
+    }
+}
@@ -0,0 +1,249 @@
+// Render JIRA wiki markup (as returned by self-hosted Server/Data Center
+// instances) into styled lines for the detail view
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+// Cheap check for whether a plain-string description uses wiki markup
+pub fn looks_like_wiki_markup(text: &str) -> bool {
+    if text.contains("{code") || text.contains("{noformat}") || text.contains("{quote}")
+        || text.contains("{{") || text.contains("{color") || text.contains("[~")
+    {
+        return true;
+    }
+
+    text.lines().any(|line| {
+        let line = line.trim_start();
+        is_heading(line).is_some()
+            || line.starts_with("bq. ")
+            || line.starts_with("||")
+            || line == "----"
+    })
+}
+
+pub fn render_wiki_markup(text: &str) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut in_code = false;
+    let mut in_quote = false;
+
+    for raw in text.lines() {
+        let trimmed = raw.trim();
+
+        // Code/noformat blocks are shown verbatim, indented four spaces so
+        // the detail view doesn't wrap them
+        if in_code {
+            if trimmed.starts_with("{code}") || trimmed.starts_with("{noformat}") {
+                in_code = false;
+            } else {
+                lines.push(Line::from(Span::styled(
+                    format!("    {}", raw),
+                    Style::default().fg(Color::Green),
+                )));
+            }
+            continue;
+        }
+        if trimmed.starts_with("{code") || trimmed.starts_with("{noformat") {
+            in_code = true;
+            continue;
+        }
+
+        if trimmed.starts_with("{quote}") {
+            in_quote = !in_quote;
+            continue;
+        }
+
+        if in_quote {
+            lines.push(quoted(render_inline(raw)));
+            continue;
+        }
+
+        if let Some(level) = is_heading(trimmed) {
+            let color = if level <= 2 { Color::Cyan } else { Color::Blue };
+            let mut spans = render_inline(trimmed[4..].trim());
+            for span in spans.iter_mut() {
+                span.style = span.style.fg(color).add_modifier(Modifier::BOLD);
+            }
+            lines.push(Line::from(spans));
+            continue;
+        }
+
+        if let Some(rest) = trimmed.strip_prefix("bq. ") {
+            lines.push(quoted(render_inline(rest)));
+            continue;
+        }
+
+        if trimmed == "----" {
+            lines.push(Line::from(Span::styled(
+                "─".repeat(40),
+                Style::default().fg(Color::DarkGray),
+            )));
+            continue;
+        }
+
+        // Table rows: `||header||header||` and `|cell|cell|`
+        if trimmed.starts_with('|') {
+            let is_header = trimmed.starts_with("||");
+            let separator = if is_header { "||" } else { "|" };
+            let cells: Vec<&str> = trimmed
+                .trim_matches('|')
+                .split(separator)
+                .map(|c| c.trim())
+                .collect();
+            let mut spans = vec![Span::styled("│ ", Style::default().fg(Color::DarkGray))];
+            for cell in cells {
+                let mut cell_spans = render_inline(cell);
+                if is_header {
+                    for span in cell_spans.iter_mut() {
+                        span.style = span.style.add_modifier(Modifier::BOLD);
+                    }
+                }
+                spans.extend(cell_spans);
+                spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
+            }
+            lines.push(Line::from(spans));
+            continue;
+        }
+
+        // Bulleted (`*`, `-`) and numbered (`#`) lists, nested by repetition
+        let marker_len = trimmed.chars().take_while(|c| *c == '*' || *c == '#' || *c == '-').count();
+        if marker_len > 0 && trimmed[marker_len..].starts_with(' ') {
+            let marker = if trimmed[..marker_len].ends_with('#') { "1." } else { "•" };
+            let mut spans = vec![Span::raw(format!("{}{} ", "  ".repeat(marker_len - 1), marker))];
+            spans.extend(render_inline(trimmed[marker_len..].trim_start()));
+            lines.push(Line::from(spans));
+            continue;
+        }
+
+        lines.push(Line::from(render_inline(raw)));
+    }
+
+    lines
+}
+
+// `h1.` through `h6.` headings, returning the level
+fn is_heading(line: &str) -> Option<u8> {
+    let bytes = line.as_bytes();
+    if bytes.len() >= 4 && bytes[0] == b'h' && (b'1'..=b'6').contains(&bytes[1])
+        && bytes[2] == b'.' && bytes[3] == b' '
+    {
+        Some(bytes[1] - b'0')
+    } else {
+        None
+    }
+}
+
+/// A line of quoted text, set off by a rule
+pub fn quoted(spans: Vec<Span<'static>>) -> Line<'static> {
+    let mut line = vec![Span::styled("│ ", Style::default().fg(Color::DarkGray))];
+    line.extend(spans.into_iter().map(|span| {
+        let style = span.style.add_modifier(Modifier::ITALIC);
+        span.style(style)
+    }));
+    Line::from(line)
+}
+
+// Inline formatting: *bold*, _italic_, -strike-, +underline+, {{mono}},
+// [text|url] links, [~user] mentions and {color} spans (stripped)
+fn render_inline(text: &str) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let prev_is_boundary = i == 0 || !chars[i - 1].is_alphanumeric();
+
+        // {{monospace}}
+        if c == '{' && chars.get(i + 1) == Some(&'{')
+            && let Some(end) = find_seq(&chars, i + 2, "}}")
+        {
+            flush(&mut spans, &mut plain);
+            spans.push(Span::styled(
+                chars[i + 2..end].iter().collect::<String>(),
+                Style::default().fg(Color::Green),
+            ));
+            i = end + 2;
+            continue;
+        }
+
+        // {color:...} and {color} markers carry no text
+        if c == '{' && let Some(end) = find_seq(&chars, i + 1, "}") {
+            let inner: String = chars[i + 1..end].iter().collect();
+            if inner.starts_with("color") {
+                i = end + 1;
+                continue;
+            }
+        }
+
+        // [text|url], [url] and [~mention]
+        if c == '[' && let Some(end) = find_seq(&chars, i + 1, "]") {
+            let inner: String = chars[i + 1..end].iter().collect();
+            flush(&mut spans, &mut plain);
+            if let Some(user) = inner.strip_prefix('~') {
+                spans.push(Span::styled(
+                    format!("@{}", user),
+                    Style::default().fg(Color::Magenta),
+                ));
+            } else {
+                let label = inner.split('|').next().unwrap_or(&inner).to_string();
+                spans.push(Span::styled(
+                    label,
+                    Style::default().fg(Color::Blue).add_modifier(Modifier::UNDERLINED),
+                ));
+            }
+            i = end + 1;
+            continue;
+        }
+
+        // Paired emphasis markers must open at a word boundary and wrap
+        // non-space content
+        let modifier = match c {
+            '*' => Some(Modifier::BOLD),
+            '_' => Some(Modifier::ITALIC),
+            '-' => Some(Modifier::CROSSED_OUT),
+            '+' => Some(Modifier::UNDERLINED),
+            _ => None,
+        };
+        if let Some(modifier) = modifier
+            && prev_is_boundary
+            && chars.get(i + 1).is_some_and(|n| !n.is_whitespace() && *n != c)
+            && let Some(end) = find_closing(&chars, i + 1, c)
+        {
+            flush(&mut spans, &mut plain);
+            spans.push(Span::styled(
+                chars[i + 1..end].iter().collect::<String>(),
+                Style::default().add_modifier(modifier),
+            ));
+            i = end + 1;
+            continue;
+        }
+
+        plain.push(c);
+        i += 1;
+    }
+
+    flush(&mut spans, &mut plain);
+    spans
+}
+
+fn flush(spans: &mut Vec<Span<'static>>, plain: &mut String) {
+    if !plain.is_empty() {
+        spans.push(Span::raw(std::mem::take(plain)));
+    }
+}
+
+fn find_seq(chars: &[char], from: usize, seq: &str) -> Option<usize> {
+    let seq: Vec<char> = seq.chars().collect();
+    (from..chars.len()).find(|&i| chars[i..].starts_with(&seq))
+}
+
+// Closing emphasis marker: preceded by non-space, followed by a boundary
+fn find_closing(chars: &[char], from: usize, marker: char) -> Option<usize> {
+    (from + 1..chars.len()).find(|&i| {
+        chars[i] == marker
+            && !chars[i - 1].is_whitespace()
+            && chars.get(i + 1).is_none_or(|n| !n.is_alphanumeric())
+    })
+}